@@ -12,6 +12,20 @@ fn main() {
         enable_timing: true,
         tile_eps: 1e-4,
         require_mutual_consent: true,
+        skin_width: 0.0,
+        broadphase: Broadphase::Grid,
+        overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+        square_circle_tile_sweep: false,
+        grid_shrink_interval: 0,
+        sort_overlaps_by_depth: false,
+        toi_in_seconds: false,
+        ray_tie_preference: TiePreference::Collider,
+        raycast_skip_start_solid: false,
+        world_bounds: None,
+        point_eps: 0.0,
+        tile_events_per_cell: false,
+        large_collider_cell_threshold: 0,
+        contact_skin: 0.0,
     });
 
     world.begin_frame();
@@ -49,23 +63,6 @@ fn main() {
         );
     }
     for ev in world.drain_events() {
-        match ev.kind {
-            EventKind::Sweep => {
-                if let Some(s) = ev.sweep {
-                    println!(
-                        "Sweep: {:?} vs {:?} toi={:.3} n=({:.2},{:.2})",
-                        ev.a, ev.b, s.toi, s.normal.x, s.normal.y
-                    );
-                }
-            }
-            EventKind::Overlap => {
-                if let Some(o) = ev.overlap {
-                    println!(
-                        "Overlap: {:?} vs {:?} depth={:.3} n=({:.2},{:.2})",
-                        ev.a, ev.b, o.depth, o.normal.x, o.normal.y
-                    );
-                }
-            }
-        }
+        println!("{ev:.3}");
     }
 }
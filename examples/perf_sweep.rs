@@ -17,6 +17,20 @@ fn build_world(n: usize, cs: f32, tighten: bool, seed0: u32) -> (PhysicsWorld, u
         enable_timing: true,
         tile_eps: 1e-4,
         require_mutual_consent: true,
+        skin_width: 0.0,
+        broadphase: Broadphase::Grid,
+        overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+        square_circle_tile_sweep: false,
+        grid_shrink_interval: 0,
+        sort_overlaps_by_depth: false,
+        toi_in_seconds: false,
+        ray_tie_preference: TiePreference::Collider,
+        raycast_skip_start_solid: false,
+        world_bounds: None,
+        point_eps: 0.0,
+        tile_events_per_cell: false,
+        large_collider_cell_threshold: 0,
+        contact_skin: 0.0,
     });
     let mut seed = seed0;
     let mask = LayerMask::simple(1, 1);
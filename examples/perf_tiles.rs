@@ -13,13 +13,27 @@ fn main() {
         enable_timing: false,
         tile_eps: 1e-4,
         require_mutual_consent: true,
+        skin_width: 0.0,
+        broadphase: Broadphase::Grid,
+        overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+        square_circle_tile_sweep: false,
+        grid_shrink_interval: 0,
+        sort_overlaps_by_depth: false,
+        toi_in_seconds: false,
+        ray_tie_preference: TiePreference::Collider,
+        raycast_skip_start_solid: false,
+        world_bounds: None,
+        point_eps: 0.0,
+        tile_events_per_cell: false,
+        large_collider_cell_threshold: 0,
+        contact_skin: 0.0,
     });
 
     // Build a 256x256 map with ~25% solids in a checkerboard-ish pattern
     let w = 256u32; let h = 256u32;
     let mut solids = vec![0u8; (w*h) as usize];
     for y in 0..h { for x in 0..w { if (x ^ y) & 0x3 == 0 { solids[(y*w+x) as usize] = 1; } }}
-    world.attach_tilemap(TileMapDesc { origin: Vec2::new(0.0,0.0), cell: 1.0, width: w, height: h, solids: &solids, mask: LayerMask::simple(2,1), user_key: None });
+    world.attach_tilemap(TileMapDesc { origin: Vec2::new(0.0,0.0), cell: 1.0, width: w, height: h, solids: &solids, mask: LayerMask::simple(2,1), user_key: None, solid_mask: 0xFF });
 
     // Ray throughput
     let origin = Vec2::new(-10.0, 100.5);
@@ -18,6 +18,20 @@ fn main() {
         enable_timing: true,
         tile_eps: 1e-4,
         require_mutual_consent: true,
+        skin_width: 0.0,
+        broadphase: Broadphase::Grid,
+        overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+        square_circle_tile_sweep: false,
+        grid_shrink_interval: 0,
+        sort_overlaps_by_depth: false,
+        toi_in_seconds: false,
+        ray_tie_preference: TiePreference::Collider,
+        raycast_skip_start_solid: false,
+        world_bounds: None,
+        point_eps: 0.0,
+        tile_events_per_cell: false,
+        large_collider_cell_threshold: 0,
+        contact_skin: 0.0,
     });
 
     let n = 20_000usize; // number of colliders
@@ -52,8 +66,8 @@ fn main() {
         println!(
             "N={} cell_size={} tighten={} end={:.3}ms gen={:.3}ms (scan={:.3}ms narrow={:.3}ms) events={}",
             n,
-            world.cfg.cell_size,
-            world.cfg.tighten_swept_aabb,
+            world.config().cell_size,
+            world.config().tighten_swept_aabb,
             t.end_frame_ms,
             t.generate_ms,
             t.generate_scan_ms,
@@ -63,7 +77,7 @@ fn main() {
     } else {
         println!(
             "N={} cell_size={} tighten={} end_frame={:?} generate={:?} events={}",
-            n, world.cfg.cell_size, world.cfg.tighten_swept_aabb, t_end, t_gen, n_events
+            n, world.config().cell_size, world.config().tighten_swept_aabb, t_end, t_gen, n_events
         );
     }
 }
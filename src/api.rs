@@ -1,4 +1,6 @@
-use glam::Vec2;
+use glam::{UVec2, Vec2};
+use std::collections::HashSet;
+use std::ops::Range;
 
 use crate::types::*;
 
@@ -14,15 +16,27 @@ pub trait PhysicsWorldApi {
     /// Begin a new frame. Clears ephemeral storage used for the previous frame.
     fn begin_frame(&mut self);
 
+    /// The configuration in effect for the current frame.
+    fn config(&self) -> &WorldConfig;
+
+    /// Replace the configuration. Takes effect at the next `begin_frame`, so the current
+    /// frame's grids and queries keep a consistent `cell_size`; a later call before then
+    /// overrides an earlier one.
+    fn set_config(&mut self, cfg: WorldConfig);
+
     /// Insert a collider for this frame and return its frame-local handle.
     fn push(&mut self, desc: ColliderDesc, motion: Motion) -> FrameId;
 
+    /// Insert many colliders at once (single reserve) and return their contiguous
+    /// `FrameId` range. `motions[i]` pairs with `descs[i]`; missing motions default to still.
+    fn push_many(&mut self, descs: &[ColliderDesc], motions: &[Motion]) -> Range<u32>;
+
     /// Convenience: push a circle collider.
     fn push_circle(
         &mut self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
-        vel: Vec2,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId;
@@ -30,9 +44,9 @@ pub trait PhysicsWorldApi {
     /// Convenience: push an AABB collider (center + half extents).
     fn push_aabb(
         &mut self,
-        center: Vec2,
-        half_extents: Vec2,
-        vel: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId;
@@ -40,8 +54,8 @@ pub trait PhysicsWorldApi {
     /// Convenience: push a point collider.
     fn push_point(
         &mut self,
-        p: Vec2,
-        vel: Vec2,
+        p: impl Into<Vec2>,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId;
@@ -49,47 +63,147 @@ pub trait PhysicsWorldApi {
     /// Finalize insertions and build the uniform grid.
     fn end_frame(&mut self);
 
+    /// Colliders whose swept bounds (start to end of the frame's motion) lie entirely
+    /// outside `WorldConfig::world_bounds`, including ones with non-finite bounds. Empty
+    /// when no bounds are configured.
+    fn out_of_bounds(&self) -> Vec<FrameId>;
+
     /// Run broadphase & narrowphase and fill the internal event buffer.
+    ///
+    /// Per pair, `WorldConfig::overlap_policy` picks the event kind(s). With the default
+    /// `SweepThenOverlapFallback`, a pair moving relative to each other emits a Sweep if
+    /// the sweep hits, otherwise an Overlap if the start positions overlap; a pair at rest
+    /// can only emit an Overlap. So at most one event per pair, even with both kinds
+    /// enabled. Use `OverlapPolicy::Both` to get one of each.
     fn generate_events(&mut self);
 
-    /// Drain and return the accumulated events for this frame.
+    /// Like `generate_events`, but hands each event to `sink` as it's produced instead of
+    /// buffering it. `max_events` caps the number of calls; the internal buffer is untouched.
+    fn generate_events_with(&mut self, sink: impl FnMut(&Event));
+
+    /// Test a transient collider against this frame's colliders, statics and tiles (after
+    /// `end_frame`) without adding it to the frame. The earliest resulting event (overlaps
+    /// count as `toi = 0`) is appended to the event buffer, space permitting, and returned.
+    /// The probe is `a`, as `BodyRef::Collider(FrameId::PROBE)`.
+    fn sweep_and_emit(&mut self, desc: ColliderDesc, motion: Motion) -> Option<Event>;
+
+    /// Drain and return the accumulated events for this frame. Optional: the buffer is
+    /// cleared by the next `begin_frame` anyway.
     fn drain_events(&mut self) -> Vec<Event>;
 
+    /// This frame's buffered events, without draining. Stays valid for any number of
+    /// readers until `begin_frame` (or `drain_events`) clears it.
+    fn events(&self) -> &[Event];
+
     // --- Queries -----------------------------------------------------------
 
     /// Raycast against the current frame's colliders. Returns closest hit.
     fn raycast(
         &self,
-        origin: Vec2,
-        dir: Vec2,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
         mask: LayerMask,
         max_t: f32,
     ) -> Option<(FrameId, SweepHit, Option<ColKey>)>;
 
+    /// Ray test against one known collider, skipping the broadphase and masks. `None` for a
+    /// miss, a hit outside `[0, max_t]`, or an id not in the current frame.
+    fn raycast_one(
+        &self,
+        id: FrameId,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        max_t: f32,
+    ) -> Option<SweepHit>;
+
     /// Return all colliders whose shapes contain the point `p` (after masking).
-    fn query_point(&self, p: Vec2, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)>;
+    fn query_point(&self, p: impl Into<Vec2>, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)>;
 
     /// Return all colliders overlapping the given centered AABB.
     fn query_aabb(
         &self,
-        center: Vec2,
-        half_extents: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)>;
+
+    /// `query_aabb` into caller-owned buffers: `seen` and `out` are cleared and reused, so
+    /// hot query loops don't allocate once they've grown.
+    fn query_aabb_into(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+        seen: &mut HashSet<u32>,
+        out: &mut Vec<(FrameId, Option<ColKey>)>,
+    );
+
+    /// [`query_aabb`](Self::query_aabb) with a key pre-filter applied inside the scan,
+    /// before the shape test. Colliders without a key are kept iff `include_unkeyed`.
+    fn query_aabb_filtered(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
         mask: LayerMask,
+        include_unkeyed: bool,
+        keep: impl Fn(ColKey) -> bool,
     ) -> Vec<(FrameId, Option<ColKey>)>;
 
+    /// Colliders whose path this frame enters the region, with the entry time (0 if already
+    /// inside; `toi` units, like `sweep_pair_interval`), earliest first. Candidates come
+    /// from the broadphase bounds built in `end_frame`, so an untightened mover is only found
+    /// if its start bounds reach the region; each is then swept exactly against the box
+    /// (circles against the box grown by the radius). Motion is ignored in discrete mode.
+    fn query_aabb_swept_region(
+        &self,
+        region_center: impl Into<Vec2>,
+        region_half: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, f32, Option<ColKey>)>;
+
     /// Return all colliders overlapping the given circle.
     fn query_circle(
         &self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
         mask: LayerMask,
     ) -> Vec<(FrameId, Option<ColKey>)>;
 
+    // --- Static colliders ---------------------------------------------------
+
+    /// Insert a static collider that persists across `begin_frame` (zero velocity).
+    /// Statics pair with frame colliders in events and appear in the `*_all` queries.
+    fn add_static(&mut self, desc: ColliderDesc) -> StaticId;
+
+    /// Remove all static colliders. Previously returned `StaticId`s become invalid.
+    fn clear_statics(&mut self);
+
     // --- Tilemap lifecycle --------------------------------------------------
 
     /// Attach a tilemap layer. Multiple tilemaps are allowed.
+    ///
+    /// An invalid description trips a debug assertion; release builds attach an empty map
+    /// in its place. See `try_attach_tilemap` to handle the error instead.
     fn attach_tilemap(&mut self, desc: TileMapDesc) -> TileMapRef;
 
+    /// Attach a tilemap after checking that `cell` is finite and positive, `origin` is
+    /// finite, and `solids.len() == width * height`.
+    fn try_attach_tilemap(&mut self, desc: TileMapDesc) -> Result<TileMapRef, TileMapError>;
+
+    /// Attach a bit-packed tilemap (1 bit per cell). Queries and `update_tiles` treat it
+    /// like a byte map whose values are 0 or 1.
+    ///
+    /// An invalid description trips a debug assertion; release builds attach an empty map
+    /// in its place. See `try_attach_tilemap_bits` to handle the error instead.
+    fn attach_tilemap_bits(&mut self, desc: TileMapBitsDesc) -> TileMapRef;
+
+    /// `try_attach_tilemap` for a bit-packed map: `bits.len()` must be
+    /// `(width * height).div_ceil(64)`.
+    fn try_attach_tilemap_bits(
+        &mut self,
+        desc: TileMapBitsDesc,
+    ) -> Result<TileMapRef, TileMapError>;
+
     /// Update a rectangular region (x,y,w,h) of the tile buffer for `map`.
     /// `data.len()` must equal `w*h` (row-major).
     fn update_tiles(&mut self, map: TileMapRef, changed_rect: (u32, u32, u32, u32), data: &[u8]);
@@ -97,43 +211,178 @@ pub trait PhysicsWorldApi {
     /// Detach and free a tilemap.
     fn detach_tilemap(&mut self, map: TileMapRef);
 
+    /// Velocity of a moving tilemap (elevators, platforms); maps start still. Timed tile
+    /// sweeps (events, `sweep_*_tiles`) run in the map's frame, so a resting collider is
+    /// swept into a rising map. `cast` and rays still treat maps as still.
+    fn set_tilemap_velocity(&mut self, map: TileMapRef, vel: Vec2);
+
+    /// Move a tilemap to a new `origin` (its position at the start of the frame). The
+    /// world never advances it from the velocity on its own.
+    fn set_tilemap_origin(&mut self, map: TileMapRef, origin: Vec2);
+
+    /// Rows written by `update_tiles` since the last `clear_dirty` (sorted, unique).
+    /// Use to rebuild derived per-row data for changed regions only.
+    fn dirty_rows(&self, map: TileMapRef) -> &[u32];
+
+    /// Reset the dirty row set for `map`.
+    fn clear_dirty(&mut self, map: TileMapRef);
+
+    /// Raw tile byte at `cell` (None if the map is missing or the cell is out of bounds).
+    fn tile_value(&self, map: TileMapRef, cell: UVec2) -> Option<u8>;
+
+    /// World-space `(min, max)` of a tile cell (None if the map or cell doesn't exist).
+    fn tile_aabb(&self, t: TileRef) -> Option<(Vec2, Vec2)>;
+
+    /// World-space `(min, max)` covering the whole tilemap.
+    fn tilemap_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)>;
+
+    /// World-space `(min, max)` of the smallest cell rect holding every solid tile, kept
+    /// current by `update_tiles`. None if the map is missing or has no solid tiles. Tile
+    /// queries and rays already skip whatever lies outside it.
+    fn tilemap_solid_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)>;
+
+    /// Attached tilemaps in `TileMapRef` order, with their layout and filtering.
+    fn tilemaps(&self) -> impl Iterator<Item = (TileMapRef, TileMapInfo)>;
+
+    /// Raw `solids` bytes of a byte tilemap (`y * width + x`). None if the map is missing or
+    /// bit-packed; read those through `tile_value`.
+    fn tile_solids(&self, map: TileMapRef) -> Option<&[u8]>;
+
+    /// Map-independent key for a tile's world cell: `(origin / cell).round()` plus the cell
+    /// index per axis, packed as `x << 32 | y` (two's complement, wrapping to 32 bits).
+    /// Equal for aligned maps sharing a cell size and unaffected by re-attaching. None if
+    /// the map or cell doesn't exist.
+    fn tile_world_key(&self, t: TileRef) -> Option<u64>;
+
     // --- Unified queries (colliders + tiles; closest or full set) ----------
 
     /// Raycast against colliders and tiles; returns the closest hit.
     fn raycast_all(
         &self,
-        origin: Vec2,
-        dir: Vec2,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
         mask: LayerMask,
         max_t: f32,
     ) -> Option<(BodyRef, SweepHit, Option<ColKey>)>;
 
+    /// [`raycast_all`](Self::raycast_all) returning the hit body's [`BodyDesc`] (shape,
+    /// center and mask; map mask and cell for tiles) instead of just its key.
+    fn raycast_desc(
+        &self,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, BodyDesc)>;
+
     /// Return all bodies (collider or tile) containing the point.
-    fn query_point_all(&self, p: Vec2, mask: LayerMask) -> Vec<(BodyRef, Option<ColKey>)>;
+    fn query_point_all(
+        &self,
+        p: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)>;
 
     /// Return all bodies overlapping the AABB.
+    ///
+    /// Tiles are reported per map: two maps with a solid cell at the same world position
+    /// yield two `BodyRef::Tile` hits. Use `query_aabb_solid_cells` for one hit per cell.
     fn query_aabb_all(
         &self,
-        center: Vec2,
-        half_extents: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
         mask: LayerMask,
     ) -> Vec<(BodyRef, Option<ColKey>)>;
 
-    /// Return all bodies overlapping the circle.
+    /// Return all bodies overlapping the circle (tiles per map, as in `query_aabb_all`).
     fn query_circle_all(
         &self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
         mask: LayerMask,
     ) -> Vec<(BodyRef, Option<ColKey>)>;
 
+    /// Return all bodies overlapping the rectangle `half_extents` rotated by `angle` radians
+    /// (counter-clockwise) about `center`. Candidates come from its bounding box; boxes and
+    /// tiles are confirmed with SAT, circles and points exactly.
+    fn query_obb_all(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        angle: f32,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)>;
+
+    /// `query_point_all`, split into colliders, statics and tiles.
+    fn query_point_grouped(&self, p: impl Into<Vec2>, mask: LayerMask) -> BodiesByKind;
+
+    /// `query_aabb_all`, split into colliders, statics and tiles.
+    fn query_aabb_grouped(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> BodiesByKind;
+
+    /// `query_circle_all`, split into colliders, statics and tiles.
+    fn query_circle_grouped(
+        &self,
+        center: impl Into<Vec2>,
+        radius: f32,
+        mask: LayerMask,
+    ) -> BodiesByKind;
+
+    /// Solid tile cells overlapping the AABB, collapsed by world position: when maps
+    /// overlap exactly, the lowest map index wins.
+    fn query_aabb_solid_cells(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(TileMapRef, UVec2)>;
+
+    /// Cells of `map` whose solid tiles overlap the circle, ignoring layer masks. Feed the
+    /// result to `update_tiles` to carve destructible terrain. Empty if `map` doesn't exist.
+    fn solid_tiles_in_circle(
+        &self,
+        center: impl Into<Vec2>,
+        radius: f32,
+        map: TileMapRef,
+    ) -> Vec<UVec2>;
+
+    /// Solid tiles overlapping a convex polygon (world-space `points`, SAT per tile).
+    fn query_polygon_tiles(&self, points: &[Vec2], mask: LayerMask) -> Vec<TileRef>;
+
+    /// Cast `shape` from `origin` along `dir` for `t ∈ [0, max_t]` against colliders, statics,
+    /// and tiles; returns the closest hit with `toi` in units of `t` (like `raycast_all`).
+    /// `Point` is a plain ray; other shapes need a finite `max_t`. Targets are treated as still.
+    fn cast(
+        &self,
+        shape: ColliderKind,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)>;
+
+    /// Whether an AABB can move by `vel * dt` without hitting a collider, static or tile.
+    /// Agrees with `cast` + `sweep_aabb_tiles` but stops at the first blocker and skips
+    /// the TOI refinement.
+    fn path_clear_aabb(&self, center: Vec2, half_extents: Vec2, vel: Vec2, mask: LayerMask)
+    -> bool;
+
+    /// `path_clear_aabb` for a circle.
+    fn path_clear_circle(&self, center: Vec2, radius: f32, vel: Vec2, mask: LayerMask) -> bool;
+
+    /// `path_clear_aabb` for a point.
+    fn path_clear_point(&self, p: Vec2, vel: Vec2, mask: LayerMask) -> bool;
+
     // --- Tile-only fast path (for profiling / direct control) ---------------
 
     /// Raycast against tiles only (closest hit across all tilemaps).
     fn raycast_tiles(
         &self,
-        origin: Vec2,
-        dir: Vec2,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
         max_t: f32,
         mask: LayerMask,
     ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
@@ -156,14 +405,109 @@ pub trait PhysicsWorldApi {
         mask: LayerMask,
     ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
 
+    /// Tile-only sweep for a vertical capsule: a core segment of `±half_height` along Y
+    /// inflated by `radius`. There is no capsule collider kind; this is a standalone query.
+    fn sweep_capsule_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        half_height: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+
+    /// Tile-only sweep for a convex polygon given as world-space `points` (any winding).
+    /// Tiles under the polygon's bounds are confirmed with SAT; fewer than 3 points never hit.
+    fn sweep_polygon_tiles(
+        &self,
+        points: &[Vec2],
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+
+    /// Every solid tile overlapping the AABB at rest, with pushout info (normal from the
+    /// tile into the box). The discrete counterpart of `sweep_aabb_tiles`.
+    fn overlap_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)>;
+
+    /// Every solid tile overlapping the circle at rest, with pushout info.
+    fn overlap_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)>;
+
+    /// Face normal of the solid tile nearest to `p`, pointing toward `p` (the
+    /// `aabb_tile_pushout` face for a point). Inside a solid cell this is the
+    /// minimum-penetration face. None when no allowed map has a solid tile.
+    fn tile_surface_normal(&self, p: Vec2, mask: LayerMask) -> Option<(TileRef, Vec2)>;
+
+    /// Sweep AABB against tiles and report up to two distinct face normals touched
+    /// at the first TOI (e.g., wall + floor when landing in an inside corner). The fixed
+    /// array keeps the call allocation-free; unused slots are `None`.
+    fn sweep_aabb_tiles_contacts(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, TileContacts)>;
+
+    /// Like `sweep_aabb_tiles_contacts`, but every solid cell touching the AABB at the
+    /// first TOI with its face normal, including faces the motion only slides along.
+    fn sweep_aabb_tiles_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, Vec<(TileRef, Vec2)>)>;
+
     // --- Pairwise checks ---------------------------------------------------
 
     /// Overlap test between two frame-local colliders (same-frame only).
     fn overlap_pair(&self, a: FrameId, b: FrameId) -> Option<Overlap>;
 
+    /// [`overlap_pair`](Self::overlap_pair) as a [`Manifold`] for resting contacts.
+    fn manifold_pair(&self, a: FrameId, b: FrameId) -> Option<Manifold>;
+
     /// Sweep test (relative velocity) between two frame-local colliders.
     fn sweep_pair(&self, a: FrameId, b: FrameId) -> Option<SweepHit>;
 
+    /// Enter and exit times of the pair's overlap along their relative motion, clamped to
+    /// the frame (`toi` units, like `sweep_pair`). `(0, _)` means they start overlapping and
+    /// `(_, 1)` that they still overlap at the end. Circle-box pairs use the box grown by
+    /// the radius, as `sweep_pair` does; points overlap within `point_eps`.
+    fn sweep_pair_interval(&self, a: FrameId, b: FrameId) -> Option<(f32, f32)>;
+
+    /// Resting contact: the pair overlaps with `depth <= eps` (edges touching, circles
+    /// tangent). Real penetration deeper than `eps` returns false.
+    fn touching_pair(&self, a: FrameId, b: FrameId, eps: f32) -> bool;
+
+    /// Overlap at the start positions, else the relative-motion sweep: distinguishes a pair
+    /// that starts inside each other from one that never meets.
+    fn cast_pair(&self, a: FrameId, b: FrameId) -> CastResult;
+
+    /// Deepest overlap between collider `id` and any collider, static, or tile allowed by
+    /// `mask` (ties keep the first found: colliders, then statics, then tiles). Normals
+    /// point from the other body into `id`.
+    fn deepest_overlap(&self, id: FrameId, mask: LayerMask) -> Option<(BodyRef, Overlap)>;
+
+    /// Overlaps between every collider of `group_a` and every collider of `group_b` (normals
+    /// from the `b` collider into the `a` one), pruned by the broadphase. Only pairs taking
+    /// one collider from each group are tested, masks are not consulted, and a pair found
+    /// both ways is reported once. Unknown or invalid ids are skipped.
+    fn overlaps_between(
+        &self,
+        group_a: &[FrameId],
+        group_b: &[FrameId],
+    ) -> Vec<(FrameId, FrameId, Overlap)>;
+
     /// Overlap test between two user keys (if unique keys were provided).
     fn overlap_by_key(&self, a: ColKey, b: ColKey) -> Option<Overlap>;
 
@@ -177,13 +521,33 @@ pub trait NarrowphaseApi {
 
     fn ray_aabb(origin: Vec2, dir: Vec2, aabb_min: Vec2, aabb_max: Vec2) -> Option<SweepHit>;
     fn ray_circle(origin: Vec2, dir: Vec2, center: Vec2, r: f32) -> Option<SweepHit>;
+    /// Ray against the capsule around segment `p0..p1` with radius `r`: the two cap circles
+    /// plus the two side segments offset by `r`. An origin inside hits at `toi = 0` with a
+    /// zero normal (like `ray_aabb`).
+    fn ray_capsule(origin: Vec2, dir: Vec2, p0: Vec2, p1: Vec2, r: f32) -> Option<SweepHit>;
     fn line_segment_aabb(a: Vec2, b: Vec2, aabb_min: Vec2, aabb_max: Vec2) -> Option<SweepHit>;
     fn line_segment_circle(a: Vec2, b: Vec2, center: Vec2, r: f32) -> Option<SweepHit>;
+    /// Crossing of segment `a0→a1` with `b0→b1`; `toi` is the fraction along `a`. Parallel → None.
+    fn segment_segment(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> Option<SweepHit>;
 
     // Overlaps --------------------------------------------------------------
 
     fn overlap_aabb_aabb(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2) -> Option<Overlap>;
+    /// `overlap_aabb_aabb`, but when the x and y penetrations are within `eps` it keeps the
+    /// axis `prev_normal` lies closest to (e.g. last frame's normal), so near-square
+    /// overlaps don't flip resolution direction. A zero `prev_normal` adds no bias.
+    fn overlap_aabb_aabb_biased(
+        c0: Vec2,
+        h0: Vec2,
+        c1: Vec2,
+        h1: Vec2,
+        prev_normal: Vec2,
+        eps: f32,
+    ) -> Option<Overlap>;
     fn overlap_circle_circle(c0: Vec2, r0: f32, c1: Vec2, r1: f32) -> Option<Overlap>;
+    fn overlap_circle_aabb(c: Vec2, r: f32, box_c: Vec2, box_h: Vec2) -> Option<Overlap>;
+    /// SAT test of a convex polygon (any winding, >= 3 points) against a box.
+    fn overlap_polygon_aabb(points: &[Vec2], box_c: Vec2, box_h: Vec2) -> Option<Overlap>;
     fn overlap_point_aabb(p: Vec2, c: Vec2, h: Vec2) -> bool;
     fn overlap_point_circle(p: Vec2, c: Vec2, r: f32) -> bool;
 
@@ -207,6 +571,17 @@ pub trait NarrowphaseApi {
         box_v: Vec2,
     ) -> Option<SweepHit>;
 
+    /// [`sweep_circle_aabb`](Self::sweep_circle_aabb) with the box as `A`: the normal points
+    /// from the circle into the box.
+    fn sweep_aabb_circle(
+        box_c: Vec2,
+        box_h: Vec2,
+        box_v: Vec2,
+        c: Vec2,
+        r: f32,
+        v: Vec2,
+    ) -> Option<SweepHit>;
+
     fn sweep_circle_circle(
         c0: Vec2,
         r0: f32,
@@ -0,0 +1,336 @@
+//! Alternative broadphase structures selected via `WorldConfig::broadphase`.
+//!
+//! These index the frame-local `aabbs` built in `end_frame`. The uniform grid
+//! lives directly in `world.rs`; everything here is rebuilt every frame.
+//! Candidate pairs are exact AABB overlaps, so no cross-cell deduplication is needed.
+//! Entries with non-finite bounds are left out of both structures.
+
+use glam::Vec2;
+use std::ops::ControlFlow;
+
+/// Max entries stored in a BVH leaf before splitting.
+const BVH_LEAF_SIZE: usize = 4;
+
+fn finite((min, max): (Vec2, Vec2)) -> bool {
+    min.is_finite() && max.is_finite()
+}
+
+#[derive(Copy, Clone, Debug)]
+struct BvhNode {
+    min: Vec2,
+    max: Vec2,
+    /// Internal: index of the left child (right is `left + 1`). Leaf: start into `order`.
+    first: u32,
+    /// Number of entries for leaves; 0 for internal nodes.
+    count: u32,
+}
+
+/// Bounding volume hierarchy over per-entry AABBs (median split on the longest axis).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Bvh {
+    nodes: Vec<BvhNode>,
+    order: Vec<usize>,
+    max_depth: usize,
+}
+
+impl Bvh {
+    /// Build a tree over `aabbs` (min, max); entry indices are positions in the slice.
+    pub(crate) fn build(aabbs: &[(Vec2, Vec2)]) -> Self {
+        let order: Vec<usize> = (0..aabbs.len()).filter(|&i| finite(aabbs[i])).collect();
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(order.len().max(1) * 2 / BVH_LEAF_SIZE + 1),
+            order,
+            max_depth: 0,
+        };
+        if bvh.order.is_empty() {
+            return bvh;
+        }
+        bvh.nodes.push(BvhNode {
+            min: Vec2::ZERO,
+            max: Vec2::ZERO,
+            first: 0,
+            count: 0,
+        });
+        bvh.build_node(aabbs, 0, 0, bvh.order.len(), 1);
+        bvh
+    }
+
+    fn build_node(
+        &mut self,
+        aabbs: &[(Vec2, Vec2)],
+        node: usize,
+        start: usize,
+        end: usize,
+        depth: usize,
+    ) {
+        self.max_depth = self.max_depth.max(depth);
+        let (mut min, mut max) = aabbs[self.order[start]];
+        for &i in &self.order[start + 1..end] {
+            min = min.min(aabbs[i].0);
+            max = max.max(aabbs[i].1);
+        }
+        self.nodes[node].min = min;
+        self.nodes[node].max = max;
+        let n = end - start;
+        if n <= BVH_LEAF_SIZE {
+            self.nodes[node].first = start as u32;
+            self.nodes[node].count = n as u32;
+            return;
+        }
+        // Median split on the longest axis of the node bounds (by AABB center)
+        let ext = max - min;
+        let axis = if ext.x >= ext.y { 0 } else { 1 };
+        let center = |i: usize| (aabbs[i].0[axis] + aabbs[i].1[axis]) * 0.5;
+        let mid = start + n / 2;
+        self.order[start..end]
+            .select_nth_unstable_by(n / 2, |&a, &b| center(a).total_cmp(&center(b)));
+        let left = self.nodes.len();
+        let empty = BvhNode {
+            min: Vec2::ZERO,
+            max: Vec2::ZERO,
+            first: 0,
+            count: 0,
+        };
+        self.nodes.push(empty);
+        self.nodes.push(empty);
+        self.nodes[node].first = left as u32;
+        self.nodes[node].count = 0;
+        self.build_node(aabbs, left, start, mid, depth + 1);
+        self.build_node(aabbs, left + 1, mid, end, depth + 1);
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Visit every entry whose leaf bounds overlap `[min, max]` (inclusive).
+    pub(crate) fn query(&self, min: Vec2, max: Vec2, mut f: impl FnMut(usize)) {
+        self.visit(|n| n.min.cmple(max).all() && n.max.cmpge(min).all(), &mut f);
+    }
+
+    /// Visit every entry in leaves hit by the ray segment `origin + dir * t`, `t ∈ [0, max_t]`.
+    pub(crate) fn query_ray(&self, origin: Vec2, dir: Vec2, max_t: f32, mut f: impl FnMut(usize)) {
+        let inv = dir.recip();
+        self.visit(
+            |n| {
+                let mut t0 = 0.0f32;
+                let mut t1 = max_t;
+                for axis in 0..2 {
+                    if dir[axis] == 0.0 {
+                        if origin[axis] < n.min[axis] || origin[axis] > n.max[axis] {
+                            return false;
+                        }
+                        continue;
+                    }
+                    let a = (n.min[axis] - origin[axis]) * inv[axis];
+                    let b = (n.max[axis] - origin[axis]) * inv[axis];
+                    t0 = t0.max(a.min(b));
+                    t1 = t1.min(a.max(b));
+                }
+                t0 <= t1
+            },
+            &mut f,
+        );
+    }
+
+    /// Visit each unordered pair `(i, j)`, `i < j`, whose AABBs overlap, until `f` breaks.
+    pub(crate) fn pairs(
+        &self,
+        aabbs: &[(Vec2, Vec2)],
+        mut f: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let mut flow = ControlFlow::Continue(());
+        for &i in &self.order {
+            let (min, max) = aabbs[i];
+            self.query(min, max, |j| {
+                if flow.is_continue()
+                    && j > i
+                    && aabbs[j].0.cmple(max).all()
+                    && aabbs[j].1.cmpge(min).all()
+                {
+                    flow = f(i, j);
+                }
+            });
+            flow?;
+        }
+        flow
+    }
+
+    fn visit(&self, hit: impl Fn(&BvhNode) -> bool, f: &mut impl FnMut(usize)) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let mut stack = vec![0usize];
+        while let Some(ni) = stack.pop() {
+            let n = &self.nodes[ni];
+            if !hit(n) {
+                continue;
+            }
+            if n.count > 0 {
+                let start = n.first as usize;
+                for &i in &self.order[start..start + n.count as usize] {
+                    f(i);
+                }
+            } else {
+                stack.push(n.first as usize + 1);
+                stack.push(n.first as usize);
+            }
+        }
+    }
+}
+
+/// Sort-and-sweep over per-entry AABBs: entries sorted by `min.x`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Sap {
+    order: Vec<usize>,
+}
+
+impl Sap {
+    /// Sort entry indices by `min.x`; entry indices are positions in `aabbs`.
+    pub(crate) fn build(aabbs: &[(Vec2, Vec2)]) -> Self {
+        let mut order: Vec<usize> = (0..aabbs.len()).filter(|&i| finite(aabbs[i])).collect();
+        order.sort_by(|&a, &b| aabbs[a].0.x.total_cmp(&aabbs[b].0.x));
+        Sap { order }
+    }
+
+    /// Visit every entry whose AABB overlaps `[min, max]` (inclusive).
+    pub(crate) fn query(
+        &self,
+        aabbs: &[(Vec2, Vec2)],
+        min: Vec2,
+        max: Vec2,
+        mut f: impl FnMut(usize),
+    ) {
+        // Only the prefix with min.x <= max.x can overlap
+        let end = self.order.partition_point(|&i| aabbs[i].0.x <= max.x);
+        for &i in &self.order[..end] {
+            let (bmin, bmax) = aabbs[i];
+            if bmax.x >= min.x && bmin.y <= max.y && bmax.y >= min.y {
+                f(i);
+            }
+        }
+    }
+
+    /// Visit each unordered pair `(i, j)`, `i < j`, whose AABBs overlap, until `f` breaks.
+    pub(crate) fn pairs(
+        &self,
+        aabbs: &[(Vec2, Vec2)],
+        mut f: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        for (k, &i) in self.order.iter().enumerate() {
+            let (amin, amax) = aabbs[i];
+            for &j in &self.order[k + 1..] {
+                let (bmin, bmax) = aabbs[j];
+                if bmin.x > amax.x {
+                    break;
+                }
+                if bmin.y <= amax.y && bmax.y >= amin.y {
+                    f(i.min(j), i.max(j))?;
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boxes() -> Vec<(Vec2, Vec2)> {
+        let mut v: Vec<(Vec2, Vec2)> = (0..20)
+            .map(|i| {
+                let c = Vec2::new(i as f32 * 3.0, 0.0);
+                (c - Vec2::splat(0.5), c + Vec2::splat(0.5))
+            })
+            .collect();
+        // One giant box spanning everything
+        v.push((Vec2::new(-1.0, -1.0), Vec2::new(100.0, 1.0)));
+        v
+    }
+
+    #[test]
+    fn test_bvh_pairs_match_brute_force() {
+        let aabbs = boxes();
+        let bvh = Bvh::build(&aabbs);
+        let mut got = Vec::new();
+        let _ = bvh.pairs(&aabbs, |i, j| {
+            got.push((i, j));
+            ControlFlow::Continue(())
+        });
+        got.sort();
+        let mut want = Vec::new();
+        for i in 0..aabbs.len() {
+            for j in (i + 1)..aabbs.len() {
+                let (a, b) = (aabbs[i], aabbs[j]);
+                if a.0.cmple(b.1).all() && b.0.cmple(a.1).all() {
+                    want.push((i, j));
+                }
+            }
+        }
+        assert_eq!(got, want);
+        assert!(bvh.max_depth() >= 2);
+        assert!(bvh.node_count() > 1);
+    }
+
+    #[test]
+    fn test_sap_pairs_match_bvh() {
+        let aabbs = boxes();
+        let mut bvh_pairs = Vec::new();
+        let _ = Bvh::build(&aabbs).pairs(&aabbs, |i, j| {
+            bvh_pairs.push((i, j));
+            ControlFlow::Continue(())
+        });
+        bvh_pairs.sort();
+        let sap = Sap::build(&aabbs);
+        let mut sap_pairs = Vec::new();
+        let _ = sap.pairs(&aabbs, |i, j| {
+            sap_pairs.push((i, j));
+            ControlFlow::Continue(())
+        });
+        sap_pairs.sort();
+        assert_eq!(sap_pairs, bvh_pairs);
+        let mut hits = Vec::new();
+        sap.query(&aabbs, Vec2::new(2.8, -0.1), Vec2::new(3.2, 0.1), |i| {
+            hits.push(i)
+        });
+        hits.sort();
+        assert_eq!(hits, vec![1, 20]);
+    }
+
+    #[test]
+    fn test_bvh_ray_visits_only_crossed_leaves() {
+        let aabbs = boxes();
+        let bvh = Bvh::build(&aabbs);
+        let mut seen = Vec::new();
+        bvh.query_ray(Vec2::new(-5.0, 10.0), Vec2::X, 200.0, |i| seen.push(i));
+        assert!(seen.is_empty());
+        bvh.query_ray(Vec2::new(-5.0, 0.0), Vec2::X, 200.0, |i| seen.push(i));
+        assert_eq!(seen.len(), aabbs.len());
+    }
+
+    #[test]
+    fn test_pairs_stop_when_visitor_breaks() {
+        let aabbs = boxes();
+        let third = |calls: &mut usize| {
+            *calls += 1;
+            if *calls == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        let mut calls = 0;
+        let flow = Bvh::build(&aabbs).pairs(&aabbs, |_, _| third(&mut calls));
+        assert!(flow.is_break());
+        assert_eq!(calls, 3);
+        let mut calls = 0;
+        let flow = Sap::build(&aabbs).pairs(&aabbs, |_, _| third(&mut calls));
+        assert!(flow.is_break());
+        assert_eq!(calls, 3);
+    }
+}
@@ -0,0 +1,825 @@
+//! Dyn-compatible mirror of [`PhysicsWorldApi`] for holding worlds as trait objects.
+//!
+//! `PhysicsWorldApi` has a `Self: Sized` constructor and generic convenience parameters
+//! (`impl Into<Vec2>`, `impl FnMut`), so it can't be used as `dyn`. [`PhysicsWorldDyn`]
+//! carries every method except `new`, with concrete parameter types, and is implemented
+//! for every `PhysicsWorldApi` type:
+//!
+//! ```
+//! use nobonk::dyn_api::PhysicsWorldDyn;
+//! use nobonk::{
+//!     Broadphase, OverlapPolicy, PhysicsWorld, PhysicsWorldApi, TiePreference, WorldConfig,
+//! };
+//!
+//! fn make() -> Box<dyn PhysicsWorldDyn> {
+//!     Box::new(PhysicsWorld::new(WorldConfig {
+//!         cell_size: 1.0,
+//!         dt: 1.0,
+//!         tighten_swept_aabb: true,
+//!         enable_overlap_events: true,
+//!         enable_sweep_events: true,
+//!         max_events: 64,
+//!         enable_timing: false,
+//!         tile_eps: 1e-4,
+//!         require_mutual_consent: true,
+//!         skin_width: 0.0,
+//!         broadphase: Broadphase::Grid,
+//!         overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+//!         square_circle_tile_sweep: false,
+//!         grid_shrink_interval: 0,
+//!         sort_overlaps_by_depth: false,
+//!         toi_in_seconds: false,
+//!         ray_tie_preference: TiePreference::Collider,
+//!         raycast_skip_start_solid: false,
+//!         world_bounds: None,
+//!         point_eps: 0.0,
+//!         tile_events_per_cell: false,
+//!         large_collider_cell_threshold: 0,
+//!         contact_skin: 0.0,
+//!     }))
+//! }
+//! let mut world = make();
+//! world.begin_frame();
+//! world.end_frame();
+//! ```
+//!
+//! The trait isn't re-exported from the crate root: with both traits in scope, method calls
+//! on a concrete `PhysicsWorld` would be ambiguous. Import it where the trait object lives.
+
+use glam::{UVec2, Vec2};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::api::PhysicsWorldApi;
+use crate::types::*;
+
+/// Object-safe counterpart of [`PhysicsWorldApi`]; see the module docs.
+pub trait PhysicsWorldDyn {
+    fn begin_frame(&mut self);
+    fn config(&self) -> &WorldConfig;
+    fn set_config(&mut self, cfg: WorldConfig);
+    fn push(&mut self, desc: ColliderDesc, motion: Motion) -> FrameId;
+    fn push_many(&mut self, descs: &[ColliderDesc], motions: &[Motion]) -> Range<u32>;
+    fn push_circle(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId;
+    fn push_aabb(
+        &mut self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId;
+    fn push_point(
+        &mut self,
+        p: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId;
+    fn end_frame(&mut self);
+    fn out_of_bounds(&self) -> Vec<FrameId>;
+    fn generate_events(&mut self);
+    fn generate_events_with(&mut self, sink: &mut dyn FnMut(&Event));
+    fn sweep_and_emit(&mut self, desc: ColliderDesc, motion: Motion) -> Option<Event>;
+    fn drain_events(&mut self) -> Vec<Event>;
+    fn events(&self) -> &[Event];
+    fn raycast(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(FrameId, SweepHit, Option<ColKey>)>;
+    fn raycast_one(&self, id: FrameId, origin: Vec2, dir: Vec2, max_t: f32) -> Option<SweepHit>;
+    fn query_point(&self, p: Vec2, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)>;
+    fn query_aabb(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)>;
+    fn query_aabb_into(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+        seen: &mut HashSet<u32>,
+        out: &mut Vec<(FrameId, Option<ColKey>)>,
+    );
+    fn query_aabb_filtered(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+        include_unkeyed: bool,
+        keep: &dyn Fn(ColKey) -> bool,
+    ) -> Vec<(FrameId, Option<ColKey>)>;
+    fn query_aabb_swept_region(
+        &self,
+        region_center: Vec2,
+        region_half: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, f32, Option<ColKey>)>;
+    fn query_circle(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)>;
+    fn add_static(&mut self, desc: ColliderDesc) -> StaticId;
+    fn clear_statics(&mut self);
+    fn attach_tilemap(&mut self, desc: TileMapDesc) -> TileMapRef;
+    fn try_attach_tilemap(&mut self, desc: TileMapDesc) -> Result<TileMapRef, TileMapError>;
+    fn attach_tilemap_bits(&mut self, desc: TileMapBitsDesc) -> TileMapRef;
+    fn try_attach_tilemap_bits(
+        &mut self,
+        desc: TileMapBitsDesc,
+    ) -> Result<TileMapRef, TileMapError>;
+    fn update_tiles(&mut self, map: TileMapRef, changed_rect: (u32, u32, u32, u32), data: &[u8]);
+    fn detach_tilemap(&mut self, map: TileMapRef);
+    fn set_tilemap_velocity(&mut self, map: TileMapRef, vel: Vec2);
+    fn set_tilemap_origin(&mut self, map: TileMapRef, origin: Vec2);
+    fn dirty_rows(&self, map: TileMapRef) -> &[u32];
+    fn clear_dirty(&mut self, map: TileMapRef);
+    fn tile_value(&self, map: TileMapRef, cell: UVec2) -> Option<u8>;
+    fn tile_aabb(&self, t: TileRef) -> Option<(Vec2, Vec2)>;
+    fn tilemap_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)>;
+    fn tilemap_solid_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)>;
+    fn tilemaps(&self) -> Vec<(TileMapRef, TileMapInfo)>;
+    fn tile_solids(&self, map: TileMapRef) -> Option<&[u8]>;
+    fn tile_world_key(&self, t: TileRef) -> Option<u64>;
+    fn raycast_all(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)>;
+    fn raycast_desc(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, BodyDesc)>;
+    fn query_point_all(&self, p: Vec2, mask: LayerMask) -> Vec<(BodyRef, Option<ColKey>)>;
+    fn query_aabb_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)>;
+    fn query_circle_all(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)>;
+    fn query_obb_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        angle: f32,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)>;
+    fn query_point_grouped(&self, p: Vec2, mask: LayerMask) -> BodiesByKind;
+    fn query_aabb_grouped(&self, center: Vec2, half_extents: Vec2, mask: LayerMask)
+    -> BodiesByKind;
+    fn query_circle_grouped(&self, center: Vec2, radius: f32, mask: LayerMask) -> BodiesByKind;
+    fn query_aabb_solid_cells(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileMapRef, UVec2)>;
+    fn solid_tiles_in_circle(&self, center: Vec2, radius: f32, map: TileMapRef) -> Vec<UVec2>;
+    fn query_polygon_tiles(&self, points: &[Vec2], mask: LayerMask) -> Vec<TileRef>;
+    fn cast(
+        &self,
+        shape: ColliderKind,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)>;
+    fn path_clear_aabb(&self, center: Vec2, half_extents: Vec2, vel: Vec2, mask: LayerMask)
+    -> bool;
+    fn path_clear_circle(&self, center: Vec2, radius: f32, vel: Vec2, mask: LayerMask) -> bool;
+    fn path_clear_point(&self, p: Vec2, vel: Vec2, mask: LayerMask) -> bool;
+    fn raycast_tiles(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        max_t: f32,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+    fn sweep_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+    fn sweep_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+    fn sweep_capsule_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        half_height: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+    fn sweep_polygon_tiles(
+        &self,
+        points: &[Vec2],
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)>;
+    fn overlap_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)>;
+    fn overlap_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)>;
+    fn tile_surface_normal(&self, p: Vec2, mask: LayerMask) -> Option<(TileRef, Vec2)>;
+    fn sweep_aabb_tiles_contacts(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, TileContacts)>;
+    fn sweep_aabb_tiles_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, Vec<(TileRef, Vec2)>)>;
+    fn overlap_pair(&self, a: FrameId, b: FrameId) -> Option<Overlap>;
+    fn manifold_pair(&self, a: FrameId, b: FrameId) -> Option<Manifold>;
+    fn sweep_pair(&self, a: FrameId, b: FrameId) -> Option<SweepHit>;
+    fn sweep_pair_interval(&self, a: FrameId, b: FrameId) -> Option<(f32, f32)>;
+    fn touching_pair(&self, a: FrameId, b: FrameId, eps: f32) -> bool;
+    fn cast_pair(&self, a: FrameId, b: FrameId) -> CastResult;
+    fn deepest_overlap(&self, id: FrameId, mask: LayerMask) -> Option<(BodyRef, Overlap)>;
+    fn overlaps_between(
+        &self,
+        group_a: &[FrameId],
+        group_b: &[FrameId],
+    ) -> Vec<(FrameId, FrameId, Overlap)>;
+    fn overlap_by_key(&self, a: ColKey, b: ColKey) -> Option<Overlap>;
+    fn sweep_by_key(&self, a: ColKey, b: ColKey) -> Option<SweepHit>;
+}
+
+impl<W: PhysicsWorldApi> PhysicsWorldDyn for W {
+    fn begin_frame(&mut self) {
+        PhysicsWorldApi::begin_frame(self)
+    }
+
+    fn config(&self) -> &WorldConfig {
+        PhysicsWorldApi::config(self)
+    }
+
+    fn set_config(&mut self, cfg: WorldConfig) {
+        PhysicsWorldApi::set_config(self, cfg)
+    }
+
+    fn push(&mut self, desc: ColliderDesc, motion: Motion) -> FrameId {
+        PhysicsWorldApi::push(self, desc, motion)
+    }
+
+    fn push_many(&mut self, descs: &[ColliderDesc], motions: &[Motion]) -> Range<u32> {
+        PhysicsWorldApi::push_many(self, descs, motions)
+    }
+
+    fn push_circle(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId {
+        PhysicsWorldApi::push_circle(self, center, radius, vel, mask, user_key)
+    }
+
+    fn push_aabb(
+        &mut self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId {
+        PhysicsWorldApi::push_aabb(self, center, half_extents, vel, mask, user_key)
+    }
+
+    fn push_point(
+        &mut self,
+        p: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+    ) -> FrameId {
+        PhysicsWorldApi::push_point(self, p, vel, mask, user_key)
+    }
+
+    fn end_frame(&mut self) {
+        PhysicsWorldApi::end_frame(self)
+    }
+
+    fn out_of_bounds(&self) -> Vec<FrameId> {
+        PhysicsWorldApi::out_of_bounds(self)
+    }
+
+    fn generate_events(&mut self) {
+        PhysicsWorldApi::generate_events(self)
+    }
+
+    fn generate_events_with(&mut self, sink: &mut dyn FnMut(&Event)) {
+        PhysicsWorldApi::generate_events_with(self, |e| sink(e))
+    }
+
+    fn sweep_and_emit(&mut self, desc: ColliderDesc, motion: Motion) -> Option<Event> {
+        PhysicsWorldApi::sweep_and_emit(self, desc, motion)
+    }
+
+    fn drain_events(&mut self) -> Vec<Event> {
+        PhysicsWorldApi::drain_events(self)
+    }
+
+    fn events(&self) -> &[Event] {
+        PhysicsWorldApi::events(self)
+    }
+
+    fn raycast(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(FrameId, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::raycast(self, origin, dir, mask, max_t)
+    }
+
+    fn raycast_one(&self, id: FrameId, origin: Vec2, dir: Vec2, max_t: f32) -> Option<SweepHit> {
+        PhysicsWorldApi::raycast_one(self, id, origin, dir, max_t)
+    }
+
+    fn query_point(&self, p: Vec2, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)> {
+        PhysicsWorldApi::query_point(self, p, mask)
+    }
+
+    fn query_aabb(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        PhysicsWorldApi::query_aabb(self, center, half_extents, mask)
+    }
+
+    fn query_aabb_into(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+        seen: &mut HashSet<u32>,
+        out: &mut Vec<(FrameId, Option<ColKey>)>,
+    ) {
+        PhysicsWorldApi::query_aabb_into(self, center, half_extents, mask, seen, out)
+    }
+
+    fn query_aabb_filtered(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+        include_unkeyed: bool,
+        keep: &dyn Fn(ColKey) -> bool,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        PhysicsWorldApi::query_aabb_filtered(
+            self,
+            center,
+            half_extents,
+            mask,
+            include_unkeyed,
+            keep,
+        )
+    }
+
+    fn query_aabb_swept_region(
+        &self,
+        region_center: Vec2,
+        region_half: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, f32, Option<ColKey>)> {
+        PhysicsWorldApi::query_aabb_swept_region(self, region_center, region_half, mask)
+    }
+
+    fn query_circle(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        PhysicsWorldApi::query_circle(self, center, radius, mask)
+    }
+
+    fn add_static(&mut self, desc: ColliderDesc) -> StaticId {
+        PhysicsWorldApi::add_static(self, desc)
+    }
+
+    fn clear_statics(&mut self) {
+        PhysicsWorldApi::clear_statics(self)
+    }
+
+    fn attach_tilemap(&mut self, desc: TileMapDesc) -> TileMapRef {
+        PhysicsWorldApi::attach_tilemap(self, desc)
+    }
+
+    fn try_attach_tilemap(&mut self, desc: TileMapDesc) -> Result<TileMapRef, TileMapError> {
+        PhysicsWorldApi::try_attach_tilemap(self, desc)
+    }
+
+    fn attach_tilemap_bits(&mut self, desc: TileMapBitsDesc) -> TileMapRef {
+        PhysicsWorldApi::attach_tilemap_bits(self, desc)
+    }
+
+    fn try_attach_tilemap_bits(
+        &mut self,
+        desc: TileMapBitsDesc,
+    ) -> Result<TileMapRef, TileMapError> {
+        PhysicsWorldApi::try_attach_tilemap_bits(self, desc)
+    }
+
+    fn update_tiles(&mut self, map: TileMapRef, changed_rect: (u32, u32, u32, u32), data: &[u8]) {
+        PhysicsWorldApi::update_tiles(self, map, changed_rect, data)
+    }
+
+    fn detach_tilemap(&mut self, map: TileMapRef) {
+        PhysicsWorldApi::detach_tilemap(self, map)
+    }
+
+    fn set_tilemap_velocity(&mut self, map: TileMapRef, vel: Vec2) {
+        PhysicsWorldApi::set_tilemap_velocity(self, map, vel)
+    }
+
+    fn set_tilemap_origin(&mut self, map: TileMapRef, origin: Vec2) {
+        PhysicsWorldApi::set_tilemap_origin(self, map, origin)
+    }
+
+    fn dirty_rows(&self, map: TileMapRef) -> &[u32] {
+        PhysicsWorldApi::dirty_rows(self, map)
+    }
+
+    fn clear_dirty(&mut self, map: TileMapRef) {
+        PhysicsWorldApi::clear_dirty(self, map)
+    }
+
+    fn tile_value(&self, map: TileMapRef, cell: UVec2) -> Option<u8> {
+        PhysicsWorldApi::tile_value(self, map, cell)
+    }
+
+    fn tile_aabb(&self, t: TileRef) -> Option<(Vec2, Vec2)> {
+        PhysicsWorldApi::tile_aabb(self, t)
+    }
+
+    fn tilemap_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)> {
+        PhysicsWorldApi::tilemap_bounds(self, map)
+    }
+
+    fn tilemap_solid_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)> {
+        PhysicsWorldApi::tilemap_solid_bounds(self, map)
+    }
+
+    fn tilemaps(&self) -> Vec<(TileMapRef, TileMapInfo)> {
+        PhysicsWorldApi::tilemaps(self).collect()
+    }
+
+    fn tile_solids(&self, map: TileMapRef) -> Option<&[u8]> {
+        PhysicsWorldApi::tile_solids(self, map)
+    }
+
+    fn tile_world_key(&self, t: TileRef) -> Option<u64> {
+        PhysicsWorldApi::tile_world_key(self, t)
+    }
+
+    fn raycast_all(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::raycast_all(self, origin, dir, mask, max_t)
+    }
+
+    fn raycast_desc(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, BodyDesc)> {
+        PhysicsWorldApi::raycast_desc(self, origin, dir, mask, max_t)
+    }
+
+    fn query_point_all(&self, p: Vec2, mask: LayerMask) -> Vec<(BodyRef, Option<ColKey>)> {
+        PhysicsWorldApi::query_point_all(self, p, mask)
+    }
+
+    fn query_aabb_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)> {
+        PhysicsWorldApi::query_aabb_all(self, center, half_extents, mask)
+    }
+
+    fn query_circle_all(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)> {
+        PhysicsWorldApi::query_circle_all(self, center, radius, mask)
+    }
+
+    fn query_obb_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        angle: f32,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)> {
+        PhysicsWorldApi::query_obb_all(self, center, half_extents, angle, mask)
+    }
+
+    fn query_point_grouped(&self, p: Vec2, mask: LayerMask) -> BodiesByKind {
+        PhysicsWorldApi::query_point_grouped(self, p, mask)
+    }
+
+    fn query_aabb_grouped(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> BodiesByKind {
+        PhysicsWorldApi::query_aabb_grouped(self, center, half_extents, mask)
+    }
+
+    fn query_circle_grouped(&self, center: Vec2, radius: f32, mask: LayerMask) -> BodiesByKind {
+        PhysicsWorldApi::query_circle_grouped(self, center, radius, mask)
+    }
+
+    fn query_aabb_solid_cells(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileMapRef, UVec2)> {
+        PhysicsWorldApi::query_aabb_solid_cells(self, center, half_extents, mask)
+    }
+
+    fn solid_tiles_in_circle(&self, center: Vec2, radius: f32, map: TileMapRef) -> Vec<UVec2> {
+        PhysicsWorldApi::solid_tiles_in_circle(self, center, radius, map)
+    }
+
+    fn query_polygon_tiles(&self, points: &[Vec2], mask: LayerMask) -> Vec<TileRef> {
+        PhysicsWorldApi::query_polygon_tiles(self, points, mask)
+    }
+
+    fn path_clear_aabb(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> bool {
+        PhysicsWorldApi::path_clear_aabb(self, center, half_extents, vel, mask)
+    }
+
+    fn path_clear_circle(&self, center: Vec2, radius: f32, vel: Vec2, mask: LayerMask) -> bool {
+        PhysicsWorldApi::path_clear_circle(self, center, radius, vel, mask)
+    }
+
+    fn path_clear_point(&self, p: Vec2, vel: Vec2, mask: LayerMask) -> bool {
+        PhysicsWorldApi::path_clear_point(self, p, vel, mask)
+    }
+
+    fn cast(
+        &self,
+        shape: ColliderKind,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::cast(self, shape, origin, dir, mask, max_t)
+    }
+
+    fn raycast_tiles(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        max_t: f32,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::raycast_tiles(self, origin, dir, max_t, mask)
+    }
+
+    fn sweep_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::sweep_aabb_tiles(self, center, half_extents, vel, mask)
+    }
+
+    fn sweep_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::sweep_circle_tiles(self, center, radius, vel, mask)
+    }
+
+    fn sweep_capsule_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        half_height: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::sweep_capsule_tiles(self, center, radius, half_height, vel, mask)
+    }
+
+    fn sweep_polygon_tiles(
+        &self,
+        points: &[Vec2],
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        PhysicsWorldApi::sweep_polygon_tiles(self, points, vel, mask)
+    }
+
+    fn overlap_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)> {
+        PhysicsWorldApi::overlap_aabb_tiles(self, center, half_extents, mask)
+    }
+
+    fn overlap_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)> {
+        PhysicsWorldApi::overlap_circle_tiles(self, center, radius, mask)
+    }
+
+    fn tile_surface_normal(&self, p: Vec2, mask: LayerMask) -> Option<(TileRef, Vec2)> {
+        PhysicsWorldApi::tile_surface_normal(self, p, mask)
+    }
+
+    fn sweep_aabb_tiles_contacts(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, TileContacts)> {
+        PhysicsWorldApi::sweep_aabb_tiles_contacts(self, center, half_extents, vel, mask)
+    }
+
+    fn sweep_aabb_tiles_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, Vec<(TileRef, Vec2)>)> {
+        PhysicsWorldApi::sweep_aabb_tiles_all(self, center, half_extents, vel, mask)
+    }
+
+    fn overlap_pair(&self, a: FrameId, b: FrameId) -> Option<Overlap> {
+        PhysicsWorldApi::overlap_pair(self, a, b)
+    }
+
+    fn manifold_pair(&self, a: FrameId, b: FrameId) -> Option<Manifold> {
+        PhysicsWorldApi::manifold_pair(self, a, b)
+    }
+
+    fn sweep_pair(&self, a: FrameId, b: FrameId) -> Option<SweepHit> {
+        PhysicsWorldApi::sweep_pair(self, a, b)
+    }
+
+    fn sweep_pair_interval(&self, a: FrameId, b: FrameId) -> Option<(f32, f32)> {
+        PhysicsWorldApi::sweep_pair_interval(self, a, b)
+    }
+
+    fn touching_pair(&self, a: FrameId, b: FrameId, eps: f32) -> bool {
+        PhysicsWorldApi::touching_pair(self, a, b, eps)
+    }
+
+    fn cast_pair(&self, a: FrameId, b: FrameId) -> CastResult {
+        PhysicsWorldApi::cast_pair(self, a, b)
+    }
+
+    fn deepest_overlap(&self, id: FrameId, mask: LayerMask) -> Option<(BodyRef, Overlap)> {
+        PhysicsWorldApi::deepest_overlap(self, id, mask)
+    }
+
+    fn overlaps_between(
+        &self,
+        group_a: &[FrameId],
+        group_b: &[FrameId],
+    ) -> Vec<(FrameId, FrameId, Overlap)> {
+        PhysicsWorldApi::overlaps_between(self, group_a, group_b)
+    }
+
+    fn overlap_by_key(&self, a: ColKey, b: ColKey) -> Option<Overlap> {
+        PhysicsWorldApi::overlap_by_key(self, a, b)
+    }
+
+    fn sweep_by_key(&self, a: ColKey, b: ColKey) -> Option<SweepHit> {
+        PhysicsWorldApi::sweep_by_key(self, a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PhysicsWorld;
+
+    #[test]
+    fn test_boxed_world_runs_a_frame() {
+        let mut w: Box<dyn PhysicsWorldDyn> = Box::new(PhysicsWorld::new(WorldConfig {
+            cell_size: 1.0,
+            dt: 1.0,
+            tighten_swept_aabb: true,
+            enable_overlap_events: true,
+            enable_sweep_events: true,
+            max_events: 64,
+            enable_timing: false,
+            tile_eps: 1e-4,
+            require_mutual_consent: true,
+            skin_width: 0.0,
+            broadphase: Broadphase::Grid,
+            overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+            square_circle_tile_sweep: false,
+            grid_shrink_interval: 0,
+            sort_overlaps_by_depth: false,
+            toi_in_seconds: false,
+            ray_tie_preference: TiePreference::Collider,
+            raycast_skip_start_solid: false,
+            world_bounds: None,
+            point_eps: 0.0,
+            tile_events_per_cell: false,
+            large_collider_cell_threshold: 0,
+            contact_skin: 0.0,
+        }));
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_circle(Vec2::ZERO, 0.5, Vec2::ZERO, mask, None);
+        let b = w.push_circle(Vec2::new(0.8, 0.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        let mut seen = 0;
+        w.generate_events_with(&mut |ev| {
+            assert_eq!((ev.a, ev.b), (BodyRef::Collider(a), BodyRef::Collider(b)));
+            seen += 1;
+        });
+        assert_eq!(seen, 1);
+        assert!(w.overlap_pair(a, b).is_some());
+    }
+}
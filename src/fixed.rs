@@ -0,0 +1,453 @@
+//! Deterministic Q16.16 fixed-point narrowphase for lockstep networking.
+//!
+//! Floats can round differently across platforms and compilers; integer math does not.
+//! [`Fx`] is a Q16.16 scalar (`i32`, 16 fractional bits, range about ±32768) whose
+//! arithmetic saturates instead of wrapping, and [`FxVec2`] is its 2D vector. The
+//! functions here mirror their float counterparts in [`crate::narrowphase`] (same
+//! normal convention: from B into A), with every step done in integers.
+//!
+//! Only the core box/circle primitives are covered so far; the world, broadphase and
+//! tile queries remain float-only. Convert inputs once with [`Fx::from_f32`] (or build
+//! them from integers) and keep simulation state in `Fx` to stay deterministic.
+//!
+//! ```
+//! use nobonk::fixed::{self, Fx, FxVec2};
+//!
+//! let h = FxVec2::splat(Fx::ONE);
+//! let ov = fixed::overlap_aabb_aabb(FxVec2::ZERO, h, FxVec2::new(Fx::from_f32(1.5), Fx::ZERO), h).unwrap();
+//! assert_eq!(ov.depth, Fx::from_f32(0.5));
+//! ```
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use glam::Vec2;
+
+/// Q16.16 fixed-point scalar. Arithmetic saturates at [`Fx::MIN`]/[`Fx::MAX`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fx(pub i32);
+
+impl Fx {
+    pub const FRAC_BITS: u32 = 16;
+    pub const ZERO: Fx = Fx(0);
+    pub const ONE: Fx = Fx(1 << Self::FRAC_BITS);
+    pub const HALF: Fx = Fx(1 << (Self::FRAC_BITS - 1));
+    pub const MIN: Fx = Fx(i32::MIN);
+    pub const MAX: Fx = Fx(i32::MAX);
+    /// Smallest positive value, `2^-16`.
+    pub const EPSILON: Fx = Fx(1);
+    /// `1/√2` rounded, for diagonal (corner) normals.
+    pub const FRAC_1_SQRT_2: Fx = Fx(46341);
+
+    pub const fn from_int(v: i32) -> Fx {
+        Fx(v.saturating_mul(1 << Self::FRAC_BITS))
+    }
+
+    /// Nearest fixed-point value (saturating; NaN becomes zero). Use at setup time only.
+    pub fn from_f32(v: f32) -> Fx {
+        Fx((v * Self::ONE.0 as f32).round() as i32)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    fn saturate(v: i64) -> Fx {
+        Fx(v.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    pub fn abs(self) -> Fx {
+        Fx(self.0.saturating_abs())
+    }
+
+    pub fn min(self, o: Fx) -> Fx {
+        Ord::min(self, o)
+    }
+
+    pub fn max(self, o: Fx) -> Fx {
+        Ord::max(self, o)
+    }
+
+    /// -1, 0 or 1.
+    pub fn signum(self) -> Fx {
+        Fx::from_int(self.0.signum())
+    }
+
+    /// Integer square root (floor); 0 for non-positive input.
+    pub fn sqrt(self) -> Fx {
+        if self.0 <= 0 {
+            return Fx::ZERO;
+        }
+        Fx((((self.0 as u64) << Self::FRAC_BITS).isqrt()) as i32)
+    }
+}
+
+impl Add for Fx {
+    type Output = Fx;
+    fn add(self, o: Fx) -> Fx {
+        Fx(self.0.saturating_add(o.0))
+    }
+}
+
+impl Sub for Fx {
+    type Output = Fx;
+    fn sub(self, o: Fx) -> Fx {
+        Fx(self.0.saturating_sub(o.0))
+    }
+}
+
+impl Neg for Fx {
+    type Output = Fx;
+    fn neg(self) -> Fx {
+        Fx(self.0.saturating_neg())
+    }
+}
+
+impl Mul for Fx {
+    type Output = Fx;
+    fn mul(self, o: Fx) -> Fx {
+        Fx::saturate((self.0 as i64 * o.0 as i64) >> Fx::FRAC_BITS)
+    }
+}
+
+impl Div for Fx {
+    type Output = Fx;
+    /// Truncating division; dividing by zero saturates toward the dividend's sign.
+    fn div(self, o: Fx) -> Fx {
+        if o.0 == 0 {
+            return if self.0 >= 0 { Fx::MAX } else { Fx::MIN };
+        }
+        Fx::saturate(((self.0 as i64) << Fx::FRAC_BITS) / o.0 as i64)
+    }
+}
+
+impl AddAssign for Fx {
+    fn add_assign(&mut self, o: Fx) {
+        *self = *self + o;
+    }
+}
+
+impl SubAssign for Fx {
+    fn sub_assign(&mut self, o: Fx) {
+        *self = *self - o;
+    }
+}
+
+/// 2D vector of [`Fx`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FxVec2 {
+    pub x: Fx,
+    pub y: Fx,
+}
+
+impl FxVec2 {
+    pub const ZERO: FxVec2 = FxVec2::new(Fx::ZERO, Fx::ZERO);
+    pub const X: FxVec2 = FxVec2::new(Fx::ONE, Fx::ZERO);
+    pub const Y: FxVec2 = FxVec2::new(Fx::ZERO, Fx::ONE);
+
+    pub const fn new(x: Fx, y: Fx) -> FxVec2 {
+        FxVec2 { x, y }
+    }
+
+    pub const fn splat(v: Fx) -> FxVec2 {
+        FxVec2 { x: v, y: v }
+    }
+
+    /// Nearest fixed-point vector. Use at setup time only.
+    pub fn from_vec2(v: Vec2) -> FxVec2 {
+        FxVec2::new(Fx::from_f32(v.x), Fx::from_f32(v.y))
+    }
+
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x.to_f32(), self.y.to_f32())
+    }
+
+    pub fn dot(self, o: FxVec2) -> Fx {
+        self.x * o.x + self.y * o.y
+    }
+
+    pub fn length_squared(self) -> Fx {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> Fx {
+        self.length_squared().sqrt()
+    }
+
+    pub fn abs(self) -> FxVec2 {
+        FxVec2::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn min(self, o: FxVec2) -> FxVec2 {
+        FxVec2::new(self.x.min(o.x), self.y.min(o.y))
+    }
+
+    pub fn max(self, o: FxVec2) -> FxVec2 {
+        FxVec2::new(self.x.max(o.x), self.y.max(o.y))
+    }
+
+    pub fn clamp(self, lo: FxVec2, hi: FxVec2) -> FxVec2 {
+        self.max(lo).min(hi)
+    }
+}
+
+impl Add for FxVec2 {
+    type Output = FxVec2;
+    fn add(self, o: FxVec2) -> FxVec2 {
+        FxVec2::new(self.x + o.x, self.y + o.y)
+    }
+}
+
+impl Sub for FxVec2 {
+    type Output = FxVec2;
+    fn sub(self, o: FxVec2) -> FxVec2 {
+        FxVec2::new(self.x - o.x, self.y - o.y)
+    }
+}
+
+impl Neg for FxVec2 {
+    type Output = FxVec2;
+    fn neg(self) -> FxVec2 {
+        FxVec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<Fx> for FxVec2 {
+    type Output = FxVec2;
+    fn mul(self, s: Fx) -> FxVec2 {
+        FxVec2::new(self.x * s, self.y * s)
+    }
+}
+
+impl Div<Fx> for FxVec2 {
+    type Output = FxVec2;
+    fn div(self, s: Fx) -> FxVec2 {
+        FxVec2::new(self.x / s, self.y / s)
+    }
+}
+
+/// Fixed-point [`crate::Overlap`] (normal from B into A).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FxOverlap {
+    pub normal: FxVec2,
+    pub depth: Fx,
+    pub contact: FxVec2,
+}
+
+/// Fixed-point [`crate::SweepHit`]: `toi` in units of the ray direction, or a fraction of
+/// the frame's relative motion for sweeps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FxHit {
+    pub toi: Fx,
+    pub normal: FxVec2,
+    pub contact: FxVec2,
+}
+
+/// See [`crate::NarrowphaseApi::overlap_aabb_aabb`]. Ties resolve on x; contacts lie on
+/// A's surface along the normal.
+pub fn overlap_aabb_aabb(c0: FxVec2, h0: FxVec2, c1: FxVec2, h1: FxVec2) -> Option<FxOverlap> {
+    let d = c1 - c0;
+    let ox = (h0.x + h1.x) - d.x.abs();
+    let oy = (h0.y + h1.y) - d.y.abs();
+    if ox < Fx::ZERO || oy < Fx::ZERO {
+        return None;
+    }
+    // From B into A: opposite the direction to B
+    let away = |v: Fx| if v >= Fx::ZERO { -Fx::ONE } else { Fx::ONE };
+    let (normal, depth) = if ox <= oy {
+        (FxVec2::new(away(d.x), Fx::ZERO), ox)
+    } else {
+        (FxVec2::new(Fx::ZERO, away(d.y)), oy)
+    };
+    let contact = c0.clamp(c1 - h1, c1 + h1) - FxVec2::new(normal.x * h0.x, normal.y * h0.y);
+    Some(FxOverlap {
+        normal,
+        depth,
+        contact,
+    })
+}
+
+/// See [`crate::NarrowphaseApi::overlap_circle_circle`]. Coincident centers report a zero
+/// normal; the contact lies on B's surface.
+pub fn overlap_circle_circle(c0: FxVec2, r0: Fx, c1: FxVec2, r1: Fx) -> Option<FxOverlap> {
+    // Widened: squared distances past ~181 units don't fit in Q16.16
+    let dx = c0.x.0 as i64 - c1.x.0 as i64;
+    let dy = c0.y.0 as i64 - c1.y.0 as i64;
+    let rsum = r0.0 as i64 + r1.0 as i64;
+    let dist_sq = (dx as i128) * (dx as i128) + (dy as i128) * (dy as i128);
+    if dist_sq > (rsum as i128) * (rsum as i128) {
+        return None;
+    }
+    let dist = (dist_sq as u128).isqrt() as i64;
+    if dist == 0 {
+        return Some(FxOverlap {
+            normal: FxVec2::ZERO,
+            depth: Fx::saturate(rsum),
+            contact: c0,
+        });
+    }
+    let unit = |d: i64| Fx::saturate((d << Fx::FRAC_BITS) / dist);
+    let normal = FxVec2::new(unit(dx), unit(dy));
+    Some(FxOverlap {
+        normal,
+        depth: Fx::saturate(rsum - dist).max(Fx::ZERO),
+        contact: c1 + normal * r1,
+    })
+}
+
+/// See [`crate::NarrowphaseApi::overlap_point_aabb`] (boundary counts as inside).
+pub fn overlap_point_aabb(p: FxVec2, c: FxVec2, h: FxVec2) -> bool {
+    let d = (p - c).abs();
+    d.x <= h.x && d.y <= h.y
+}
+
+/// See [`crate::NarrowphaseApi::ray_aabb`]: slab test returning the earliest `toi >= 0`.
+/// An origin inside hits at 0 with a zero normal; an exact corner entry gets the
+/// diagonal normal.
+pub fn ray_aabb(origin: FxVec2, dir: FxVec2, min: FxVec2, max: FxVec2) -> Option<FxHit> {
+    let mut tmin = Fx::MIN;
+    let mut tmax = Fx::MAX;
+    let mut normal = FxVec2::ZERO;
+    let axes = [
+        (origin.x, dir.x, min.x, max.x, FxVec2::X),
+        (origin.y, dir.y, min.y, max.y, FxVec2::Y),
+    ];
+    for (o, d, lo, hi, axis) in axes {
+        if d == Fx::ZERO {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+        let (mut t1, mut t2, mut n) = ((lo - o) / d, (hi - o) / d, -axis);
+        if t1 > t2 {
+            core::mem::swap(&mut t1, &mut t2);
+            n = axis;
+        }
+        if t1 == tmin && normal != FxVec2::ZERO {
+            normal = (normal + n) * Fx::FRAC_1_SQRT_2;
+        } else if t1 > tmin {
+            tmin = t1;
+            normal = n;
+        }
+        tmax = tmax.min(t2);
+        if tmin > tmax {
+            return None;
+        }
+    }
+    if tmax < Fx::ZERO {
+        return None;
+    }
+    let (toi, normal) = if tmin < Fx::ZERO {
+        (Fx::ZERO, FxVec2::ZERO)
+    } else {
+        (tmin, normal)
+    };
+    Some(FxHit {
+        toi,
+        normal,
+        contact: origin + dir * toi,
+    })
+}
+
+/// See [`crate::NarrowphaseApi::sweep_aabb_aabb`]: `toi` in `[0, 1]` of the relative
+/// motion `v0 - v1`; None when not moving relative to each other.
+pub fn sweep_aabb_aabb(
+    c0: FxVec2,
+    h0: FxVec2,
+    v0: FxVec2,
+    c1: FxVec2,
+    h1: FxVec2,
+    v1: FxVec2,
+) -> Option<FxHit> {
+    let vrel = v0 - v1;
+    if vrel == FxVec2::ZERO {
+        return None;
+    }
+    let expand = h0 + h1;
+    let hit = ray_aabb(c0, vrel, c1 - expand, c1 + expand)?;
+    if hit.toi > Fx::ONE {
+        return None;
+    }
+    let at_hit = c0 + vrel * hit.toi;
+    Some(FxHit {
+        contact: at_hit - FxVec2::new(hit.normal.x * h0.x, hit.normal.y * h0.y),
+        ..hit
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::NarrowphaseApi;
+    use crate::narrowphase::Narrowphase;
+
+    fn fx(v: f32) -> Fx {
+        Fx::from_f32(v)
+    }
+
+    fn v(x: f32, y: f32) -> FxVec2 {
+        FxVec2::from_vec2(Vec2::new(x, y))
+    }
+
+    #[test]
+    fn test_fx_arithmetic_saturates() {
+        assert_eq!(fx(1.5) * fx(2.0), fx(3.0));
+        assert_eq!(fx(3.0) / fx(2.0), fx(1.5));
+        assert_eq!(fx(-2.0).abs(), fx(2.0));
+        assert_eq!(fx(2.25).sqrt(), fx(1.5));
+        assert_eq!(Fx::MAX + Fx::ONE, Fx::MAX);
+        assert_eq!(Fx::MIN - Fx::ONE, Fx::MIN);
+        assert_eq!(Fx::from_int(30000) * Fx::from_int(30000), Fx::MAX);
+        assert_eq!(Fx::ONE / Fx::ZERO, Fx::MAX);
+        assert_eq!(-Fx::MIN, Fx::MAX);
+    }
+
+    #[test]
+    fn test_fixed_primitives_match_float() {
+        let ov = overlap_aabb_aabb(v(0.0, 0.0), v(1.0, 1.0), v(1.5, 0.25), v(1.0, 1.0)).unwrap();
+        let fl =
+            Narrowphase::overlap_aabb_aabb(Vec2::ZERO, Vec2::ONE, Vec2::new(1.5, 0.25), Vec2::ONE)
+                .unwrap();
+        assert_eq!(ov.normal.to_vec2(), fl.normal);
+        assert_eq!(ov.depth.to_f32(), fl.depth);
+        assert_eq!(ov.contact.to_vec2(), fl.contact);
+
+        let ov = overlap_circle_circle(v(0.0, 0.0), fx(1.0), v(1.5, 0.0), fx(1.0)).unwrap();
+        assert_eq!((ov.normal, ov.depth), (-FxVec2::X, fx(0.5)));
+        assert_eq!(ov.contact, v(0.5, 0.0));
+        assert!(overlap_circle_circle(v(0.0, 0.0), fx(1.0), v(2.5, 0.0), fx(1.0)).is_none());
+
+        let hit = ray_aabb(v(-2.0, 0.5), v(1.0, 0.0), v(0.0, 0.0), v(1.0, 1.0)).unwrap();
+        assert_eq!((hit.toi, hit.normal), (fx(2.0), -FxVec2::X));
+        // Exact corner entry gets the diagonal
+        let hit = ray_aabb(v(-1.0, -1.0), v(1.0, 1.0), v(0.0, 0.0), v(1.0, 1.0)).unwrap();
+        assert_eq!(hit.normal, FxVec2::splat(-Fx::FRAC_1_SQRT_2));
+        assert!(ray_aabb(v(2.0, 0.5), v(1.0, 0.0), v(0.0, 0.0), v(1.0, 1.0)).is_none());
+
+        let hit = sweep_aabb_aabb(
+            v(0.0, 0.0),
+            v(0.5, 0.5),
+            v(4.0, 0.0),
+            v(3.0, 0.0),
+            v(0.5, 0.5),
+            v(0.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!((hit.toi, hit.normal), (fx(0.5), -FxVec2::X));
+        assert_eq!(hit.contact, v(2.5, 0.0));
+        assert!(overlap_point_aabb(v(1.0, 1.0), v(0.0, 0.0), v(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_circle_circle_large_distances_dont_saturate() {
+        // Squared distances here are far past Fx::MAX
+        assert!(overlap_circle_circle(v(0.0, 0.0), fx(200.0), v(1000.0, 0.0), fx(200.0)).is_none());
+        assert!(
+            overlap_circle_circle(v(-20000.0, 0.0), fx(1.0), v(20000.0, 0.0), fx(1.0)).is_none()
+        );
+        let ov = overlap_circle_circle(v(0.0, 0.0), fx(200.0), v(300.0, 400.0), fx(400.0)).unwrap();
+        assert_eq!(ov.depth, fx(100.0));
+        assert!((ov.normal.to_vec2() - Vec2::new(-0.6, -0.8)).length() < 1e-4);
+        assert!((ov.contact.to_vec2() - Vec2::new(60.0, 80.0)).length() < 0.05);
+    }
+}
@@ -0,0 +1,155 @@
+//! Narrowphase primitives as plain functions.
+//!
+//! Thin wrappers over [`Narrowphase`]'s [`NarrowphaseApi`] impl, so the geometry can be
+//! called without the trait in scope:
+//!
+//! ```
+//! use glam::Vec2;
+//!
+//! let ov = nobonk::geom::overlap_aabb_aabb(Vec2::ZERO, Vec2::ONE, Vec2::new(1.5, 0.0), Vec2::ONE);
+//! assert!(ov.is_some());
+//! ```
+
+use glam::Vec2;
+
+use crate::api::NarrowphaseApi;
+use crate::narrowphase::Narrowphase;
+use crate::types::{Overlap, SweepHit};
+
+// Rays / segments -----------------------------------------------------------
+
+/// See [`NarrowphaseApi::ray_aabb`].
+pub fn ray_aabb(origin: Vec2, dir: Vec2, aabb_min: Vec2, aabb_max: Vec2) -> Option<SweepHit> {
+    Narrowphase::ray_aabb(origin, dir, aabb_min, aabb_max)
+}
+
+/// See [`NarrowphaseApi::ray_circle`].
+pub fn ray_circle(origin: Vec2, dir: Vec2, center: Vec2, r: f32) -> Option<SweepHit> {
+    Narrowphase::ray_circle(origin, dir, center, r)
+}
+
+/// See [`NarrowphaseApi::ray_capsule`].
+pub fn ray_capsule(origin: Vec2, dir: Vec2, p0: Vec2, p1: Vec2, r: f32) -> Option<SweepHit> {
+    Narrowphase::ray_capsule(origin, dir, p0, p1, r)
+}
+
+/// See [`NarrowphaseApi::line_segment_aabb`].
+pub fn line_segment_aabb(a: Vec2, b: Vec2, aabb_min: Vec2, aabb_max: Vec2) -> Option<SweepHit> {
+    Narrowphase::line_segment_aabb(a, b, aabb_min, aabb_max)
+}
+
+/// See [`NarrowphaseApi::line_segment_circle`].
+pub fn line_segment_circle(a: Vec2, b: Vec2, center: Vec2, r: f32) -> Option<SweepHit> {
+    Narrowphase::line_segment_circle(a, b, center, r)
+}
+
+/// See [`NarrowphaseApi::segment_segment`].
+pub fn segment_segment(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> Option<SweepHit> {
+    Narrowphase::segment_segment(a0, a1, b0, b1)
+}
+
+// Overlaps ------------------------------------------------------------------
+
+/// See [`NarrowphaseApi::overlap_aabb_aabb`].
+pub fn overlap_aabb_aabb(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2) -> Option<Overlap> {
+    Narrowphase::overlap_aabb_aabb(c0, h0, c1, h1)
+}
+
+/// See [`NarrowphaseApi::overlap_aabb_aabb_biased`].
+pub fn overlap_aabb_aabb_biased(
+    c0: Vec2,
+    h0: Vec2,
+    c1: Vec2,
+    h1: Vec2,
+    prev_normal: Vec2,
+    eps: f32,
+) -> Option<Overlap> {
+    Narrowphase::overlap_aabb_aabb_biased(c0, h0, c1, h1, prev_normal, eps)
+}
+
+/// See [`NarrowphaseApi::overlap_circle_circle`].
+pub fn overlap_circle_circle(c0: Vec2, r0: f32, c1: Vec2, r1: f32) -> Option<Overlap> {
+    Narrowphase::overlap_circle_circle(c0, r0, c1, r1)
+}
+
+/// See [`NarrowphaseApi::overlap_circle_aabb`].
+pub fn overlap_circle_aabb(c: Vec2, r: f32, box_c: Vec2, box_h: Vec2) -> Option<Overlap> {
+    Narrowphase::overlap_circle_aabb(c, r, box_c, box_h)
+}
+
+/// See [`NarrowphaseApi::overlap_polygon_aabb`].
+pub fn overlap_polygon_aabb(points: &[Vec2], box_c: Vec2, box_h: Vec2) -> Option<Overlap> {
+    Narrowphase::overlap_polygon_aabb(points, box_c, box_h)
+}
+
+/// See [`NarrowphaseApi::overlap_point_aabb`].
+pub fn overlap_point_aabb(p: Vec2, c: Vec2, h: Vec2) -> bool {
+    Narrowphase::overlap_point_aabb(p, c, h)
+}
+
+/// See [`NarrowphaseApi::overlap_point_circle`].
+pub fn overlap_point_circle(p: Vec2, c: Vec2, r: f32) -> bool {
+    Narrowphase::overlap_point_circle(p, c, r)
+}
+
+// Sweeps --------------------------------------------------------------------
+
+/// See [`NarrowphaseApi::sweep_aabb_aabb`].
+pub fn sweep_aabb_aabb(
+    c0: Vec2,
+    h0: Vec2,
+    v0: Vec2,
+    c1: Vec2,
+    h1: Vec2,
+    v1: Vec2,
+) -> Option<SweepHit> {
+    Narrowphase::sweep_aabb_aabb(c0, h0, v0, c1, h1, v1)
+}
+
+/// See [`NarrowphaseApi::sweep_circle_aabb`].
+pub fn sweep_circle_aabb(
+    c: Vec2,
+    r: f32,
+    v: Vec2,
+    box_c: Vec2,
+    box_h: Vec2,
+    box_v: Vec2,
+) -> Option<SweepHit> {
+    Narrowphase::sweep_circle_aabb(c, r, v, box_c, box_h, box_v)
+}
+
+/// See [`NarrowphaseApi::sweep_aabb_circle`].
+pub fn sweep_aabb_circle(
+    box_c: Vec2,
+    box_h: Vec2,
+    box_v: Vec2,
+    c: Vec2,
+    r: f32,
+    v: Vec2,
+) -> Option<SweepHit> {
+    Narrowphase::sweep_aabb_circle(box_c, box_h, box_v, c, r, v)
+}
+
+/// See [`NarrowphaseApi::sweep_circle_circle`].
+pub fn sweep_circle_circle(
+    c0: Vec2,
+    r0: f32,
+    v0: Vec2,
+    c1: Vec2,
+    r1: f32,
+    v1: Vec2,
+) -> Option<SweepHit> {
+    Narrowphase::sweep_circle_circle(c0, r0, v0, c1, r1, v1)
+}
+
+// Tile helpers --------------------------------------------------------------
+
+/// See [`NarrowphaseApi::aabb_tile_pushout`].
+pub fn aabb_tile_pushout(c: Vec2, he: Vec2, tile_min: Vec2, cell: f32) -> (Vec2, f32, Vec2) {
+    Narrowphase::aabb_tile_pushout(c, he, tile_min, cell)
+}
+
+/// See [`NarrowphaseApi::circle_tile_pushout`].
+pub fn circle_tile_pushout(c: Vec2, r: f32, tile_min: Vec2, cell: f32) -> (Vec2, f32, Vec2) {
+    Narrowphase::circle_tile_pushout(c, r, tile_min, cell)
+}
@@ -1,6 +1,11 @@
 //! nobonk: detection-only physics engine (ephemeral world, no resolution)
 
 pub mod api;
+mod broadphase;
+pub mod dyn_api;
+pub mod fixed;
+pub mod geom;
+mod hash;
 pub mod narrowphase;
 pub mod types;
 pub mod world;
@@ -3,6 +3,111 @@ use glam::Vec2;
 use crate::api::NarrowphaseApi;
 use crate::types::*;
 
+/// Slab entry normal; when both axes are entered at the same `toi` (exact corner hit)
+/// the two face normals are averaged so the result is still unit length.
+fn corner_normal(
+    toi: f32,
+    n_enter: Vec2,
+    x_enter: Option<(f32, Vec2)>,
+    y_enter: Option<(f32, Vec2)>,
+) -> Vec2 {
+    match (x_enter, y_enter) {
+        (Some((tx, nx)), Some((ty, ny)))
+            if toi > 0.0 && (tx - ty).abs() <= 1e-6 * toi.max(1.0) =>
+        {
+            (nx + ny).normalize()
+        }
+        _ => n_enter,
+    }
+}
+
+/// Unclamped `(enter, exit)` times of the line `origin + t * dir` inside the box (slab
+/// method). A zero `dir` inside the box is inside for all `t`.
+pub(crate) fn slab_interval(origin: Vec2, dir: Vec2, min: Vec2, max: Vec2) -> Option<(f32, f32)> {
+    let mut tmin = f32::NEG_INFINITY;
+    let mut tmax = f32::INFINITY;
+    for (o, d, lo, hi) in [(origin.x, dir.x, min.x, max.x), (origin.y, dir.y, min.y, max.y)] {
+        if d.abs() < f32::EPSILON {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+        let (t1, t2) = ((lo - o) / d, (hi - o) / d);
+        tmin = tmin.max(t1.min(t2));
+        tmax = tmax.min(t1.max(t2));
+    }
+    (tmin <= tmax).then_some((tmin, tmax))
+}
+
+/// Unclamped `(enter, exit)` times of the line `origin + t * dir` inside the circle. A zero
+/// `dir` inside the circle is inside for all `t`.
+pub(crate) fn circle_interval(origin: Vec2, dir: Vec2, center: Vec2, r: f32) -> Option<(f32, f32)> {
+    let m = origin - center;
+    let c = m.length_squared() - r * r;
+    let a = dir.length_squared();
+    if a == 0.0 {
+        return (c <= 0.0).then_some((f32::NEG_INFINITY, f32::INFINITY));
+    }
+    let b = 2.0 * m.dot(dir);
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let sqrt_disc = disc.sqrt();
+    Some(((-b - sqrt_disc) / (2.0 * a), (-b + sqrt_disc) / (2.0 * a)))
+}
+
+/// AABB overlap with the penetration axis picked by `use_x(ox, oy)` (true = resolve on x).
+fn overlap_aabb_aabb_with(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2, use_x: impl FnOnce(f32, f32) -> bool) -> Option<Overlap> {
+    // Compute overlap extents along axes
+    let d = c1 - c0;
+    let ox = (h0.x + h1.x) - d.x.abs();
+    let oy = (h0.y + h1.y) - d.y.abs();
+    if ox < 0.0 || oy < 0.0 {
+        return None;
+    }
+
+    // Choose axis of minimum penetration (the caller decides near-ties)
+    let (depth, mut normal, axis_h) = if use_x(ox, oy) {
+        let nx = if d.x >= 0.0 { -1.0 } else { 1.0 }; // from B into A
+        (ox.max(0.0), Vec2::new(nx, 0.0), h0.x)
+    } else {
+        let ny = if d.y >= 0.0 { -1.0 } else { 1.0 };
+        (oy.max(0.0), Vec2::new(0.0, ny), h0.y)
+    };
+
+    // Touching without penetration: report the shared corner or the shared edge's midpoint
+    const TOUCH_EPS: f32 = 1e-6;
+    if ox <= TOUCH_EPS && oy <= TOUCH_EPS {
+        let sign = Vec2::new(if d.x >= 0.0 { -1.0 } else { 1.0 }, if d.y >= 0.0 { -1.0 } else { 1.0 });
+        let contact = c0 - sign * h0;
+        return Some(Overlap { normal: sign.normalize(), depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::from_box_normal(sign) });
+    }
+    if depth <= TOUCH_EPS {
+        let (amin, amax) = (c0 - h0, c0 + h0);
+        let (lo, hi) = (amin.max(c1 - h1), amax.min(c1 + h1));
+        let mid = (lo + hi) * 0.5;
+        let face = c0 - normal * h0;
+        let contact = if normal.x != 0.0 { Vec2::new(face.x, mid.y) } else { Vec2::new(mid.x, face.y) };
+        return Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::from_box_normal(normal) });
+    }
+    if normal.length_squared() == 0.0 {
+        // Shouldn't happen, but guard against NaN
+        normal = Vec2::ZERO;
+    }
+
+    // Contact point: project A's center onto B's box then move to A's surface along normal
+    let bmin = c1 - h1;
+    let bmax = c1 + h1;
+    let clamp = |v: f32, lo: f32, hi: f32| v.max(lo).min(hi);
+    let mut contact = Vec2::new(clamp(c0.x, bmin.x, bmax.x), clamp(c0.y, bmin.y, bmax.y));
+    // Move to A's surface along the chosen axis
+    contact -= normal * axis_h;
+
+    Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::from_box_normal(normal) })
+}
+
 /// Narrowphase primitive tests (skeleton; to be implemented).
 pub struct Narrowphase;
 
@@ -12,6 +117,9 @@ impl NarrowphaseApi for Narrowphase {
         let mut tmin = f32::NEG_INFINITY;
         let mut tmax = f32::INFINITY;
         let mut n_enter = Vec2::ZERO;
+        // Per-axis entry (t, face normal) to detect exact corner hits
+        let mut x_enter = None;
+        let mut y_enter = None;
 
         // X axis
         if dir.x.abs() < f32::EPSILON {
@@ -27,6 +135,7 @@ impl NarrowphaseApi for Narrowphase {
                 core::mem::swap(&mut t1, &mut t2);
                 nx = 1.0;
             }
+            x_enter = Some((t1, Vec2::new(nx, 0.0)));
             if t1 > tmin {
                 tmin = t1;
                 n_enter = Vec2::new(nx, 0.0);
@@ -53,6 +162,7 @@ impl NarrowphaseApi for Narrowphase {
                 core::mem::swap(&mut t1, &mut t2);
                 ny = 1.0;
             }
+            y_enter = Some((t1, Vec2::new(0.0, ny)));
             if t1 > tmin {
                 tmin = t1;
                 n_enter = Vec2::new(0.0, ny);
@@ -65,11 +175,15 @@ impl NarrowphaseApi for Narrowphase {
             }
         }
 
+        // Box entirely behind the origin
+        if tmax < 0.0 {
+            return None;
+        }
         // If origin inside, tmin < 0; treat as immediate hit
         let toi = if tmin < 0.0 { 0.0 } else { tmin };
         let contact = origin + dir * toi;
-        let normal = if tmin < 0.0 { Vec2::ZERO } else { n_enter };
-        Some(SweepHit { toi, normal, contact, hint: ResolutionHint::default() })
+        let normal = if tmin < 0.0 { Vec2::ZERO } else { corner_normal(toi, n_enter, x_enter, y_enter) };
+        Some(SweepHit { toi, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
     }
 
     fn ray_circle(origin: Vec2, dir: Vec2, center: Vec2, r: f32) -> Option<SweepHit> {
@@ -96,7 +210,48 @@ impl NarrowphaseApi for Narrowphase {
         let n = contact - center;
         let len = n.length();
         let normal = if len > 0.0 { n / len } else { Vec2::ZERO };
-        Some(SweepHit { toi: t, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi: t, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
+    }
+
+    fn ray_capsule(origin: Vec2, dir: Vec2, p0: Vec2, p1: Vec2, r: f32) -> Option<SweepHit> {
+        if dir.length_squared() == 0.0 {
+            return None;
+        }
+        let axis = p1 - p0;
+        let len2 = axis.length_squared();
+        // Origin inside: distance to the core segment within r
+        let u = if len2 > 0.0 { ((origin - p0).dot(axis) / len2).clamp(0.0, 1.0) } else { 0.0 };
+        if (origin - (p0 + axis * u)).length_squared() <= r * r {
+            return Some(SweepHit { toi: 0.0, normal: Vec2::ZERO, contact: origin, hint: ResolutionHint::default(), impact_speed: 0.0 });
+        }
+        // Origin is outside both caps, so ray_circle reports the entry root
+        let mut best = Self::ray_circle(origin, dir, p0, r);
+        let mut consider = |hit: Option<SweepHit>| {
+            if let Some(h) = hit
+                && best.is_none_or(|b| h.toi < b.toi)
+            {
+                best = Some(h);
+            }
+        };
+        consider(Self::ray_circle(origin, dir, p1, r));
+        if len2 > 0.0 {
+            let side = axis.perp() / len2.sqrt();
+            for n in [side, -side] {
+                // Only the face the ray approaches from outside can be entered
+                if dir.dot(n) >= 0.0 {
+                    continue;
+                }
+                let a = p0 + n * r;
+                let denom = dir.perp_dot(axis);
+                let t = (a - origin).perp_dot(axis) / denom;
+                let s = (a - origin).perp_dot(dir) / denom;
+                if t >= 0.0 && (0.0..=1.0).contains(&s) {
+                    let contact = origin + dir * t;
+                    consider(Some(SweepHit { toi: t, normal: n, contact, hint: ResolutionHint::default(), impact_speed: 0.0 }));
+                }
+            }
+        }
+        best
     }
 
     fn line_segment_aabb(a: Vec2, b: Vec2, aabb_min: Vec2, aabb_max: Vec2) -> Option<SweepHit> {
@@ -105,6 +260,9 @@ impl NarrowphaseApi for Narrowphase {
         let mut tmin = 0.0;
         let mut tmax = 1.0;
         let mut n_enter = Vec2::ZERO;
+        // Per-axis entry (t, face normal) to detect exact corner hits
+        let mut x_enter = None;
+        let mut y_enter = None;
 
         // X axis
         if d.x.abs() < f32::EPSILON {
@@ -120,6 +278,7 @@ impl NarrowphaseApi for Narrowphase {
                 core::mem::swap(&mut t1, &mut t2);
                 nx = 1.0;
             }
+            x_enter = Some((t1, Vec2::new(nx, 0.0)));
             if t1 > tmin {
                 tmin = t1;
                 n_enter = Vec2::new(nx, 0.0);
@@ -146,6 +305,7 @@ impl NarrowphaseApi for Narrowphase {
                 core::mem::swap(&mut t1, &mut t2);
                 ny = 1.0;
             }
+            y_enter = Some((t1, Vec2::new(0.0, ny)));
             if t1 > tmin {
                 tmin = t1;
                 n_enter = Vec2::new(0.0, ny);
@@ -168,9 +328,9 @@ impl NarrowphaseApi for Narrowphase {
         {
             Vec2::ZERO
         } else {
-            n_enter
+            corner_normal(toi, n_enter, x_enter, y_enter)
         };
-        Some(SweepHit { toi, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
     }
 
     fn line_segment_circle(a: Vec2, b: Vec2, center: Vec2, r: f32) -> Option<SweepHit> {
@@ -203,43 +363,45 @@ impl NarrowphaseApi for Narrowphase {
         let n = contact - center;
         let len = n.length();
         let normal = if len > 0.0 { n / len } else { Vec2::ZERO };
-        Some(SweepHit { toi: t, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi: t, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
     }
 
-    fn overlap_aabb_aabb(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2) -> Option<Overlap> {
-        // Compute overlap extents along axes
-        let d = c1 - c0;
-        let ox = (h0.x + h1.x) - d.x.abs();
-        let oy = (h0.y + h1.y) - d.y.abs();
-        if ox < 0.0 || oy < 0.0 {
+    fn segment_segment(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> Option<SweepHit> {
+        // Solve a0 + t*da = b0 + u*db with t, u in [0,1]
+        let da = a1 - a0;
+        let db = b1 - b0;
+        let denom = da.perp_dot(db);
+        if denom.abs() < f32::EPSILON {
             return None;
         }
-
-        // Choose axis of minimum penetration
-        let (depth, mut normal, axis_h) = if ox <= oy {
-            let nx = if d.x >= 0.0 { -1.0 } else { 1.0 }; // from B into A
-            (ox.max(0.0), Vec2::new(nx, 0.0), h0.x)
-        } else {
-            let ny = if d.y >= 0.0 { -1.0 } else { 1.0 };
-            (oy.max(0.0), Vec2::new(0.0, ny), h0.y)
-        };
-
-        if depth == 0.0 {
-            // Degenerate: edge-touch; keep normal axis-aligned as above
-        } else if normal.length_squared() == 0.0 {
-            // Shouldn't happen, but guard against NaN
-            normal = Vec2::ZERO;
+        let w = b0 - a0;
+        let t = w.perp_dot(db) / denom;
+        let u = w.perp_dot(da) / denom;
+        if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+            return None;
         }
+        let contact = a0 + da * t;
+        // Normal is perpendicular to b, on the side a approaches from
+        let mut normal = db.perp().normalize_or_zero();
+        if normal.dot(da) > 0.0 {
+            normal = -normal;
+        }
+        Some(SweepHit { toi: t, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
+    }
 
-        // Contact point: project A's center onto B's box then move to A's surface along normal
-        let bmin = c1 - h1;
-        let bmax = c1 + h1;
-        let clamp = |v: f32, lo: f32, hi: f32| v.max(lo).min(hi);
-        let mut contact = Vec2::new(clamp(c0.x, bmin.x, bmax.x), clamp(c0.y, bmin.y, bmax.y));
-        // Move to A's surface along the chosen axis
-        contact -= normal * axis_h;
+    fn overlap_aabb_aabb(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2) -> Option<Overlap> {
+        overlap_aabb_aabb_with(c0, h0, c1, h1, |ox, oy| ox <= oy)
+    }
 
-        Some(Overlap { normal, depth, contact, hint: ResolutionHint::default() })
+    fn overlap_aabb_aabb_biased(c0: Vec2, h0: Vec2, c1: Vec2, h1: Vec2, prev_normal: Vec2, eps: f32) -> Option<Overlap> {
+        overlap_aabb_aabb_with(c0, h0, c1, h1, |ox, oy| {
+            if (ox - oy).abs() <= eps && prev_normal != Vec2::ZERO {
+                // Near-tie: keep the axis the previous normal lies closest to
+                prev_normal.x.abs() >= prev_normal.y.abs()
+            } else {
+                ox <= oy
+            }
+        })
     }
 
     fn overlap_circle_circle(c0: Vec2, r0: f32, c1: Vec2, r1: f32) -> Option<Overlap> {
@@ -255,13 +417,91 @@ impl NarrowphaseApi for Narrowphase {
             let normal = Vec2::ZERO;
             let depth = rsum; // maximal, but depth is >= 0; choosing r0+r1
             let contact = c0; // arbitrary representative
-            return Some(Overlap { normal, depth, contact, hint: ResolutionHint::default() });
+            return Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::Round });
         }
         let dist = dist2.sqrt();
         let normal = delta / dist; // from B into A
         let depth = (rsum - dist).max(0.0);
-        let contact = c0 - normal * r0;
-        Some(Overlap { normal, depth, contact, hint: ResolutionHint::default() })
+        // On B's surface: unlike A's deepest point, it does not move as the depth jitters
+        let contact = c1 + normal * r1;
+        Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::Round })
+    }
+
+    fn overlap_circle_aabb(c: Vec2, r: f32, box_c: Vec2, box_h: Vec2) -> Option<Overlap> {
+        let min = box_c - box_h;
+        let max = box_c + box_h;
+        let clamp = |v: f32, lo: f32, hi: f32| v.max(lo).min(hi);
+        let closest = Vec2::new(clamp(c.x, min.x, max.x), clamp(c.y, min.y, max.y));
+        let delta = c - closest;
+        let d2 = delta.length_squared();
+        if d2 > r * r {
+            return None;
+        }
+        let (normal, depth, contact) = if d2 > 0.0 {
+            // Center outside the box: push along the closest-point direction (from box into circle)
+            let d = d2.sqrt();
+            (delta / d, (r - d).max(0.0), closest)
+        } else {
+            // Center inside the box: exit through the nearest face
+            let faces = [
+                (max.x - c.x, Vec2::X),
+                (c.x - min.x, Vec2::NEG_X),
+                (max.y - c.y, Vec2::Y),
+                (c.y - min.y, Vec2::NEG_Y),
+            ];
+            let (dist, n) = faces
+                .into_iter()
+                .fold(faces[0], |best, f| if f.0 < best.0 { f } else { best });
+            (n, r + dist, c + n * dist)
+        };
+        // Contact is on the box surface, so a circle resting on a floor keeps a steady point
+        Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature: ContactFeature::from_box_normal(normal) })
+    }
+
+    fn overlap_polygon_aabb(points: &[Vec2], box_c: Vec2, box_h: Vec2) -> Option<Overlap> {
+        if points.len() < 3 {
+            return None;
+        }
+        let centroid = points.iter().copied().sum::<Vec2>() / points.len() as f32;
+        // Minimum-overlap axis, oriented from the box into the polygon
+        let mut best: Option<(f32, Vec2, ContactFeature)> = None;
+        let mut test_axis = |axis: Vec2, feature: ContactFeature| {
+            let (pmin, pmax) = points.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), p| {
+                let d = p.dot(axis);
+                (lo.min(d), hi.max(d))
+            });
+            let bc = box_c.dot(axis);
+            let br = box_h.x * axis.x.abs() + box_h.y * axis.y.abs();
+            let depth = pmax.min(bc + br) - pmin.max(bc - br);
+            if depth < 0.0 {
+                return false;
+            }
+            let n = if (centroid - box_c).dot(axis) >= 0.0 { axis } else { -axis };
+            if best.is_none_or(|(d, _, _)| depth < d) {
+                // Box axes name the box face; edge axes name the polygon edge
+                let feature = if feature == ContactFeature::None { ContactFeature::from_box_normal(n) } else { feature };
+                best = Some((depth, n, feature));
+            }
+            true
+        };
+        if !test_axis(Vec2::X, ContactFeature::None) || !test_axis(Vec2::Y, ContactFeature::None) {
+            return None;
+        }
+        for (i, &a) in points.iter().enumerate() {
+            let e = points[(i + 1) % points.len()] - a;
+            let len = e.length();
+            if len > 1e-12 && !test_axis(e.perp() / len, ContactFeature::Edge(i as u32)) {
+                return None;
+            }
+        }
+        let (depth, normal, feature) = best?;
+        // Deepest polygon vertex along the normal
+        let contact = points
+            .iter()
+            .copied()
+            .min_by(|a, b| a.dot(normal).total_cmp(&b.dot(normal)))
+            .unwrap_or(centroid);
+        Some(Overlap { normal, depth, contact, hint: ResolutionHint::default(), feature })
     }
 
     fn overlap_point_aabb(p: Vec2, c: Vec2, h: Vec2) -> bool {
@@ -297,7 +537,7 @@ impl NarrowphaseApi for Narrowphase {
         let center_at_hit = c0 + vrel * hit.toi;
         let normal = hit.normal;
         let contact = center_at_hit - normal * h0;
-        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
     }
 
     fn sweep_circle_aabb(
@@ -322,7 +562,20 @@ impl NarrowphaseApi for Narrowphase {
         let center_at_hit = c + vrel * hit.toi;
         let normal = hit.normal;
         let contact = center_at_hit - normal * r;
-        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
+    }
+
+    fn sweep_aabb_circle(
+        box_c: Vec2,
+        box_h: Vec2,
+        box_v: Vec2,
+        c: Vec2,
+        r: f32,
+        v: Vec2,
+    ) -> Option<SweepHit> {
+        // Swap so the circle leads, then flip the normal back to point into the box
+        let hit = Self::sweep_circle_aabb(c, r, v, box_c, box_h, box_v)?;
+        Some(SweepHit { normal: -hit.normal, ..hit })
     }
 
     fn sweep_circle_circle(
@@ -345,7 +598,7 @@ impl NarrowphaseApi for Narrowphase {
         let center_at_hit = c0 + vrel * hit.toi;
         let normal = hit.normal; // outward from expanded circle => from B to A
         let contact = center_at_hit - normal * r0;
-        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default() })
+        Some(SweepHit { toi: hit.toi, normal, contact, hint: ResolutionHint::default(), impact_speed: 0.0 })
     }
 
     fn aabb_tile_pushout(c: Vec2, he: Vec2, tile_min: Vec2, cell: f32) -> (Vec2, f32, Vec2) {
@@ -423,6 +676,38 @@ mod tests {
         assert!(o.normal.x.abs() > 0.0 || o.normal.y.abs() > 0.0);
     }
 
+    #[test]
+    fn test_overlap_aabb_aabb_touching_contacts() {
+        // Flat edge: A's right face touches B's left face over y in [0, 1]
+        let ov = Narrowphase::overlap_aabb_aabb(Vec2::ZERO, Vec2::ONE, Vec2::new(2.0, 0.5), Vec2::new(1.0, 0.5)).unwrap();
+        assert_eq!(ov.depth, 0.0);
+        assert_eq!(ov.normal, Vec2::NEG_X);
+        assert_eq!(ov.contact, Vec2::new(1.0, 0.5));
+        // Corner: boxes meet only at (1, 1)
+        let ov = Narrowphase::overlap_aabb_aabb(Vec2::ZERO, Vec2::ONE, Vec2::new(1.5, 1.5), Vec2::splat(0.5)).unwrap();
+        assert_eq!(ov.depth, 0.0);
+        assert!((ov.normal - Vec2::splat(-std::f32::consts::FRAC_1_SQRT_2)).length() < 1e-6);
+        assert_eq!(ov.contact, Vec2::ONE);
+    }
+
+    #[test]
+    fn test_overlap_aabb_aabb_biased_keeps_previous_axis() {
+        let (h0, h1) = (Vec2::splat(0.5), Vec2::splat(0.5));
+        // ox = 0.2, oy = 0.201: plain picks x; a +Y history keeps y inside eps
+        let c1 = Vec2::new(0.8, 0.799);
+        let plain = Narrowphase::overlap_aabb_aabb(Vec2::ZERO, h0, c1, h1).unwrap();
+        assert_eq!(plain.normal, Vec2::NEG_X);
+        let ov = Narrowphase::overlap_aabb_aabb_biased(Vec2::ZERO, h0, c1, h1, Vec2::NEG_Y, 0.01).unwrap();
+        assert_eq!(ov.normal, Vec2::NEG_Y);
+        assert!((ov.depth - 0.201).abs() < 1e-5);
+        // Outside eps the minimum axis still wins
+        let ov = Narrowphase::overlap_aabb_aabb_biased(Vec2::ZERO, h0, c1, h1, Vec2::NEG_Y, 1e-4).unwrap();
+        assert_eq!(ov.normal, Vec2::NEG_X);
+        // No history: same as the unbiased test
+        let ov = Narrowphase::overlap_aabb_aabb_biased(Vec2::ZERO, h0, c1, h1, Vec2::ZERO, 0.01).unwrap();
+        assert_eq!(ov.normal, plain.normal);
+    }
+
     #[test]
     fn test_overlap_aabb_aabb_separated() {
         let c0 = Vec2::new(0.0, 0.0);
@@ -455,6 +740,36 @@ mod tests {
         assert!((o.depth).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_overlap_circle_aabb_normals() {
+        let bc = Vec2::new(0.0, 0.0);
+        let bh = Vec2::new(2.0, 1.0);
+        // Center outside, above the top face
+        let o = Narrowphase::overlap_circle_aabb(Vec2::new(0.5, 1.25), 0.5, bc, bh).unwrap();
+        assert!((o.normal - Vec2::Y).length() < 1e-5);
+        assert!((o.depth - 0.25).abs() < 1e-5);
+        // Center inside, nearest to the right face
+        let o = Narrowphase::overlap_circle_aabb(Vec2::new(1.75, 0.0), 0.5, bc, bh).unwrap();
+        assert!((o.normal - Vec2::X).length() < 1e-5);
+        assert!((o.depth - 0.75).abs() < 1e-5);
+        // Separated diagonally past the corner
+        assert!(Narrowphase::overlap_circle_aabb(Vec2::new(2.5, 1.5), 0.5, bc, bh).is_none());
+    }
+
+    #[test]
+    fn test_overlap_polygon_aabb_sat() {
+        // Diamond whose bounds overlap the box corner but whose edge does not
+        let diamond = [Vec2::new(1.5, 0.6), Vec2::new(2.4, 1.5), Vec2::new(1.5, 2.4), Vec2::new(0.6, 1.5)];
+        assert!(Narrowphase::overlap_polygon_aabb(&diamond, Vec2::ZERO, Vec2::ONE).is_none());
+        // Triangle poking into the top face
+        let tri = [Vec2::new(-0.5, 0.8), Vec2::new(0.5, 0.8), Vec2::new(0.0, 2.0)];
+        let ov = Narrowphase::overlap_polygon_aabb(&tri, Vec2::ZERO, Vec2::ONE).unwrap();
+        assert!((ov.normal - Vec2::Y).length() < 1e-6);
+        assert!((ov.depth - 0.2).abs() < 1e-5);
+        assert!((ov.contact.y - 0.8).abs() < 1e-6);
+        assert!(Narrowphase::overlap_polygon_aabb(&tri[..2], Vec2::ZERO, Vec2::ONE).is_none());
+    }
+
     #[test]
     fn test_overlap_point_aabb() {
         let c = Vec2::new(0.0, 0.0);
@@ -499,6 +814,12 @@ mod tests {
         assert!(hit.contact.x <= min.x + 1e-5);
     }
 
+    #[test]
+    fn test_ray_aabb_box_behind_misses() {
+        let hit = Narrowphase::ray_aabb(Vec2::ZERO, Vec2::NEG_Y, Vec2::new(-1.0, 4.0), Vec2::new(1.0, 6.0));
+        assert!(hit.is_none());
+    }
+
     #[test]
     fn test_ray_aabb_parallel_miss() {
         let o = Vec2::new(-5.0, 2.0);
@@ -519,6 +840,29 @@ mod tests {
         assert!((hit.normal.x - (-1.0)).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_ray_capsule_cap_and_body_hits() {
+        // Vertical capsule from (0,-1) to (0,1), radius 0.5
+        let (p0, p1) = (Vec2::new(0.0, -1.0), Vec2::new(0.0, 1.0));
+        // Body: horizontal ray hits the flat side
+        let h = Narrowphase::ray_capsule(Vec2::new(-3.0, 0.5), Vec2::X, p0, p1, 0.5).unwrap();
+        assert!((h.toi - 2.5).abs() < 1e-6);
+        assert!((h.normal + Vec2::X).length() < 1e-6);
+        // Cap: downward ray hits the top of the upper circle
+        let h = Narrowphase::ray_capsule(Vec2::new(0.0, 4.0), Vec2::NEG_Y, p0, p1, 0.5).unwrap();
+        assert!((h.toi - 2.5).abs() < 1e-6);
+        assert!((h.normal - Vec2::Y).length() < 1e-6);
+        // Cap corner: above the body's end, the rounded cap is hit later than a box would be
+        let h = Narrowphase::ray_capsule(Vec2::new(-3.0, 1.3), Vec2::X, p0, p1, 0.5).unwrap();
+        assert!((h.toi - 2.6).abs() < 1e-5);
+        assert!((h.normal - Vec2::new(-0.8, 0.6)).length() < 1e-5);
+        // Miss and inside
+        assert!(Narrowphase::ray_capsule(Vec2::new(-3.0, 1.6), Vec2::X, p0, p1, 0.5).is_none());
+        let h = Narrowphase::ray_capsule(Vec2::new(0.2, 0.0), Vec2::X, p0, p1, 0.5).unwrap();
+        assert_eq!(h.toi, 0.0);
+        assert_eq!(h.normal, Vec2::ZERO);
+    }
+
     #[test]
     fn test_segment_aabb_hit_and_miss() {
         let a = Vec2::new(-2.0, 0.0);
@@ -545,6 +889,27 @@ mod tests {
         assert!((hit.normal.x - (-1.0)).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_segment_segment_cross_and_parallel() {
+        let hit = Narrowphase::segment_segment(
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.5, -1.0),
+            Vec2::new(0.5, 1.0),
+        )
+        .unwrap();
+        assert!((hit.toi - 0.75).abs() < 1e-5);
+        assert!((hit.contact.x - 0.5).abs() < 1e-5);
+        assert!((hit.normal.x + 1.0).abs() < 1e-5);
+        assert!(Narrowphase::segment_segment(
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(1.0, 1.0),
+        )
+        .is_none());
+    }
+
     // --- Sweeps (CCD) -----------------------------------------------------
 
     #[test]
@@ -589,6 +954,49 @@ mod tests {
         assert!((hit.contact.x - (-1.0)).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_overlap_mtv_separates_a() {
+        let still_touching = |ov: Option<Overlap>| ov.is_none_or(|o| o.depth < 1e-5);
+        for (c0, h0, c1, h1) in [
+            (Vec2::new(0.7, 0.2), Vec2::splat(0.5), Vec2::ZERO, Vec2::splat(0.5)),
+            (Vec2::new(-0.1, -0.9), Vec2::new(1.0, 0.5), Vec2::ZERO, Vec2::splat(0.5)),
+        ] {
+            let ov = Narrowphase::overlap_aabb_aabb(c0, h0, c1, h1).unwrap();
+            assert!(ov.depth > 0.0 && ov.mtv().dot(c0 - c1) > 0.0);
+            assert!(still_touching(Narrowphase::overlap_aabb_aabb(c0 + ov.mtv(), h0, c1, h1)));
+        }
+        for (c0, r0, c1, r1) in [(Vec2::new(0.5, 0.5), 1.0, Vec2::ZERO, 0.5), (Vec2::new(-1.2, 0.0), 0.5, Vec2::ZERO, 1.0)] {
+            let ov = Narrowphase::overlap_circle_circle(c0, r0, c1, r1).unwrap();
+            assert!(ov.depth > 0.0 && ov.mtv().dot(c0 - c1) > 0.0);
+            assert!(still_touching(Narrowphase::overlap_circle_circle(c0 + ov.mtv(), r0, c1, r1)));
+        }
+    }
+
+    #[test]
+    fn test_sweep_aabb_circle_matches_swapped() {
+        // The box moves right into a still circle; the normal points from the circle into the box
+        let hit = Narrowphase::sweep_aabb_circle(Vec2::new(-3.0, 0.0), Vec2::ONE, Vec2::new(5.0, 0.0), Vec2::ZERO, 1.0, Vec2::ZERO).unwrap();
+        let swapped = Narrowphase::sweep_circle_aabb(Vec2::ZERO, 1.0, Vec2::ZERO, Vec2::new(-3.0, 0.0), Vec2::ONE, Vec2::new(5.0, 0.0)).unwrap();
+        assert!((hit.toi - 0.2).abs() < 1e-5);
+        assert_eq!(hit.toi, swapped.toi);
+        assert_eq!(hit.normal, -swapped.normal);
+        assert!((hit.normal.x + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sweep_circle_aabb_exact_corner() {
+        let c = Vec2::new(-3.0, -3.0);
+        let v = Vec2::new(5.0, 5.0);
+        let hit = Narrowphase::sweep_circle_aabb(c, 1.0, v, Vec2::ZERO, Vec2::ONE, Vec2::ZERO).unwrap();
+        assert!((hit.toi - 0.2).abs() < 1e-5);
+        let diag = Vec2::new(-1.0, -1.0).normalize();
+        assert!((hit.normal - diag).length() < 1e-5);
+        assert!((hit.normal.length() - 1.0).abs() < 1e-5);
+        // Same corner entry through the segment path
+        let seg = Narrowphase::line_segment_aabb(c, c + v, Vec2::splat(-2.0), Vec2::splat(2.0)).unwrap();
+        assert!((seg.normal - diag).length() < 1e-5);
+    }
+
     #[test]
     fn test_circle_tile_pushout_signed_depth() {
         let tile_min = Vec2::new(0.0, 0.0);
@@ -626,4 +1034,22 @@ mod tests {
         assert!(d3 > 0.0);
         assert!(n3.length() > 0.9);
     }
+
+    #[test]
+    fn test_overlap_features() {
+        let h = Vec2::splat(1.0);
+        // A sits on top of B: B's +Y face
+        let o = Narrowphase::overlap_aabb_aabb(Vec2::new(0.0, 1.9), h, Vec2::ZERO, h).unwrap();
+        assert_eq!(o.feature, ContactFeature::Face(2));
+        let o = Narrowphase::overlap_aabb_aabb(Vec2::new(-1.9, 0.0), h, Vec2::ZERO, h).unwrap();
+        assert_eq!(o.feature, ContactFeature::Face(1));
+        // Corner touch at B's (+X, -Y) corner
+        let o = Narrowphase::overlap_aabb_aabb(Vec2::new(2.0, -2.0), h, Vec2::ZERO, h).unwrap();
+        assert_eq!(o.feature, ContactFeature::Corner(1));
+        // Circle past B's (+X, +Y) corner
+        let o = Narrowphase::overlap_circle_aabb(Vec2::new(1.3, 1.3), 0.5, Vec2::ZERO, h).unwrap();
+        assert_eq!(o.feature, ContactFeature::Corner(3));
+        let o = Narrowphase::overlap_circle_circle(Vec2::ZERO, 1.0, Vec2::X, 1.0).unwrap();
+        assert_eq!(o.feature, ContactFeature::Round);
+    }
 }
@@ -24,6 +24,27 @@ impl LayerMask {
         }
     }
 
+    /// Member of every layer, collides with every layer.
+    pub fn all() -> Self {
+        Self::simple(u32::MAX, u32::MAX)
+    }
+
+    /// No layers, collides with nothing.
+    pub fn none() -> Self {
+        Self::simple(0, 0)
+    }
+
+    /// Query mask that only hits `layers`. It sits on every layer so targets always consent.
+    pub fn only(layers: u32) -> Self {
+        Self::simple(u32::MAX, layers)
+    }
+
+    /// Builder: add `bits` to `exclude`.
+    pub fn with_exclude(mut self, bits: u32) -> Self {
+        self.exclude |= bits;
+        self
+    }
+
     /// Pair filtering rule (spec):
     /// A may hit B iff `(A.collides_with & B.layer) != 0` AND `(A.exclude & B.layer) == 0`.
     /// Engines SHOULD also check the symmetric predicate (B→A) to require mutual consent.
@@ -45,6 +66,17 @@ pub enum ColliderKind {
     Point,
 }
 
+impl ColliderKind {
+    /// Circles with `radius <= 0` degrade to `Point`, so a negative radius can't square
+    /// into a phantom positive one. Other kinds are unchanged.
+    pub(crate) fn normalized(self) -> Self {
+        match self {
+            ColliderKind::Circle { radius } if radius <= 0.0 => ColliderKind::Point,
+            kind => kind,
+        }
+    }
+}
+
 /// One collider instance to be considered for **this frame**.
 #[derive(Copy, Clone, Debug)]
 pub struct ColliderDesc {
@@ -53,6 +85,47 @@ pub struct ColliderDesc {
     pub mask: LayerMask,
     /// Optional user key echoed in events and query results.
     pub user_key: Option<ColKey>,
+    /// How this collider finds event partners.
+    pub broadphase: BroadphaseMode,
+    /// Free-form metadata (material, team, ...) echoed in events as `a_tag`/`b_tag`.
+    /// Unlike `user_key` it needn't be unique and is never looked up.
+    pub tag: u32,
+    /// Which `generate_events` phases this collider takes part in. Queries ignore it.
+    pub event_targets: EventTargets,
+}
+
+/// Per-collider broadphase participation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BroadphaseMode {
+    /// Indexed by `WorldConfig::broadphase` like everything else.
+    #[default]
+    Grid,
+    /// Skips grid insertion and is narrowphase-tested against every frame collider and
+    /// static in `generate_events`. For a few world-sized sensors; each costs O(n).
+    Global,
+}
+
+/// Bodies a collider generates events against.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EventTargets {
+    #[default]
+    Both,
+    /// Tiles only (e.g. bullets): the collider and static phases skip it entirely.
+    TilesOnly,
+    /// Other colliders and statics only; the tile phase skips it.
+    CollidersOnly,
+}
+
+impl EventTargets {
+    /// Pairs with frame colliders and statics.
+    pub fn colliders(self) -> bool {
+        self != EventTargets::TilesOnly
+    }
+
+    /// Pairs with tiles.
+    pub fn tiles(self) -> bool {
+        self != EventTargets::CollidersOnly
+    }
 }
 
 /// Per-frame motion used for continuous detection.
@@ -60,32 +133,193 @@ pub struct ColliderDesc {
 pub struct Motion {
     /// Velocity over the frame (units per frame). CCD uses relative velocity.
     pub vel: Vec2,
+    /// Per-collider override for `WorldConfig::tighten_swept_aabb` (None = use config).
+    pub tighten: Option<bool>,
+    /// Exact displacement over the frame, used instead of `vel * dt` when set (`vel` is then
+    /// ignored; relative speeds use `delta / dt`).
+    pub delta: Option<Vec2>,
+    /// Per-collider step length used instead of `WorldConfig::dt` for `vel * dt` (e.g.
+    /// bullet-time entities). Ignored when `delta` is set.
+    pub dt: Option<f32>,
+}
+
+impl Motion {
+    /// Motion given as a frame displacement (e.g. known start/end positions), avoiding a
+    /// `vel = delta / dt` round trip.
+    pub fn from_delta(delta: Vec2) -> Self {
+        Motion {
+            delta: Some(delta),
+            ..Default::default()
+        }
+    }
+
+    /// Motion at `vel` stepped by its own `dt` rather than the world's.
+    pub fn with_dt(vel: Vec2, dt: f32) -> Self {
+        Motion {
+            vel,
+            dt: Some(dt),
+            ..Default::default()
+        }
+    }
 }
 
 /// Resolution hint attached to hits (tiles & non-tiles).
 #[derive(Copy, Clone, Debug, Default)]
 pub struct ResolutionHint {
     pub safe_pos: Option<Vec2>,
+    /// Set on overlap events that already penetrate (`depth > 0`) at the frame's start
+    /// positions, on tile overlaps that begin inside a solid, and on sweep hits at `toi == 0`
+    /// (the shapes already touch or overlap when the motion starts).
     pub start_embedded: bool,
     pub fully_embedded: bool,
+    /// Remaining motion after the hit projected onto the contact plane (sweeps only).
+    pub slide: Option<Vec2>,
+    /// Last sample position a sampled tile sweep actually tested free of solids (AABB,
+    /// point and square-circle tile sweeps). Unlike `safe_pos` it cannot be embedded by
+    /// an undershooting refinement; None when no tested position was free.
+    pub last_free_pos: Option<Vec2>,
+    /// Width of the bracket a sampled tile sweep's bisection ended with, in `toi` units:
+    /// the true impact lies within `[toi - toi_error, toi]`. 0 for exact sweeps.
+    pub toi_error: f32,
+    /// Tile face(s) a sampled tile sweep crossed to reach the hit: `Y` for floors and
+    /// ceilings, `X` for walls, `Both` for a corner, whatever the normal says. None for
+    /// embedded starts and exact circle sweeps.
+    pub contact_axis: Option<Axis>,
+}
+
+/// Axis of a tile face hit, from `ResolutionHint::contact_axis`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Both,
+}
+
+/// Which part of a shape a contact lies on, so contacts of one pair can be told apart
+/// (and matched up) across frames.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ContactFeature {
+    #[default]
+    None,
+    /// Box face by outward axis: 0 = +X, 1 = -X, 2 = +Y, 3 = -Y.
+    Face(u8),
+    /// Box corner: bit 0 set = +X side, bit 1 set = +Y side.
+    Corner(u8),
+    /// Polygon edge `i`, from `points[i]` to `points[i + 1]`.
+    Edge(u32),
+    /// Circle against circle (or point), and any sweep involving a round shape: a single
+    /// feature.
+    Round,
+}
+
+impl ContactFeature {
+    /// Box feature facing along `n`: a face when `n` is axis-aligned, a corner when
+    /// diagonal, `None` for a zero vector.
+    pub fn from_box_normal(n: Vec2) -> Self {
+        match (n.x != 0.0, n.y != 0.0) {
+            (false, false) => ContactFeature::None,
+            (true, false) => ContactFeature::Face(u8::from(n.x < 0.0)),
+            (false, true) => ContactFeature::Face(2 + u8::from(n.y < 0.0)),
+            (true, true) => ContactFeature::Corner(u8::from(n.x > 0.0) | u8::from(n.y > 0.0) << 1),
+        }
+    }
+
+    /// Distinct integer per feature, for hashing into contact ids.
+    pub(crate) fn code(self) -> u64 {
+        match self {
+            ContactFeature::None => 0,
+            ContactFeature::Face(i) => 1 << 32 | i as u64,
+            ContactFeature::Corner(i) => 2 << 32 | i as u64,
+            ContactFeature::Edge(i) => 3 << 32 | i as u64,
+            ContactFeature::Round => 4 << 32,
+        }
+    }
 }
 
 /// Overlap contact result (discrete).
 #[derive(Copy, Clone, Debug)]
 pub struct Overlap {
+    /// Unit separating direction from `B` into `A` (zero when the centers coincide and no
+    /// axis is preferred).
     pub normal: Vec2,
+    /// Penetration along `normal`; moving `A` by `normal * depth` separates the pair.
     pub depth: f32,
     pub contact: Vec2,
     pub hint: ResolutionHint,
+    /// Feature the contact lies on. For box contacts this is the face/corner the normal
+    /// leaves through (the `B` box, or the box of a swapped circle/point pair).
+    pub feature: ContactFeature,
+}
+
+/// Resting-contact manifold for a solver. Circle contacts need a single point, taken on
+/// the surface of `B` (or of the box in circle/box pairs) so it stays put while the
+/// penetration depth jitters from frame to frame.
+#[derive(Copy, Clone, Debug)]
+pub struct Manifold {
+    /// From `B` into `A`, as in [`Overlap::normal`].
+    pub normal: Vec2,
+    pub depth: f32,
+    pub point: Vec2,
+    pub feature: ContactFeature,
+}
+
+impl Overlap {
+    /// Minimum translation vector `normal * depth`: add it to `A`'s position (or subtract
+    /// it from `B`'s) to leave the pair just touching.
+    pub fn mtv(&self) -> Vec2 {
+        self.normal * self.depth
+    }
+}
+
+impl From<Overlap> for Manifold {
+    fn from(ov: Overlap) -> Self {
+        Manifold {
+            normal: ov.normal,
+            depth: ov.depth,
+            point: ov.contact,
+            feature: ov.feature,
+        }
+    }
 }
 
 /// Sweep (time-of-impact) result for continuous detection.
 #[derive(Copy, Clone, Debug)]
 pub struct SweepHit {
+    /// Time of impact. For sweeps: fraction of the frame's motion in `[0, 1]`, or seconds
+    /// when `WorldConfig::toi_in_seconds` is set. For rays and casts: distance along `dir`.
     pub toi: f32,
     pub normal: Vec2,
     pub contact: Vec2,
     pub hint: ResolutionHint,
+    /// Closing speed at impact, `(a.vel - b.vel).dot(-normal)` (units per frame, positive
+    /// when approaching). Set on sweep events and `sweep_*_tiles`; 0 for rays and casts.
+    pub impact_speed: f32,
+}
+
+impl SweepHit {
+    /// Rescale `toi` (and its error bound) into another unit.
+    pub(crate) fn scale_toi(&mut self, s: f32) {
+        self.toi *= s;
+        self.hint.toi_error *= s;
+    }
+
+    /// Move the hit's positions by `by` (contact, `safe_pos`, `last_free_pos`).
+    pub(crate) fn translate(&mut self, by: Vec2) {
+        self.contact += by;
+        self.hint.safe_pos = self.hint.safe_pos.map(|p| p + by);
+        self.hint.last_free_pos = self.hint.last_free_pos.map(|p| p + by);
+    }
+}
+
+/// State of a collider pair over the frame, from `cast_pair`.
+#[derive(Copy, Clone, Debug)]
+pub enum CastResult {
+    /// Not touching at the start and no contact during the frame's motion.
+    Separated,
+    /// Not touching at the start; first contact at `toi`.
+    WillHit(SweepHit),
+    /// Already overlapping or touching at the start positions (even if separating).
+    Overlapping(Overlap),
 }
 
 /// Event discriminator.
@@ -99,6 +333,15 @@ pub enum EventKind {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FrameId(pub u32);
 
+impl FrameId {
+    /// Stand-in for the transient collider of `sweep_and_emit`; never a real frame index.
+    pub const PROBE: FrameId = FrameId(u32::MAX);
+}
+
+/// Handle to a static collider that persists across frames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StaticId(pub u32);
+
 /// Opaque handle to a registered tilemap layer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TileMapRef(pub u32);
@@ -110,13 +353,66 @@ pub struct TileRef {
     pub cell_xy: UVec2,
 }
 
-/// Reference to an event/query participant (collider or tile).
+/// Up to two distinct tile contacts (tile, face normal); unused slots are `None`.
+pub type TileContacts = [Option<(TileRef, Vec2)>; 2];
+
+/// Reference to an event/query participant (collider, static collider, or tile).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BodyRef {
     Collider(FrameId),
+    Static(StaticId),
     Tile(TileRef),
 }
 
+/// What a query hit, resolved so a caller needs no second lookup.
+#[derive(Copy, Clone, Debug)]
+pub enum BodyDesc {
+    /// A frame collider or static, as pushed (circles with `radius <= 0` read as `Point`).
+    Shape(ColliderDesc),
+    /// A tile cell: its map's mask and key, the cell's world-space min corner and size, and
+    /// the raw `solids` byte.
+    Tile {
+        mask: LayerMask,
+        user_key: Option<ColKey>,
+        min: Vec2,
+        cell: f32,
+        bits: u8,
+    },
+}
+
+/// Query results split by `BodyRef` kind, in the order the flat query reports them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BodiesByKind {
+    pub colliders: Vec<(FrameId, Option<ColKey>)>,
+    pub statics: Vec<(StaticId, Option<ColKey>)>,
+    pub tiles: Vec<(TileRef, Option<ColKey>)>,
+}
+
+impl BodiesByKind {
+    /// Total number of bodies across all kinds.
+    pub fn len(&self) -> usize {
+        self.colliders.len() + self.statics.len() + self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl FromIterator<(BodyRef, Option<ColKey>)> for BodiesByKind {
+    fn from_iter<I: IntoIterator<Item = (BodyRef, Option<ColKey>)>>(iter: I) -> Self {
+        let mut out = BodiesByKind::default();
+        for (body, key) in iter {
+            match body {
+                BodyRef::Collider(id) => out.colliders.push((id, key)),
+                BodyRef::Static(id) => out.statics.push((id, key)),
+                BodyRef::Tile(t) => out.tiles.push((t, key)),
+            }
+        }
+        out
+    }
+}
+
 /// Collision event emitted after generation.
 #[derive(Copy, Clone, Debug)]
 pub struct Event {
@@ -125,10 +421,79 @@ pub struct Event {
     pub b: BodyRef,
     pub a_key: Option<ColKey>,
     pub b_key: Option<ColKey>,
+    /// `LayerMask::layer` of `a`.
+    pub a_layer: u32,
+    /// `LayerMask::layer` of `b` (the tilemap's mask layer for tiles).
+    pub b_layer: u32,
+    /// `ColliderDesc::tag` of `a`.
+    pub a_tag: u32,
+    /// `ColliderDesc::tag` of `b` (0 for tiles).
+    pub b_tag: u32,
+    /// Stable, order-independent hash of the pair: `(a_key, b_key)` when keyed, else the
+    /// body handles (tiles also mix in their cell). Same inputs give the same id every frame.
+    pub pair_id: u64,
+    /// `pair_id` mixed with the contact feature (`Overlap::feature`, or the box feature
+    /// facing the hit normal for sweeps). Stable while the same features stay in contact,
+    /// e.g. for warm-starting an external solver.
+    pub contact_id: u64,
     pub overlap: Option<Overlap>,
     pub sweep: Option<SweepHit>,
 }
 
+/// `Collider(3)`, `Static(0)` or `Tile(map=0,cell=3,4)`.
+impl std::fmt::Display for BodyRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_body(f, *self, None)
+    }
+}
+
+fn fmt_body(
+    f: &mut std::fmt::Formatter<'_>,
+    body: BodyRef,
+    key: Option<ColKey>,
+) -> std::fmt::Result {
+    match body {
+        BodyRef::Collider(id) => write!(f, "Collider({}", id.0)?,
+        BodyRef::Static(id) => write!(f, "Static({}", id.0)?,
+        BodyRef::Tile(t) => write!(
+            f,
+            "Tile(map={},cell={},{}",
+            t.map.0, t.cell_xy.x, t.cell_xy.y
+        )?,
+    }
+    if let Some(k) = key {
+        write!(f, ",key={k}")?;
+    }
+    f.write_str(")")
+}
+
+/// One-line summary for logs, e.g. `Sweep Collider(0,key=1) vs Tile(map=0,cell=3,4)
+/// toi=0.25 n=(-1,0)`. Numbers honor a precision (`{ev:.2}`).
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let num = |f: &mut std::fmt::Formatter<'_>, v: f32| match f.precision() {
+            Some(p) => write!(f, "{v:.p$}"),
+            None => write!(f, "{v}"),
+        };
+        write!(f, "{:?} ", self.kind)?;
+        fmt_body(f, self.a, self.a_key)?;
+        f.write_str(" vs ")?;
+        fmt_body(f, self.b, self.b_key)?;
+        let (label, value, normal) = match (self.kind, self.sweep, self.overlap) {
+            (EventKind::Sweep, Some(s), _) => ("toi", s.toi, s.normal),
+            (EventKind::Overlap, _, Some(o)) => ("depth", o.depth, o.normal),
+            _ => return Ok(()),
+        };
+        write!(f, " {label}=")?;
+        num(f, value)?;
+        f.write_str(" n=(")?;
+        num(f, normal.x)?;
+        f.write_str(",")?;
+        num(f, normal.y)?;
+        f.write_str(")")
+    }
+}
+
 /// World-level configuration for the ephemeral detector.
 #[derive(Clone, Debug)]
 pub struct WorldConfig {
@@ -139,10 +504,91 @@ pub struct WorldConfig {
     pub enable_sweep_events: bool,
     pub max_events: usize,
     pub enable_timing: bool,
-    /// Epsilon used when computing safe_pos for tile hits.
+    /// Path backoff for tile raycast `safe_pos`, and the slop within which tile sweep contacts
+    /// count as simultaneous. Tile sweeps back off by `skin_width` instead.
     pub tile_eps: f32,
     /// If true, require mutual consent for events/queries (colliders and tiles).
     pub require_mutual_consent: bool,
+    /// Clearance backed off along the contact normal when computing sweep `safe_pos`.
+    pub skin_width: f32,
+    /// Broadphase used for frame colliders (statics always use their own grid).
+    pub broadphase: Broadphase,
+    /// Which event kind(s) a pair produces when both sweep and overlap apply.
+    pub overlap_policy: OverlapPolicy,
+    /// Sweep circles against tiles as their bounding square (sampled, faster) instead of
+    /// the exact rounded-corner TOI. Affects circle tile sweeps, `cast`, and tile events.
+    pub square_circle_tile_sweep: bool,
+    /// Every N frames `begin_frame` releases the dynamic grid's retained capacity instead
+    /// of only clearing it, bounding memory when colliders roam a large area. 0 = never.
+    pub grid_shrink_interval: u32,
+    /// Order this frame's overlap events in the buffer deepest-first; sweep events keep their
+    /// slots. Ignored by `generate_events_with`, which streams events as they are found.
+    pub sort_overlaps_by_depth: bool,
+    /// Report `SweepHit::toi` from events and the `sweep_*` queries in seconds (`toi * dt`)
+    /// instead of as a fraction of the frame. Rays, casts and `NarrowphaseApi` are unaffected.
+    pub toi_in_seconds: bool,
+    /// Which body `raycast_all` and `cast` report when a tile and a collider/static are hit
+    /// at exactly the same `toi`. Default `Collider`.
+    pub ray_tie_preference: TiePreference,
+    /// Tile raycasts ignore the solid cell the ray starts in and report the next solid
+    /// cell they enter instead of a `toi = 0`, zero-normal hit. Default false.
+    pub raycast_skip_start_solid: bool,
+    /// Play-area rectangle `(min, max)`. Colliders whose swept bounds miss it entirely are
+    /// listed by `out_of_bounds` (for despawning). None disables the check.
+    pub world_bounds: Option<(Vec2, Vec2)>,
+    /// Two `Point` colliders overlap when at most this far apart, and `query_point*` hits a
+    /// point collider within it. Default 0 (exact equality).
+    pub point_eps: f32,
+    /// Emit a tile overlap event for every solid cell under a collider instead of only the
+    /// first one found (still capped by `max_events`). Sweeps stay one per collider.
+    pub tile_events_per_cell: bool,
+    /// Grid broadphase only: a collider whose bounds span more than this many cells skips
+    /// the grid and is tested against every query and collider directly, like
+    /// `BroadphaseMode::Global`. Keeps one huge body from filling thousands of cells. 0 = off.
+    pub large_collider_cell_threshold: u32,
+    /// Forgiveness margin for collider and static overlap tests (pairs, overlap events,
+    /// `deepest_overlap`, `overlaps_between`): shapes closer than this count as overlapping
+    /// and report a negative depth (minus the gap). Boxes grow with square corners. Tiles
+    /// and region queries stay exact. 0 = exact contact.
+    pub contact_skin: f32,
+}
+
+/// Event selection per pair (collider, static, or tile). `enable_sweep_events` and
+/// `enable_overlap_events` still gate each kind on top of this.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Only sweep events; pairs that don't move relative to each other emit nothing.
+    SweepOnly,
+    /// Only overlap events at the frame's start positions.
+    OverlapOnly,
+    /// Sweep if the pair moves and hits, otherwise overlap.
+    #[default]
+    SweepThenOverlapFallback,
+    /// Sweep and overlap independently; a pair can emit one of each.
+    Both,
+}
+
+/// Winner of an exact `toi` tie between a tile and a collider (or static) in unified casts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TiePreference {
+    /// Keep the collider/static (e.g. a pass-through trigger in front of a wall).
+    #[default]
+    Collider,
+    /// Report the tile (solid wall over a pass-through collider).
+    Tile,
+}
+
+/// Broadphase structure for frame colliders, rebuilt in `end_frame`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Broadphase {
+    /// Uniform grid keyed by `cell_size`; best when collider sizes are similar.
+    #[default]
+    Grid,
+    /// AABB tree (median split on the longest axis); robust to widely varying sizes.
+    Bvh,
+    /// Sort-and-sweep on `min.x`; suits scenes spread along one dominant axis.
+    /// Queries and raycasts scan the sorted prefix instead of a grid.
+    Sap,
 }
 
 /// Description of a tilemap to attach to the world.
@@ -155,8 +601,93 @@ pub struct TileMapDesc<'a> {
     pub solids: &'a [u8],
     pub mask: LayerMask,
     pub user_key: Option<ColKey>,
+    /// A tile is solid when `byte & solid_mask != 0`; `0xFF` treats any nonzero byte as solid.
+    pub solid_mask: u8,
 }
 
+impl<'a> TileMapDesc<'a> {
+    /// A map with no user key where any nonzero byte is solid (`solid_mask: 0xFF`). Set
+    /// the rest with struct update syntax: `TileMapDesc { user_key, ..TileMapDesc::new(..) }`.
+    pub fn new(
+        origin: Vec2,
+        cell: f32,
+        width: u32,
+        height: u32,
+        solids: &'a [u8],
+        mask: LayerMask,
+    ) -> Self {
+        TileMapDesc {
+            origin,
+            cell,
+            width,
+            height,
+            solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        }
+    }
+}
+
+/// Bit-packed tilemap for `attach_tilemap_bits`: cell `i = y * width + x` is solid when
+/// bit `i % 64` of `bits[i / 64]` is set. Tile values read back as 0 or 1.
+#[derive(Clone, Copy, Debug)]
+pub struct TileMapBitsDesc<'a> {
+    pub origin: Vec2,
+    pub cell: f32,
+    pub width: u32,
+    pub height: u32,
+    pub bits: &'a [u64],
+    pub mask: LayerMask,
+    pub user_key: Option<ColKey>,
+}
+
+/// An attached tilemap's layout and filtering, as reported by `tilemaps()`.
+#[derive(Copy, Clone, Debug)]
+pub struct TileMapInfo {
+    pub origin: Vec2,
+    pub cell: f32,
+    pub width: u32,
+    pub height: u32,
+    pub mask: LayerMask,
+    pub user_key: Option<ColKey>,
+    /// 1 for bit-packed maps.
+    pub solid_mask: u8,
+    /// Set by `set_tilemap_velocity`.
+    pub vel: Vec2,
+}
+
+/// Why a `TileMapDesc` was rejected by `try_attach_tilemap`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TileMapError {
+    /// `cell` must be finite and > 0.
+    InvalidCell(f32),
+    /// `origin` must be finite.
+    InvalidOrigin(Vec2),
+    /// `solids.len()` must equal `width * height` (`bits.len()` must be that many bits
+    /// rounded up to whole `u64` words).
+    SizeMismatch { expected: usize, got: usize },
+}
+
+impl std::fmt::Display for TileMapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TileMapError::InvalidCell(c) => {
+                write!(f, "tile cell size must be finite and > 0, got {c}")
+            }
+            TileMapError::InvalidOrigin(o) => write!(f, "tilemap origin must be finite, got {o}"),
+            TileMapError::SizeMismatch { expected, got } => {
+                write!(
+                    f,
+                    "tilemap has {got} tiles, expected width * height = {expected}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TileMapError {}
+
 /// Debug/performance statistics for a built frame.
 #[derive(Copy, Clone, Debug, Default)]
 pub struct WorldStats {
@@ -166,6 +697,75 @@ pub struct WorldStats {
     pub candidate_pairs: usize,
     /// Unique pairs encountered when deduplicated across cells.
     pub unique_pairs: usize,
+    /// BVH node count (0 unless `Broadphase::Bvh`).
+    pub bvh_nodes: usize,
+    /// Deepest BVH level, root = 1 (0 unless `Broadphase::Bvh`).
+    pub bvh_max_depth: usize,
+    /// Colliders left out of the broadphase for a non-finite AABB; see `invalid_colliders`.
+    pub invalid_entries: usize,
+    /// Mask checks since `begin_frame` (events and queries) that `require_mutual_consent`
+    /// refused although one side consented, e.g. `collides_with` set on only one side.
+    pub mutual_consent_rejections: usize,
+}
+
+/// `WorldStats` plus per-cell occupancy; every field is independent of hash iteration order.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct WorldStatsDetailed {
+    pub stats: WorldStats,
+    /// Most colliders registered in any one grid cell (0 unless `Broadphase::Grid`).
+    pub max_cell_occupancy: usize,
+    /// Cell holding `max_cell_occupancy`; ties go to the smallest `(x, y)`.
+    pub busiest_cell: Option<(i32, i32)>,
+}
+
+/// Retained capacities of the frame-local buffers. `begin_frame` clears but never shrinks
+/// them, so values that keep rising across frames point at unbounded growth.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub entries_cap: usize,
+    pub aabbs_cap: usize,
+    /// Slots allocated by the dynamic grid's hash map.
+    pub grid_buckets: usize,
+    /// Sum of per-cell list capacities currently held by the dynamic grid.
+    pub grid_bucket_cap_sum: usize,
+    pub events_cap: usize,
+}
+
+/// Why `PhysicsWorld::explain_pair` found no event for a pair.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PairMiss {
+    /// Either id is not a collider of this frame.
+    UnknownId,
+    /// Either collider has non-finite bounds and is skipped.
+    InvalidBounds,
+    /// The broadphase never pairs these two (includes `a == b`).
+    NotCandidates,
+    /// Layer masks refuse the pair.
+    MaskRejected,
+    /// One side's `event_targets` is `TilesOnly`.
+    TargetsExcluded,
+    /// Neither branch runs under the current flags / `overlap_policy` (or the pair is static
+    /// and overlaps are off).
+    EventsDisabled,
+    /// The tests ran and found no contact.
+    NoContact,
+}
+
+/// Step-by-step account of how `generate_events` treats one collider pair.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PairExplanation {
+    pub mask_allowed: bool,
+    pub broadphase_candidate: bool,
+    /// `a.vel - b.vel` in units per frame; zero when sweeps are disabled.
+    pub rel_vel: Vec2,
+    /// The sweep branch ran (sweeps enabled and non-zero relative velocity).
+    pub sweep_tested: bool,
+    pub sweep: Option<SweepHit>,
+    /// The overlap branch ran (per `overlap_policy`, after the sweep result).
+    pub overlap_tested: bool,
+    pub overlap: Option<Overlap>,
+    /// `None` when `generate_events` emits at least one event for the pair.
+    pub miss: Option<PairMiss>,
 }
 
 /// Timing breakdown for the last completed frame operations.
@@ -180,4 +780,6 @@ pub struct WorldTiming {
     pub generate_narrowphase_ms: f64,
 
     pub events_emitted: usize,
+    /// `PhysicsWorld::frame_counter` of the frame these numbers were recorded in.
+    pub frame_counter: u32,
 }
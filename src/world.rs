@@ -1,23 +1,47 @@
 use glam::Vec2;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+use std::ops::{ControlFlow, Range};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 
 use crate::api::{NarrowphaseApi, PhysicsWorldApi};
+use crate::broadphase::{Bvh, Sap};
+use crate::hash::{FxHashMap, FxHashSet};
 use crate::types::*;
 
 /// Ephemeral detection-only world implementation (skeleton).
 pub struct PhysicsWorld {
-    pub cfg: WorldConfig,
+    // Read through `config()`; replaced only via `set_config` at `begin_frame`
+    cfg: WorldConfig,
+    // Set by `set_config`, applied at the next `begin_frame`
+    pending_cfg: Option<WorldConfig>,
     pub frame_counter: u32,
 
     // Frame-local storage
     entries: Vec<Entry>,
     aabbs: Vec<(Vec2, Vec2)>, // (min, max) per entry
-    key_to_id: HashMap<ColKey, FrameId>,
+    key_to_id: FxHashMap<ColKey, FrameId>,
 
     // Uniform grid: cell coord -> list of indices into `entries`
-    grid: HashMap<(i32, i32), Vec<usize>>,
+    grid: FxHashMap<(i32, i32), Vec<usize>>,
+    // Occupied cell range of `grid`; queries are clamped to it
+    grid_bounds: Option<CellBounds>,
+    // `BroadphaseMode::Global` entries (plus, on the grid, ones over
+    // `large_collider_cell_threshold` cells), kept out of `grid`
+    globals: Vec<usize>,
+    // Entries with a non-finite AABB; left out of every broadphase
+    invalid: Vec<usize>,
+    // Alternatives to `grid` when `cfg.broadphase` selects them
+    bvh: Bvh,
+    sap: Sap,
+
+    // Persistent static colliders (kept across frames) with their own grid
+    statics: Vec<Entry>,
+    static_aabbs: Vec<(Vec2, Vec2)>,
+    static_grid: FxHashMap<(i32, i32), Vec<usize>>,
+    static_grid_bounds: Option<CellBounds>,
 
     // Tilemaps
     tilemaps: Vec<TileMap>,
@@ -25,38 +49,445 @@ pub struct PhysicsWorld {
     // Event buffer for this frame
     events: Vec<Event>,
 
-    // Timing for last operations (optional)
+    // Timing being recorded for the current frame (optional)
     last_timing: Option<WorldTiming>,
+    // Timing of the most recently completed timed `generate_events`; survives `begin_frame`
+    completed_timing: Option<WorldTiming>,
+    // Pairs `allows_pair` refused only because consent was one-sided; atomic since it
+    // counts from `&self` queries too
+    consent_rejections: AtomicUsize,
 }
 
+/// Inclusive `((min_x, min_y), (max_x, max_y))` grid cell range.
+type CellBounds = ((i32, i32), (i32, i32));
+
+/// Bucket count for `grid_occupancy_histogram`; the last bucket is open-ended.
+const GRID_HISTOGRAM_BUCKETS: usize = 32;
+
 struct Entry {
     desc: ColliderDesc,
     motion: Motion,
 }
 
+/// Borrowed view over one collider store (frame-local or static) for grid lookups.
+#[derive(Copy, Clone)]
+struct GridView<'a> {
+    grid: &'a FxHashMap<(i32, i32), Vec<usize>>,
+    grid_bounds: Option<CellBounds>,
+    /// Entries outside `grid` that every grid lookup must still visit.
+    globals: &'a [usize],
+    /// Used instead of `grid` when present.
+    bvh: Option<&'a Bvh>,
+    /// Used instead of `grid` when present.
+    sap: Option<&'a Sap>,
+    entries: &'a [Entry],
+    aabbs: &'a [(Vec2, Vec2)],
+}
+
 #[derive(Clone)]
 struct TileMap {
     origin: Vec2,
     cell: f32,
     width: u32,
     height: u32,
-    solids: Vec<u8>,
+    solids: TileCells,
+    solid_mask: u8,
     mask: LayerMask,
     user_key: Option<ColKey>,
+    // Per-second velocity; timed tile sweeps run relative to it
+    vel: Vec2,
+    // Rows touched by `update_tiles` since the last `clear_dirty` (sorted, unique)
+    dirty_rows: Vec<u32>,
+    // Empty-run lengths per cell along ±X/±Y (0 on solids) for DDA skipping; empty for
+    // bit maps, which count zero bits instead
+    runs: TileRuns,
+    // Inclusive cell range holding every solid cell; None when the map has none
+    solid_bounds: Option<(glam::UVec2, glam::UVec2)>,
+}
+
+/// Per-cell tile values, one byte each or bit-packed (values 0/1).
+#[derive(Clone)]
+enum TileCells {
+    Bytes(Vec<u8>),
+    Bits(Vec<u64>),
+}
+
+/// Zero bits from bit `start` upward, at most `max`, skipping whole empty words.
+fn zero_run_fwd(words: &[u64], start: usize, max: usize) -> usize {
+    let mut n = 0;
+    while n < max {
+        let i = start + n;
+        let w = words[i / 64] >> (i % 64);
+        if w != 0 {
+            n += w.trailing_zeros() as usize;
+            break;
+        }
+        n += 64 - i % 64;
+    }
+    n.min(max)
+}
+
+/// Zero bits from bit `start` downward, at most `max` (`max <= start + 1`).
+fn zero_run_back(words: &[u64], start: usize, max: usize) -> usize {
+    let mut n = 0;
+    while n < max {
+        let i = start - n;
+        let w = words[i / 64] << (63 - i % 64);
+        if w != 0 {
+            n += w.leading_zeros() as usize;
+            break;
+        }
+        n += i % 64 + 1;
+    }
+    n.min(max)
+}
+
+impl TileCells {
+    #[inline]
+    fn get(&self, idx: usize) -> u8 {
+        match self {
+            TileCells::Bytes(b) => b[idx],
+            TileCells::Bits(w) => ((w[idx / 64] >> (idx % 64)) & 1) as u8,
+        }
+    }
+
+    fn set(&mut self, idx: usize, v: u8) {
+        match self {
+            TileCells::Bytes(b) => b[idx] = v,
+            TileCells::Bits(w) => {
+                let bit = 1u64 << (idx % 64);
+                if v != 0 {
+                    w[idx / 64] |= bit;
+                } else {
+                    w[idx / 64] &= !bit;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct TileRuns {
+    pos_x: Vec<u16>,
+    neg_x: Vec<u16>,
+    pos_y: Vec<u16>,
+    neg_y: Vec<u16>,
+}
+
+/// Rotated rectangle for `query_obb_all`: world-space corners for SAT, plus the rotation
+/// to bring round shapes into its local frame.
+struct Obb {
+    center: Vec2,
+    half_extents: Vec2,
+    // (cos, sin) of the angle
+    rot: Vec2,
+    corners: [Vec2; 4],
+}
+
+impl Obb {
+    fn new(center: Vec2, half_extents: Vec2, angle: f32) -> Self {
+        let half_extents = half_extents.abs();
+        let rot = Vec2::from_angle(angle);
+        let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .map(|(sx, sy)| center + rot.rotate(half_extents * Vec2::new(sx, sy)));
+        Self {
+            center,
+            half_extents,
+            rot,
+            corners,
+        }
+    }
+
+    /// `p` in the box's frame, where it is the axis-aligned `half_extents` at the origin.
+    fn to_local(&self, p: Vec2) -> Vec2 {
+        Vec2::new(self.rot.x, -self.rot.y).rotate(p - self.center)
+    }
+
+    fn overlaps_entry(&self, e: &Entry) -> bool {
+        use crate::narrowphase::Narrowphase;
+        match e.desc.kind {
+            ColliderKind::Aabb { half_extents } => {
+                Narrowphase::overlap_polygon_aabb(&self.corners, e.desc.center, half_extents)
+                    .is_some()
+            }
+            ColliderKind::Circle { radius } => PhysicsWorld::overlap_circle_aabb_bool(
+                self.to_local(e.desc.center),
+                radius,
+                Vec2::ZERO,
+                self.half_extents,
+            ),
+            ColliderKind::Point => Narrowphase::overlap_point_aabb(
+                self.to_local(e.desc.center),
+                Vec2::ZERO,
+                self.half_extents,
+            ),
+        }
+    }
+}
+
+impl TileMap {
+    #[inline]
+    fn is_solid(&self, idx: usize) -> bool {
+        self.solids.get(idx) & self.solid_mask != 0
+    }
+
+    /// Inclusive cell range covering world-space `[min, max]`, clamped to the solid cells'
+    /// bounds. None when the box misses them (or the map has none) or isn't finite.
+    fn cell_range(&self, min: Vec2, max: Vec2) -> Option<(i32, i32, i32, i32)> {
+        let (s0, s1) = self.solid_bounds?;
+        if !(min.is_finite() && max.is_finite()) {
+            return None;
+        }
+        let cell = self.cell.max(1e-5);
+        let lo = ((min - self.origin) / cell).floor();
+        let hi = ((max - self.origin) / cell).floor();
+        let (s0, s1) = (s0.as_vec2(), s1.as_vec2());
+        if hi.x < s0.x || hi.y < s0.y || lo.x > s1.x || lo.y > s1.y {
+            return None;
+        }
+        let (lo, hi) = (lo.max(s0), hi.min(s1));
+        Some((lo.x as i32, lo.y as i32, hi.x as i32, hi.y as i32))
+    }
+
+    /// World-space `(min, max)` of the solid cells' bounds.
+    fn solid_bounds_world(&self) -> Option<(Vec2, Vec2)> {
+        let (s0, s1) = self.solid_bounds?;
+        let min = self.tile_min(s0.x as i32, s0.y as i32);
+        Some((min, self.tile_min(s1.x as i32 + 1, s1.y as i32 + 1)))
+    }
+
+    /// Bounds of the solid cells in the inclusive cell rect `(x0, y0)..=(x1, y1)`.
+    fn scan_solid_bounds(
+        &self,
+        (x0, y0): (u32, u32),
+        (x1, y1): (u32, u32),
+    ) -> Option<(glam::UVec2, glam::UVec2)> {
+        let mut out: Option<(glam::UVec2, glam::UVec2)> = None;
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                if self.is_solid((y * self.width + x) as usize) {
+                    let c = glam::UVec2::new(x, y);
+                    out = Some(out.map_or((c, c), |(lo, hi)| (lo.min(c), hi.max(c))));
+                }
+            }
+        }
+        out
+    }
+
+    /// Whether a cell holding `v` (as passed to `update_tiles`) would be solid.
+    fn value_is_solid(&self, v: u8) -> bool {
+        let v = match self.solids {
+            TileCells::Bytes(_) => v,
+            TileCells::Bits(_) => (v != 0) as u8,
+        };
+        v & self.solid_mask != 0
+    }
+
+    /// Refresh `solid_bounds` after cells in the inclusive rect `(x0, y0)..=(x1, y1)`
+    /// changed: grow it by the rect's solids, rescanning only the old bounds when
+    /// `cleared_edge` (a solid on a bounds edge row or column was cleared) lets them shrink.
+    fn update_solid_bounds(
+        &mut self,
+        (x0, y0): (u32, u32),
+        (x1, y1): (u32, u32),
+        cleared_edge: bool,
+    ) {
+        if self.width == 0 || self.height == 0 {
+            self.solid_bounds = None;
+            return;
+        }
+        let kept = match self.solid_bounds {
+            Some((lo, hi)) if cleared_edge => self.scan_solid_bounds((lo.x, lo.y), (hi.x, hi.y)),
+            old => old,
+        };
+        let fresh = self.scan_solid_bounds((x0, y0), (x1, y1));
+        self.solid_bounds = match (kept, fresh) {
+            (Some((a0, a1)), Some((b0, b1))) => Some((a0.min(b0), a1.max(b1))),
+            (kept, fresh) => kept.or(fresh),
+        };
+    }
+
+    /// World-space min corner of cell `(ix, iy)`.
+    fn tile_min(&self, ix: i32, iy: i32) -> Vec2 {
+        self.origin + Vec2::new(ix as f32, iy as f32) * self.cell.max(1e-5)
+    }
+
+    /// Solid cell closest to `p` with its squared distance (0 inside), searching square
+    /// rings around `p`'s cell clamped into the map. Ring `k` is at least `(k - 1) * cell`
+    /// away, so the search stops once that exceeds the best distance found.
+    fn nearest_solid(&self, p: Vec2) -> Option<((i32, i32), f32)> {
+        if !p.is_finite() || self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let cell = self.cell.max(1e-5);
+        let (w, h) = (self.width as i32, self.height as i32);
+        let local = ((p - self.origin) / cell).floor();
+        let cx = (local.x.max(0.0) as i32).min(w - 1);
+        let cy = (local.y.max(0.0) as i32).min(h - 1);
+        let mut best: Option<((i32, i32), f32)> = None;
+        let visit = |ix: i32, iy: i32, best: &mut Option<((i32, i32), f32)>| {
+            if !self.is_solid((iy * w + ix) as usize) {
+                return;
+            }
+            let min = self.tile_min(ix, iy);
+            let d2 = p.distance_squared(p.clamp(min, min + Vec2::splat(cell)));
+            if best.is_none_or(|b| d2 < b.1) {
+                *best = Some(((ix, iy), d2));
+            }
+        };
+        for k in 0..=w.max(h) {
+            if let Some((_, d2)) = best {
+                let reach = (k - 1) as f32 * cell;
+                if reach > 0.0 && reach * reach > d2 {
+                    break;
+                }
+            }
+            let (x0, x1) = ((cx - k).max(0), (cx + k).min(w - 1));
+            for iy in (cy - k).max(0)..=(cy + k).min(h - 1) {
+                if (iy - cy).abs() == k {
+                    for ix in x0..=x1 {
+                        visit(ix, iy, &mut best);
+                    }
+                } else {
+                    for ix in [cx - k, cx + k] {
+                        if (0..w).contains(&ix) {
+                            visit(ix, iy, &mut best);
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Build the run tables; bit maps keep none and count zero bits in `empty_run`.
+    fn rebuild_all_runs(&mut self) {
+        if matches!(self.solids, TileCells::Bits(_)) {
+            self.runs = TileRuns::default();
+            return;
+        }
+        let n = self.width as usize * self.height as usize;
+        self.runs = TileRuns {
+            pos_x: vec![0; n],
+            neg_x: vec![0; n],
+            pos_y: vec![0; n],
+            neg_y: vec![0; n],
+        };
+        self.rebuild_row_runs(0, self.height);
+        self.rebuild_col_runs(0, self.width);
+    }
+
+    /// Empty cells from `(x, y)` (inclusive) stepping by `step` along X or Y, up to the map
+    /// edge or `limit`, counted from the bits of a bit map (which keeps no run tables).
+    fn bit_run(&self, (x, y): (u32, u32), along_x: bool, step: i32, limit: u32) -> u32 {
+        let TileCells::Bits(words) = &self.solids else {
+            return 0;
+        };
+        let idx = (y * self.width + x) as usize;
+        let edge = match (along_x, step > 0) {
+            (true, true) => self.width - x,
+            (true, false) => x + 1,
+            (false, true) => self.height - y,
+            (false, false) => y + 1,
+        };
+        let max = edge.min(limit) as usize;
+        if along_x && step > 0 {
+            zero_run_fwd(words, idx, max) as u32
+        } else if along_x {
+            zero_run_back(words, idx, max) as u32
+        } else {
+            // Columns aren't contiguous in memory; test cell by cell
+            let w = self.width as isize * step.signum() as isize;
+            (0..max)
+                .take_while(|&n| !self.is_solid((idx as isize + w * n as isize) as usize))
+                .count() as u32
+        }
+    }
+
+    /// Recompute ±X empty runs for rows `y0..y1`.
+    fn rebuild_row_runs(&mut self, y0: u32, y1: u32) {
+        if self.runs.pos_x.is_empty() {
+            return;
+        }
+        let w = self.width as usize;
+        for y in y0..y1.min(self.height) {
+            let row = y as usize * w;
+            let mut run = 0u16;
+            for x in (0..w).rev() {
+                run = if self.is_solid(row + x) {
+                    0
+                } else {
+                    run.saturating_add(1)
+                };
+                self.runs.pos_x[row + x] = run;
+            }
+            run = 0;
+            for x in 0..w {
+                run = if self.is_solid(row + x) {
+                    0
+                } else {
+                    run.saturating_add(1)
+                };
+                self.runs.neg_x[row + x] = run;
+            }
+        }
+    }
+
+    /// Recompute ±Y empty runs for columns `x0..x1`.
+    fn rebuild_col_runs(&mut self, x0: u32, x1: u32) {
+        if self.runs.pos_y.is_empty() {
+            return;
+        }
+        let (w, h) = (self.width as usize, self.height as usize);
+        for x in x0 as usize..(x1.min(self.width) as usize) {
+            let mut run = 0u16;
+            for y in (0..h).rev() {
+                let i = y * w + x;
+                run = if self.is_solid(i) {
+                    0
+                } else {
+                    run.saturating_add(1)
+                };
+                self.runs.pos_y[i] = run;
+            }
+            run = 0;
+            for y in 0..h {
+                let i = y * w + x;
+                run = if self.is_solid(i) {
+                    0
+                } else {
+                    run.saturating_add(1)
+                };
+                self.runs.neg_y[i] = run;
+            }
+        }
+    }
 }
 
 impl PhysicsWorldApi for PhysicsWorld {
     fn new(cfg: WorldConfig) -> Self {
         Self {
             cfg,
+            pending_cfg: None,
             frame_counter: 0,
             entries: Vec::new(),
             aabbs: Vec::new(),
-            key_to_id: HashMap::new(),
-            grid: HashMap::new(),
+            key_to_id: FxHashMap::default(),
+            grid: FxHashMap::default(),
+            grid_bounds: None,
+            globals: Vec::new(),
+            invalid: Vec::new(),
+            bvh: Bvh::default(),
+            sap: Sap::default(),
+            statics: Vec::new(),
+            static_aabbs: Vec::new(),
+            static_grid: FxHashMap::default(),
+            static_grid_bounds: None,
             tilemaps: Vec::new(),
             events: Vec::new(),
             last_timing: None,
+            completed_timing: None,
+            consent_rejections: AtomicUsize::new(0),
         }
     }
 
@@ -65,13 +496,36 @@ impl PhysicsWorldApi for PhysicsWorld {
         self.entries.clear();
         self.aabbs.clear();
         self.grid.clear();
+        self.grid_bounds = None;
+        self.globals.clear();
+        self.invalid.clear();
+        self.bvh = Bvh::default();
+        self.sap = Sap::default();
         self.key_to_id.clear();
         self.events.clear();
         self.last_timing = None;
+        *self.consent_rejections.get_mut() = 0;
+        if let Some(cfg) = self.pending_cfg.take() {
+            self.apply_config(cfg);
+        }
         self.frame_counter = self.frame_counter.wrapping_add(1);
+        let interval = self.cfg.grid_shrink_interval;
+        if interval > 0 && self.frame_counter.is_multiple_of(interval) {
+            // `clear` keeps the table sized for the busiest frame seen; release it
+            self.grid.shrink_to_fit();
+        }
+    }
+
+    fn config(&self) -> &WorldConfig {
+        &self.cfg
+    }
+
+    fn set_config(&mut self, cfg: WorldConfig) {
+        self.pending_cfg = Some(cfg);
     }
 
-    fn push(&mut self, desc: ColliderDesc, motion: Motion) -> FrameId {
+    fn push(&mut self, mut desc: ColliderDesc, motion: Motion) -> FrameId {
+        desc.kind = desc.kind.normalized();
         let id = FrameId(self.entries.len() as u32);
         if let Some(k) = desc.user_key {
             debug_assert!(
@@ -84,56 +538,90 @@ impl PhysicsWorldApi for PhysicsWorld {
         id
     }
 
+    fn push_many(&mut self, descs: &[ColliderDesc], motions: &[Motion]) -> Range<u32> {
+        let start = self.entries.len() as u32;
+        self.entries.reserve(descs.len());
+        for (i, &desc) in descs.iter().enumerate() {
+            self.push(desc, motions.get(i).copied().unwrap_or_default());
+        }
+        start..self.entries.len() as u32
+    }
+
     fn push_circle(
         &mut self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
-        vel: Vec2,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId {
+        let center = center.into();
+        let vel = vel.into();
         let desc = ColliderDesc {
             kind: ColliderKind::Circle { radius },
             center,
             mask,
             user_key,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        let motion = Motion {
+            vel,
+            ..Default::default()
         };
-        let motion = Motion { vel };
         self.push(desc, motion)
     }
 
     fn push_aabb(
         &mut self,
-        center: Vec2,
-        half_extents: Vec2,
-        vel: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId {
+        let center = center.into();
+        let half_extents = half_extents.into();
+        let vel = vel.into();
         let desc = ColliderDesc {
             kind: ColliderKind::Aabb { half_extents },
             center,
             mask,
             user_key,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        let motion = Motion {
+            vel,
+            ..Default::default()
         };
-        let motion = Motion { vel };
         self.push(desc, motion)
     }
 
     fn push_point(
         &mut self,
-        p: Vec2,
-        vel: Vec2,
+        p: impl Into<Vec2>,
+        vel: impl Into<Vec2>,
         mask: LayerMask,
         user_key: Option<ColKey>,
     ) -> FrameId {
+        let p = p.into();
+        let vel = vel.into();
         let desc = ColliderDesc {
             kind: ColliderKind::Point,
             center: p,
             mask,
             user_key,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        let motion = Motion {
+            vel,
+            ..Default::default()
         };
-        let motion = Motion { vel };
         self.push(desc, motion)
     }
 
@@ -155,6 +643,10 @@ impl PhysicsWorldApi for PhysicsWorld {
         for (i, e) in self.entries.iter().enumerate() {
             let (min, max) = self.compute_entry_aabb(e);
             self.aabbs[i] = (min, max);
+            // A NaN/infinite transform would otherwise span an unbounded cell range
+            if !(min.is_finite() && max.is_finite()) {
+                self.invalid.push(i);
+            }
         }
         let aabb_ms = t0
             .map(|t| t.elapsed().as_secs_f64() * 1000.0)
@@ -164,9 +656,33 @@ impl PhysicsWorldApi for PhysicsWorld {
         } else {
             None
         };
-        let aabbs_snapshot = self.aabbs.clone();
-        for (i, (min, max)) in aabbs_snapshot.into_iter().enumerate() {
-            self.insert_into_grid(i, min, max);
+        match self.cfg.broadphase {
+            Broadphase::Grid => {
+                let cs = self.cfg.cell_size.max(1e-5);
+                for (i, &(min, max)) in self.aabbs.iter().enumerate() {
+                    if !(min.is_finite() && max.is_finite()) {
+                        continue;
+                    }
+                    if self.is_global(i) {
+                        self.globals.push(i);
+                        continue;
+                    }
+                    Self::insert_into_grid(&mut self.grid, &mut self.grid_bounds, cs, i, min, max);
+                }
+            }
+            Broadphase::Bvh => self.bvh = Bvh::build(&self.aabbs),
+            Broadphase::Sap => self.sap = Sap::build(&self.aabbs),
+        }
+        if self.cfg.broadphase != Broadphase::Grid {
+            // Trees still index globals (so queries find them); only pairing changes. A
+            // non-finite AABB marks an invalid entry without searching `invalid`.
+            let (entries, aabbs) = (&self.entries, &self.aabbs);
+            self.globals.extend((0..entries.len()).filter(|&i| {
+                let (min, max) = aabbs[i];
+                entries[i].desc.broadphase == BroadphaseMode::Global
+                    && min.is_finite()
+                    && max.is_finite()
+            }));
         }
         let grid_ms = t1
             .map(|t| t.elapsed().as_secs_f64() * 1000.0)
@@ -181,213 +697,87 @@ impl PhysicsWorldApi for PhysicsWorld {
         }
     }
 
-    fn generate_events(&mut self) {
-        // Build candidate pairs from grid, deduplicate, then dispatch narrowphase
-        let t_all = if self.cfg.enable_timing {
-            Some(Instant::now())
-        } else {
-            None
-        };
-        let t_scan0 = if self.cfg.enable_timing {
-            Some(Instant::now())
-        } else {
-            None
-        };
-        let mut seen_pairs: HashSet<(usize, usize)> = HashSet::new();
-        let push_event = |ev: Event, buf: &mut Vec<Event>, max: usize| {
-            if buf.len() < max {
-                buf.push(ev);
-            }
+    fn out_of_bounds(&self) -> Vec<FrameId> {
+        let Some((bmin, bmax)) = self.cfg.world_bounds else {
+            return Vec::new();
         };
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                // Full sweep regardless of `tighten`, so a fast body still inside at the
+                // start is kept; NaN bounds fail every comparison and count as outside
+                let (min, max) = Self::bounds_over(
+                    e.desc.kind,
+                    e.desc.center,
+                    self.displacement(&e.motion),
+                    true,
+                );
+                !(min.x <= bmax.x && max.x >= bmin.x && min.y <= bmax.y && max.y >= bmin.y)
+            })
+            .map(|(i, _)| FrameId(i as u32))
+            .collect()
+    }
 
-        for indices in self.grid.values() {
-            for i0 in 0..indices.len() {
-                for i1 in (i0 + 1)..indices.len() {
-                    let a = indices[i0];
-                    let b = indices[i1];
-                    let key = if a < b { (a, b) } else { (b, a) };
-                    if !seen_pairs.insert(key) {
-                        continue;
-                    }
-                    if self.events.len() >= self.cfg.max_events {
-                        return;
-                    }
+    fn generate_events(&mut self) {
+        let mut buf = std::mem::take(&mut self.events);
+        let start = buf.len();
+        self.emit_events(start, &mut |ev| buf.push(ev));
+        if self.cfg.sort_overlaps_by_depth {
+            Self::sort_overlaps_by_depth(&mut buf[start..]);
+        }
+        self.events = buf;
+    }
 
-                    let t_np0 = if self.cfg.enable_timing {
-                        Some(Instant::now())
-                    } else {
-                        None
-                    };
-                    let ea = &self.entries[a];
-                    let eb = &self.entries[b];
-                    // Mask consent (possibly mutual based on config)
-                    if !self.allows_pair(ea.desc.mask, eb.desc.mask) {
-                        continue;
-                    }
+    fn generate_events_with(&mut self, mut sink: impl FnMut(&Event)) {
+        self.emit_events(0, &mut |ev| sink(&ev));
+    }
 
-                    let rel = ea.motion.vel - eb.motion.vel;
-                    let dynamic = rel.length_squared() > 1e-12;
-
-                    if dynamic && self.cfg.enable_sweep_events {
-                        if let Some(mut sweep) = self.sweep_pair_idx(a, b) {
-                            sweep.hint = ResolutionHint::default();
-                            let ev = Event {
-                                kind: crate::types::EventKind::Sweep,
-                                a: BodyRef::Collider(FrameId(a as u32)),
-                                b: BodyRef::Collider(FrameId(b as u32)),
-                                a_key: ea.desc.user_key,
-                                b_key: eb.desc.user_key,
-                                overlap: None,
-                                sweep: Some(sweep),
-                            };
-                            push_event(ev, &mut self.events, self.cfg.max_events);
-                        } else if self.cfg.enable_overlap_events
-                            && let Some(mut ov) = self.overlap_pair_idx(a, b)
-                        {
-                            ov.hint = ResolutionHint::default();
-                            let ev = Event {
-                                kind: crate::types::EventKind::Overlap,
-                                a: BodyRef::Collider(FrameId(a as u32)),
-                                b: BodyRef::Collider(FrameId(b as u32)),
-                                a_key: ea.desc.user_key,
-                                b_key: eb.desc.user_key,
-                                overlap: Some(ov),
-                                sweep: None,
-                            };
-                            push_event(ev, &mut self.events, self.cfg.max_events);
-                        }
-                    } else if self.cfg.enable_overlap_events
-                        && let Some(mut ov) = self.overlap_pair_idx(a, b)
-                    {
-                        ov.hint = ResolutionHint::default();
-                        let ev = Event {
-                            kind: crate::types::EventKind::Overlap,
-                            a: BodyRef::Collider(FrameId(a as u32)),
-                            b: BodyRef::Collider(FrameId(b as u32)),
-                            a_key: ea.desc.user_key,
-                            b_key: eb.desc.user_key,
-                            overlap: Some(ov),
-                            sweep: None,
-                        };
-                        push_event(ev, &mut self.events, self.cfg.max_events);
-                    }
-                    if let (Some(t_np0), Some(timing)) = (t_np0, self.last_timing.as_mut()) {
-                        timing.generate_narrowphase_ms += t_np0.elapsed().as_secs_f64() * 1000.0;
-                    }
-                }
-            }
-        }
-        if let Some(t_scan0) = t_scan0 {
-            if self.last_timing.is_none() {
-                self.last_timing = Some(WorldTiming::default());
-            }
-            if let Some(timing) = self.last_timing.as_mut() {
-                timing.generate_scan_ms =
-                    t_scan0.elapsed().as_secs_f64() * 1000.0 - timing.generate_narrowphase_ms;
-            }
+    fn sweep_and_emit(&mut self, mut desc: ColliderDesc, motion: Motion) -> Option<Event> {
+        desc.kind = desc.kind.normalized();
+        let probe = Entry { desc, motion };
+        let a = BodyRef::Collider(FrameId::PROBE);
+        let (min, max) = self.compute_entry_aabb(&probe);
+        if !(min.is_finite() && max.is_finite()) {
+            return None;
         }
-
-        // Phase 2: collider ↔ tile events
-        if self.events.len() < self.cfg.max_events {
-            for (i, e) in self.entries.iter().enumerate() {
-                let he = match e.desc.kind {
-                    ColliderKind::Aabb { half_extents } => half_extents,
-                    ColliderKind::Circle { radius } => Vec2::splat(radius),
-                    ColliderKind::Point => Vec2::ZERO,
-                };
-                let mask_a = e.desc.mask;
-                let v = e.motion.vel;
-                let mut emitted = false;
-                if v.length_squared() > 1e-12
-                    && self.cfg.enable_sweep_events
-                    && let Some((tref, mut hit, key_b)) =
-                        self.sweep_shape_tiles(e.desc.center, he, v, mask_a)
+        let mut found: Vec<Event> = Vec::new();
+        let mut scan = |view: GridView<'_>, body: &dyn Fn(usize) -> BodyRef| {
+            self.view_candidates(view, min, max, |j| {
+                let eb = &view.entries[j];
+                let (bmin, bmax) = view.aabbs[j];
+                if bmin.is_finite()
+                    && bmax.is_finite()
+                    && probe.desc.event_targets.colliders()
+                    && eb.desc.event_targets.colliders()
+                    && self.allows_pair(probe.desc.mask, eb.desc.mask)
                 {
-                    hit.hint.start_embedded = false;
-                    let ev = Event {
-                        kind: EventKind::Sweep,
-                        a: BodyRef::Collider(FrameId(i as u32)),
-                        b: BodyRef::Tile(tref),
-                        a_key: e.desc.user_key,
-                        b_key: key_b,
-                        overlap: None,
-                        sweep: Some(hit),
-                    };
-                    push_event(ev, &mut self.events, self.cfg.max_events);
-                    emitted = true;
-                }
-                if !emitted && self.cfg.enable_overlap_events {
-                    // Check start embedded
-                    for (mi, m) in self.tilemaps.iter().enumerate() {
-                        if !self.allows_pair(mask_a, m.mask) {
-                            continue;
-                        }
-                        if let Some(tref) = self.any_tile_overlap_at(mi, m, e.desc.center, he) {
-                            // Build overlap with pushout hint
-                            let cell = m.cell.max(1e-5);
-                            let tile_min = m.origin
-                                + Vec2::new(
-                                    tref.cell_xy.x as f32 * cell,
-                                    tref.cell_xy.y as f32 * cell,
-                                );
-                            let (normal, depth, contact) = if he == Vec2::ZERO {
-                                crate::narrowphase::Narrowphase::circle_tile_pushout(
-                                    e.desc.center,
-                                    0.0,
-                                    tile_min,
-                                    cell,
-                                )
-                            } else if he.x == he.y {
-                                // treat as circle for simplicity when square
-                                crate::narrowphase::Narrowphase::circle_tile_pushout(
-                                    e.desc.center,
-                                    he.x,
-                                    tile_min,
-                                    cell,
-                                )
-                            } else {
-                                crate::narrowphase::Narrowphase::aabb_tile_pushout(
-                                    e.desc.center,
-                                    he,
-                                    tile_min,
-                                    cell,
-                                )
-                            };
-                            let mut ov = Overlap {
-                                normal,
-                                depth,
-                                contact,
-                                hint: ResolutionHint::default(),
-                            };
-                            ov.hint.start_embedded = true;
-                            let ev = Event {
-                                kind: EventKind::Overlap,
-                                a: BodyRef::Collider(FrameId(i as u32)),
-                                b: BodyRef::Tile(tref),
-                                a_key: e.desc.user_key,
-                                b_key: m.user_key,
-                                overlap: Some(ov),
-                                sweep: None,
-                            };
-                            push_event(ev, &mut self.events, self.cfg.max_events);
-                            break;
-                        }
-                    }
+                    let evs = self.pair_events(&probe, eb, a, body(j));
+                    found.extend(evs.into_iter().flatten());
                 }
-                if self.events.len() >= self.cfg.max_events {
-                    break;
-                }
-            }
+            });
+        };
+        scan(self.dynamic_view(), &|j| {
+            BodyRef::Collider(FrameId(j as u32))
+        });
+        scan(self.static_view(), &|j| BodyRef::Static(StaticId(j as u32)));
+        if probe.desc.event_targets.tiles() {
+            self.tile_events(&probe, a, &mut |ev| {
+                found.push(ev);
+                true
+            });
         }
-        if let Some(t_all) = t_all {
-            if self.last_timing.is_none() {
-                self.last_timing = Some(WorldTiming::default());
-            }
-            if let Some(timing) = self.last_timing.as_mut() {
-                timing.generate_ms = t_all.elapsed().as_secs_f64() * 1000.0;
-                timing.events_emitted = self.events.len();
-            }
+        let time = |ev: &Event| ev.sweep.map_or(0.0, |hit| hit.toi);
+        let mut ev = found
+            .into_iter()
+            .min_by(|x, y| time(x).total_cmp(&time(y)))?;
+        if let Some(hit) = ev.sweep.as_mut() {
+            hit.scale_toi(self.toi_scale());
+        }
+        if self.events.len() < self.cfg.max_events {
+            self.events.push(ev);
         }
+        Some(ev)
     }
 
     fn drain_events(&mut self) -> Vec<Event> {
@@ -396,197 +786,358 @@ impl PhysicsWorldApi for PhysicsWorld {
         out
     }
 
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    // --- Static colliders ---------------------------------------------------
+    fn add_static(&mut self, mut desc: ColliderDesc) -> StaticId {
+        desc.kind = desc.kind.normalized();
+        let id = StaticId(self.statics.len() as u32);
+        let e = Entry {
+            desc,
+            motion: Motion::default(),
+        };
+        let (min, max) = self.compute_entry_aabb(&e);
+        let cs = self.cfg.cell_size.max(1e-5);
+        Self::insert_into_grid(
+            &mut self.static_grid,
+            &mut self.static_grid_bounds,
+            cs,
+            id.0 as usize,
+            min,
+            max,
+        );
+        self.statics.push(e);
+        self.static_aabbs.push((min, max));
+        id
+    }
+
+    fn clear_statics(&mut self) {
+        self.statics.clear();
+        self.static_aabbs.clear();
+        self.static_grid.clear();
+        self.static_grid_bounds = None;
+    }
+
     // --- Tilemap lifecycle --------------------------------------------------
     fn attach_tilemap(&mut self, desc: TileMapDesc) -> TileMapRef {
-        let map = TileMap {
+        let (mask, user_key) = (desc.mask, desc.user_key);
+        match self.try_attach_tilemap(desc) {
+            Ok(map) => map,
+            Err(e) => {
+                debug_assert!(false, "attach_tilemap: {e}");
+                self.push_empty_tilemap(mask, user_key)
+            }
+        }
+    }
+
+    fn try_attach_tilemap(&mut self, desc: TileMapDesc) -> Result<TileMapRef, TileMapError> {
+        if !(desc.cell.is_finite() && desc.cell > 0.0) {
+            return Err(TileMapError::InvalidCell(desc.cell));
+        }
+        if !desc.origin.is_finite() {
+            return Err(TileMapError::InvalidOrigin(desc.origin));
+        }
+        let expected = (desc.width as usize).saturating_mul(desc.height as usize);
+        if desc.solids.len() != expected {
+            return Err(TileMapError::SizeMismatch {
+                expected,
+                got: desc.solids.len(),
+            });
+        }
+        Ok(self.push_tilemap(TileMap {
             origin: desc.origin,
             cell: desc.cell,
             width: desc.width,
             height: desc.height,
-            solids: desc.solids.to_vec(),
+            solids: TileCells::Bytes(desc.solids.to_vec()),
+            solid_mask: desc.solid_mask,
             mask: desc.mask,
             user_key: desc.user_key,
-        };
-        self.tilemaps.push(map);
-        TileMapRef((self.tilemaps.len() - 1) as u32)
+            vel: Vec2::ZERO,
+            dirty_rows: Vec::new(),
+            runs: TileRuns::default(),
+            solid_bounds: None,
+        }))
     }
 
-    fn update_tiles(&mut self, map: TileMapRef, changed_rect: (u32, u32, u32, u32), data: &[u8]) {
-        if let Some(m) = self.tilemaps.get_mut(map.0 as usize) {
-            let (x, y, w, h) = changed_rect;
-            assert_eq!((w * h) as usize, data.len());
-            for row in 0..h {
-                let dst_y = y + row;
-                if dst_y >= m.height {
-                    break;
-                }
-                let dst_off = (dst_y * m.width + x) as usize;
-                let src_off = (row * w) as usize;
-                let len = w.min(m.width - x) as usize;
-                m.solids[dst_off..dst_off + len].copy_from_slice(&data[src_off..src_off + len]);
+    fn attach_tilemap_bits(&mut self, desc: TileMapBitsDesc) -> TileMapRef {
+        let (mask, user_key) = (desc.mask, desc.user_key);
+        match self.try_attach_tilemap_bits(desc) {
+            Ok(map) => map,
+            Err(e) => {
+                debug_assert!(false, "attach_tilemap_bits: {e}");
+                self.push_empty_tilemap(mask, user_key)
             }
         }
     }
 
-    fn detach_tilemap(&mut self, map: TileMapRef) {
-        let idx = map.0 as usize;
-        if idx < self.tilemaps.len() {
-            self.tilemaps.remove(idx);
+    fn try_attach_tilemap_bits(
+        &mut self,
+        desc: TileMapBitsDesc,
+    ) -> Result<TileMapRef, TileMapError> {
+        if !(desc.cell.is_finite() && desc.cell > 0.0) {
+            return Err(TileMapError::InvalidCell(desc.cell));
         }
-    }
-
-    fn raycast(
-        &self,
-        origin: Vec2,
-        dir: Vec2,
-        mask: LayerMask,
-        max_t: f32,
-    ) -> Option<(FrameId, SweepHit, Option<ColKey>)> {
-        if dir.length_squared() == 0.0 {
-            return None;
+        if !desc.origin.is_finite() {
+            return Err(TileMapError::InvalidOrigin(desc.origin));
         }
-        let cs = self.cfg.cell_size.max(1e-5);
-        // Setup DDA
-        let mut best: Option<(usize, SweepHit)> = None;
-        let mut tested: HashSet<usize> = HashSet::new();
-
-        let mut cell = self.world_to_cell(origin, cs);
-        let step_x = if dir.x > 0.0 {
-            1
-        } else if dir.x < 0.0 {
-            -1
-        } else {
-            0
-        };
-        let step_y = if dir.y > 0.0 {
-            1
-        } else if dir.y < 0.0 {
-            -1
-        } else {
-            0
-        };
-        let next_boundary = |c: i32, step: i32| -> f32 {
-            if step > 0 {
-                (c as f32 + 1.0) * cs
-            } else {
-                c as f32 * cs
-            }
-        };
-        let _cell_origin = Vec2::new(cell.0 as f32 * cs, cell.1 as f32 * cs);
-        let mut t_max_x = if step_x != 0 {
-            let nb = next_boundary(cell.0, step_x);
-            (nb - origin.x) / dir.x
-        } else {
-            f32::INFINITY
-        };
-        let mut t_max_y = if step_y != 0 {
-            let nb = next_boundary(cell.1, step_y);
-            (nb - origin.y) / dir.y
-        } else {
-            f32::INFINITY
-        };
-        let t_delta_x = if step_x != 0 {
-            cs / dir.x.abs()
-        } else {
-            f32::INFINITY
-        };
-        let t_delta_y = if step_y != 0 {
-            cs / dir.y.abs()
-        } else {
-            f32::INFINITY
-        };
+        let cells = (desc.width as usize).saturating_mul(desc.height as usize);
+        let expected = cells.div_ceil(64);
+        if desc.bits.len() != expected {
+            return Err(TileMapError::SizeMismatch {
+                expected,
+                got: desc.bits.len(),
+            });
+        }
+        Ok(self.push_tilemap(TileMap {
+            origin: desc.origin,
+            cell: desc.cell,
+            width: desc.width,
+            height: desc.height,
+            solids: TileCells::Bits(desc.bits.to_vec()),
+            solid_mask: 1,
+            mask: desc.mask,
+            user_key: desc.user_key,
+            vel: Vec2::ZERO,
+            dirty_rows: Vec::new(),
+            runs: TileRuns::default(),
+            solid_bounds: None,
+        }))
+    }
 
-        let mut t_curr = 0.0f32;
-        // Visit cells until exceeding max_t
-        for _ in 0..10_000 {
-            // safety cap
-            if t_curr > max_t {
-                break;
-            }
-            if let Some(list) = self.grid.get(&cell) {
-                for &idx in list {
-                    if !tested.insert(idx) {
-                        continue;
-                    }
-                    let e = &self.entries[idx];
-                    // Mask mutual consent between ray mask and collider mask
-                    if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
-                        continue;
-                    }
-                    let hit = match e.desc.kind {
-                        ColliderKind::Aabb { .. } => {
-                            let (min, max) = self.aabbs[idx];
-                            crate::narrowphase::Narrowphase::ray_aabb(origin, dir, min, max)
-                        }
-                        ColliderKind::Circle { radius } => {
-                            crate::narrowphase::Narrowphase::ray_circle(
-                                origin,
-                                dir,
-                                e.desc.center,
-                                radius,
-                            )
-                        }
-                        ColliderKind::Point => crate::narrowphase::Narrowphase::ray_circle(
-                            origin,
-                            dir,
-                            e.desc.center,
-                            0.0,
-                        ),
-                    };
-                    if let Some(mut h) = hit {
-                        if h.toi < 0.0 || h.toi > max_t {
-                            continue;
-                        }
-                        h.hint = ResolutionHint::default();
-                        match &mut best {
-                            Some((_, bh)) if h.toi >= bh.toi => {}
-                            _ => best = Some((idx, h)),
+    fn update_tiles(&mut self, map: TileMapRef, changed_rect: (u32, u32, u32, u32), data: &[u8]) {
+        if let Some(m) = self.tilemaps.get_mut(map.0 as usize) {
+            let (x, y, w, h) = changed_rect;
+            assert_eq!((w * h) as usize, data.len());
+            let bounds = m.solid_bounds;
+            let mut cleared_edge = false;
+            for row in 0..h {
+                let dst_y = y + row;
+                if dst_y >= m.height {
+                    break;
+                }
+                let dst_off = (dst_y * m.width + x) as usize;
+                let src_off = (row * w) as usize;
+                let len = w.min(m.width.saturating_sub(x)) as usize;
+                if len == 0 {
+                    break;
+                }
+                let src = &data[src_off..src_off + len];
+                if let Some((lo, hi)) = bounds
+                    && !cleared_edge
+                {
+                    let edge_row = dst_y == lo.y || dst_y == hi.y;
+                    cleared_edge = src.iter().enumerate().any(|(i, &v)| {
+                        let cx = x + i as u32;
+                        (edge_row || cx == lo.x || cx == hi.x)
+                            && m.is_solid(dst_off + i)
+                            && !m.value_is_solid(v)
+                    });
+                }
+                match &mut m.solids {
+                    TileCells::Bytes(b) => b[dst_off..dst_off + len].copy_from_slice(src),
+                    bits => {
+                        for (i, &v) in src.iter().enumerate() {
+                            bits.set(dst_off + i, v);
                         }
                     }
                 }
+                if let Err(at) = m.dirty_rows.binary_search(&dst_y) {
+                    m.dirty_rows.insert(at, dst_y);
+                }
             }
-
-            // Step to next cell
-            if t_max_x < t_max_y {
-                cell.0 += step_x;
-                t_curr = t_max_x;
-                t_max_x += t_delta_x;
-            } else {
-                cell.1 += step_y;
-                t_curr = t_max_y;
-                t_max_y += t_delta_y;
+            m.rebuild_row_runs(y, y.saturating_add(h));
+            m.rebuild_col_runs(x, x.saturating_add(w));
+            if x < m.width && y < m.height && w > 0 && h > 0 {
+                let x1 = x.saturating_add(w - 1).min(m.width - 1);
+                let y1 = y.saturating_add(h - 1).min(m.height - 1);
+                m.update_solid_bounds((x, y), (x1, y1), cleared_edge);
             }
         }
+    }
+
+    fn dirty_rows(&self, map: TileMapRef) -> &[u32] {
+        self.tilemaps
+            .get(map.0 as usize)
+            .map_or(&[], |m| m.dirty_rows.as_slice())
+    }
+
+    fn clear_dirty(&mut self, map: TileMapRef) {
+        if let Some(m) = self.tilemaps.get_mut(map.0 as usize) {
+            m.dirty_rows.clear();
+        }
+    }
+
+    fn tile_value(&self, map: TileMapRef, cell: glam::UVec2) -> Option<u8> {
+        let m = self.tilemaps.get(map.0 as usize)?;
+        if cell.x >= m.width || cell.y >= m.height {
+            return None;
+        }
+        Some(m.solids.get((cell.y * m.width + cell.x) as usize))
+    }
+
+    fn tile_aabb(&self, t: TileRef) -> Option<(Vec2, Vec2)> {
+        let m = self.tilemaps.get(t.map.0 as usize)?;
+        if t.cell_xy.x >= m.width || t.cell_xy.y >= m.height {
+            return None;
+        }
+        let min = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32);
+        Some((min, min + Vec2::splat(m.cell.max(1e-5))))
+    }
+
+    fn tilemap_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)> {
+        let m = self.tilemaps.get(map.0 as usize)?;
+        Some((m.origin, m.tile_min(m.width as i32, m.height as i32)))
+    }
+
+    fn tilemap_solid_bounds(&self, map: TileMapRef) -> Option<(Vec2, Vec2)> {
+        self.tilemaps.get(map.0 as usize)?.solid_bounds_world()
+    }
+
+    fn tilemaps(&self) -> impl Iterator<Item = (TileMapRef, TileMapInfo)> {
+        self.tilemaps.iter().enumerate().map(|(i, m)| {
+            let info = TileMapInfo {
+                origin: m.origin,
+                cell: m.cell,
+                width: m.width,
+                height: m.height,
+                mask: m.mask,
+                user_key: m.user_key,
+                solid_mask: m.solid_mask,
+                vel: m.vel,
+            };
+            (TileMapRef(i as u32), info)
+        })
+    }
+
+    fn tile_solids(&self, map: TileMapRef) -> Option<&[u8]> {
+        match &self.tilemaps.get(map.0 as usize)?.solids {
+            TileCells::Bytes(b) => Some(b),
+            TileCells::Bits(_) => None,
+        }
+    }
+
+    fn tile_world_key(&self, t: TileRef) -> Option<u64> {
+        let m = self.tilemaps.get(t.map.0 as usize)?;
+        if t.cell_xy.x >= m.width || t.cell_xy.y >= m.height {
+            return None;
+        }
+        // Integer cell math: world corners lose the cell size to f32 spacing far out
+        let base = (m.origin / m.cell.max(1e-5)).round();
+        let x = base.x as i64 + t.cell_xy.x as i64;
+        let y = base.y as i64 + t.cell_xy.y as i64;
+        Some(((x as u32 as u64) << 32) | y as u32 as u64)
+    }
+
+    fn detach_tilemap(&mut self, map: TileMapRef) {
+        let idx = map.0 as usize;
+        if idx < self.tilemaps.len() {
+            self.tilemaps.remove(idx);
+        }
+    }
+
+    fn set_tilemap_velocity(&mut self, map: TileMapRef, vel: Vec2) {
+        if let Some(m) = self.tilemaps.get_mut(map.0 as usize) {
+            m.vel = vel;
+        }
+    }
+
+    fn set_tilemap_origin(&mut self, map: TileMapRef, origin: Vec2) {
+        if let Some(m) = self.tilemaps.get_mut(map.0 as usize) {
+            m.origin = origin;
+        }
+    }
 
-        best.map(|(idx, h)| (FrameId(idx as u32), h, self.entries[idx].desc.user_key))
+    fn raycast(
+        &self,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(FrameId, SweepHit, Option<ColKey>)> {
+        let origin = origin.into();
+        let dir = dir.into();
+        let (idx, h) = self.raycast_grid(self.dynamic_view(), origin, dir, mask, max_t)?;
+        Some((FrameId(idx as u32), h, self.entries[idx].desc.user_key))
+    }
+
+    fn raycast_one(
+        &self,
+        id: FrameId,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        max_t: f32,
+    ) -> Option<SweepHit> {
+        let dir = dir.into();
+        let idx = id.0 as usize;
+        if dir.length_squared() == 0.0 || idx >= self.entries.len() {
+            return None;
+        }
+        Self::ray_shape(self.dynamic_view(), idx, origin.into(), dir, max_t)
     }
 
     // --- Unified queries (colliders + tiles) --------------------------------
     fn raycast_all(
         &self,
-        origin: Vec2,
-        dir: Vec2,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
         mask: LayerMask,
         max_t: f32,
     ) -> Option<(BodyRef, SweepHit, Option<ColKey>)> {
+        let origin = origin.into();
+        let dir = dir.into();
         let mut best: Option<(BodyRef, SweepHit, Option<ColKey>)> = None;
         if let Some((id, hit, key)) = self.raycast(origin, dir, mask, max_t) {
             best = Some((BodyRef::Collider(id), hit, key));
         }
-        if let Some((tref, hit, key)) = self.raycast_tiles_internal(origin, dir, max_t, mask) {
+        if let Some((si, hit)) = self.raycast_grid(self.static_view(), origin, dir, mask, max_t) {
             match &best {
                 Some((_, bh, _)) if hit.toi >= bh.toi => {}
-                _ => best = Some((BodyRef::Tile(tref), hit, key)),
+                _ => {
+                    let key = self.statics[si].desc.user_key;
+                    best = Some((BodyRef::Static(StaticId(si as u32)), hit, key))
+                }
+            }
+        }
+        if let Some((tref, hit, key)) = self.raycast_tiles_internal(origin, dir, max_t, mask) {
+            let body = BodyRef::Tile(tref);
+            match &best {
+                Some((_, bh, _)) if !self.replaces_best(body, hit.toi, bh.toi) => {}
+                _ => best = Some((body, hit, key)),
             }
         }
         best
     }
 
-    fn query_point_all(&self, p: Vec2, mask: LayerMask) -> Vec<(BodyRef, Option<ColKey>)> {
+    fn raycast_desc(
+        &self,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, BodyDesc)> {
+        let (body, hit, _) = self.raycast_all(origin, dir, mask, max_t)?;
+        Some((body, hit, self.body_desc(body)?))
+    }
+
+    fn query_point_all(
+        &self,
+        p: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(BodyRef, Option<ColKey>)> {
+        let p = p.into();
         let mut out: Vec<(BodyRef, Option<ColKey>)> = Vec::new();
         for (id, key) in self.query_point(p, mask) {
             out.push((BodyRef::Collider(id), key));
         }
+        let eps = Vec2::splat(self.cfg.point_eps);
+        for (si, key) in self.grid_query(self.static_view(), p - eps, p + eps, mask, |e| {
+            Self::entry_contains_point(e, p, self.cfg.point_eps)
+        }) {
+            out.push((BodyRef::Static(StaticId(si as u32)), key));
+        }
         for (mi, m) in self.tilemaps.iter().enumerate() {
             if !self.allows_pair(mask, m.mask) {
                 continue;
@@ -597,7 +1148,7 @@ impl PhysicsWorldApi for PhysicsWorld {
             let cy = (local.y / cell).floor() as i32;
             if cx >= 0 && cy >= 0 && (cx as u32) < m.width && (cy as u32) < m.height {
                 let idx = cy as u32 * m.width + cx as u32;
-                if m.solids[idx as usize] != 0 {
+                if m.is_solid(idx as usize) {
                     out.push((
                         BodyRef::Tile(TileRef {
                             map: TileMapRef(mi as u32),
@@ -613,25 +1164,35 @@ impl PhysicsWorldApi for PhysicsWorld {
 
     fn query_aabb_all(
         &self,
-        center: Vec2,
-        half_extents: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
         mask: LayerMask,
     ) -> Vec<(BodyRef, Option<ColKey>)> {
+        let center = center.into();
+        let half_extents = half_extents.into();
         let mut out: Vec<(BodyRef, Option<ColKey>)> = Vec::new();
         for (id, key) in self.query_aabb(center, half_extents, mask) {
             out.push((BodyRef::Collider(id), key));
         }
+        for (si, key) in self.grid_query(
+            self.static_view(),
+            center - half_extents,
+            center + half_extents,
+            mask,
+            |e| Self::entry_overlaps_aabb(e, center, half_extents),
+        ) {
+            out.push((BodyRef::Static(StaticId(si as u32)), key));
+        }
         for (mi, m) in self.tilemaps.iter().enumerate() {
             if !self.allows_pair(mask, m.mask) {
                 continue;
             }
             let cell = m.cell.max(1e-5);
-            let min = center - half_extents - m.origin;
-            let max = center + half_extents - m.origin;
-            let ix0 = (min.x / cell).floor() as i32;
-            let iy0 = (min.y / cell).floor() as i32;
-            let ix1 = (max.x / cell).floor() as i32;
-            let iy1 = (max.y / cell).floor() as i32;
+            let Some((ix0, iy0, ix1, iy1)) =
+                m.cell_range(center - half_extents, center + half_extents)
+            else {
+                continue;
+            };
             for iy in iy0..=iy1 {
                 for ix in ix0..=ix1 {
                     if ix < 0 || iy < 0 {
@@ -642,10 +1203,10 @@ impl PhysicsWorldApi for PhysicsWorld {
                         continue;
                     }
                     let idx = (uy * m.width + ux) as usize;
-                    if m.solids[idx] == 0 {
+                    if !m.is_solid(idx) {
                         continue;
                     }
-                    let tile_min = m.origin + Vec2::new(ix as f32 * cell, iy as f32 * cell);
+                    let tile_min = m.tile_min(ix, iy);
                     let tile_c = tile_min + Vec2::splat(cell * 0.5);
                     let tile_h = Vec2::splat(cell * 0.5);
                     if crate::narrowphase::Narrowphase::overlap_aabb_aabb(
@@ -672,25 +1233,35 @@ impl PhysicsWorldApi for PhysicsWorld {
 
     fn query_circle_all(
         &self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
         mask: LayerMask,
     ) -> Vec<(BodyRef, Option<ColKey>)> {
+        let center = center.into();
         let mut out: Vec<(BodyRef, Option<ColKey>)> = Vec::new();
         for (id, key) in self.query_circle(center, radius, mask) {
             out.push((BodyRef::Collider(id), key));
         }
+        for (si, key) in self.grid_query_where(
+            self.static_view(),
+            center - Vec2::splat(radius),
+            center + Vec2::splat(radius),
+            mask,
+            Self::cell_in_circle(center, radius),
+            |e| Self::entry_overlaps_circle(e, center, radius),
+        ) {
+            out.push((BodyRef::Static(StaticId(si as u32)), key));
+        }
         for (mi, m) in self.tilemaps.iter().enumerate() {
             if !self.allows_pair(mask, m.mask) {
                 continue;
             }
             let cell = m.cell.max(1e-5);
-            let min = center - Vec2::splat(radius) - m.origin;
-            let max = center + Vec2::splat(radius) - m.origin;
-            let ix0 = (min.x / cell).floor() as i32;
-            let iy0 = (min.y / cell).floor() as i32;
-            let ix1 = (max.x / cell).floor() as i32;
-            let iy1 = (max.y / cell).floor() as i32;
+            let Some((ix0, iy0, ix1, iy1)) =
+                m.cell_range(center - Vec2::splat(radius), center + Vec2::splat(radius))
+            else {
+                continue;
+            };
             for iy in iy0..=iy1 {
                 for ix in ix0..=ix1 {
                     if ix < 0 || iy < 0 {
@@ -701,10 +1272,10 @@ impl PhysicsWorldApi for PhysicsWorld {
                         continue;
                     }
                     let idx = (uy * m.width + ux) as usize;
-                    if m.solids[idx] == 0 {
+                    if !m.is_solid(idx) {
                         continue;
                     }
-                    let tile_min = m.origin + Vec2::new(ix as f32 * cell, iy as f32 * cell);
+                    let tile_min = m.tile_min(ix, iy);
                     let tile_c = tile_min + Vec2::splat(cell * 0.5);
                     let tile_h = Vec2::splat(cell * 0.5);
                     if Self::overlap_circle_aabb_bool(center, radius, tile_c, tile_h) {
@@ -722,1298 +1293,7302 @@ impl PhysicsWorldApi for PhysicsWorld {
         out
     }
 
-    // --- Tile-only fast paths ----------------------------------------------
-    fn raycast_tiles(
+    fn query_obb_all(
         &self,
-        origin: Vec2,
-        dir: Vec2,
-        max_t: f32,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        angle: f32,
         mask: LayerMask,
-    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
-        self.raycast_tiles_internal(origin, dir, max_t, mask)
+    ) -> Vec<(BodyRef, Option<ColKey>)> {
+        use crate::narrowphase::Narrowphase;
+        let obb = Obb::new(center.into(), half_extents.into(), angle);
+        let mut out: Vec<(BodyRef, Option<ColKey>)> = Vec::new();
+        let Some((bc, bh)) = Self::polygon_bounds(&obb.corners) else {
+            return out;
+        };
+        let test = |e: &Entry| obb.overlaps_entry(e);
+        for (i, key) in self.grid_query(self.dynamic_view(), bc - bh, bc + bh, mask, test) {
+            out.push((BodyRef::Collider(FrameId(i as u32)), key));
+        }
+        for (si, key) in self.grid_query(self.static_view(), bc - bh, bc + bh, mask, test) {
+            out.push((BodyRef::Static(StaticId(si as u32)), key));
+        }
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
+            }
+            let h = Vec2::splat(m.cell.max(1e-5) * 0.5);
+            self.visit_tile_overlaps(mi, m, bc, bh, |t| {
+                let tile_c = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32) + h;
+                if Narrowphase::overlap_polygon_aabb(&obb.corners, tile_c, h).is_some() {
+                    out.push((BodyRef::Tile(t), m.user_key));
+                }
+                true
+            });
+        }
+        out
     }
 
-    fn sweep_aabb_tiles(
+    fn query_point_grouped(&self, p: impl Into<Vec2>, mask: LayerMask) -> BodiesByKind {
+        self.query_point_all(p, mask).into_iter().collect()
+    }
+
+    fn query_aabb_grouped(
         &self,
-        center: Vec2,
-        half_extents: Vec2,
-        vel: Vec2,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
         mask: LayerMask,
-    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
-        self.sweep_shape_tiles(center, half_extents, vel, mask)
+    ) -> BodiesByKind {
+        self.query_aabb_all(center, half_extents, mask)
+            .into_iter()
+            .collect()
     }
 
-    fn sweep_circle_tiles(
+    fn query_circle_grouped(
         &self,
-        center: Vec2,
+        center: impl Into<Vec2>,
         radius: f32,
-        vel: Vec2,
         mask: LayerMask,
-    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
-        self.sweep_shape_tiles(center, Vec2::splat(radius), vel, mask)
+    ) -> BodiesByKind {
+        self.query_circle_all(center, radius, mask)
+            .into_iter()
+            .collect()
     }
 
-    fn query_point(&self, p: Vec2, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)> {
-        let cs = self.cfg.cell_size.max(1e-5);
-        let cell = self.world_to_cell(p, cs);
+    fn query_aabb_solid_cells(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(TileMapRef, glam::UVec2)> {
+        let center = center.into();
+        let half_extents = half_extents.into();
         let mut out = Vec::new();
-        if let Some(list) = self.grid.get(&cell) {
-            for &idx in list {
-                let e = &self.entries[idx];
-                if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
-                    continue;
-                }
-                let hit = match e.desc.kind {
-                    ColliderKind::Aabb { .. } => {
-                        crate::narrowphase::Narrowphase::overlap_point_aabb(
-                            p,
-                            e.desc.center,
-                            self.half_extents_of(idx),
-                        )
-                    }
-                    ColliderKind::Circle { radius } => {
-                        crate::narrowphase::Narrowphase::overlap_point_circle(
-                            p,
-                            e.desc.center,
-                            radius,
-                        )
-                    }
-                    ColliderKind::Point => p == e.desc.center,
-                };
-                if hit {
-                    out.push((FrameId(idx as u32), e.desc.user_key));
-                }
+        let mut seen: FxHashSet<(u32, u32)> = FxHashSet::default();
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
             }
+            self.visit_tile_overlaps(mi, m, center, half_extents, |t| {
+                let min = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32);
+                if seen.insert((min.x.to_bits(), min.y.to_bits())) {
+                    out.push((t.map, t.cell_xy));
+                }
+                true
+            });
         }
         out
     }
 
-    fn query_aabb(
+    fn solid_tiles_in_circle(
         &self,
-        center: Vec2,
-        half_extents: Vec2,
-        mask: LayerMask,
-    ) -> Vec<(FrameId, Option<ColKey>)> {
-        let cs = self.cfg.cell_size.max(1e-5);
-        let min = center - half_extents;
-        let max = center + half_extents;
-        let (ix0, iy0) = self.world_to_cell(min, cs);
-        let (ix1, iy1) = self.world_to_cell(max, cs);
+        center: impl Into<Vec2>,
+        radius: f32,
+        map: TileMapRef,
+    ) -> Vec<glam::UVec2> {
+        let center = center.into();
         let mut out = Vec::new();
-        let mut seen = HashSet::new();
-        for iy in iy0..=iy1 {
-            for ix in ix0..=ix1 {
-                if let Some(list) = self.grid.get(&(ix, iy)) {
-                    for &idx in list {
-                        if !seen.insert(idx) {
-                            continue;
-                        }
-                        let e = &self.entries[idx];
-                        if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
-                            continue;
-                        }
-                        let ov = match e.desc.kind {
-                            ColliderKind::Aabb { .. } => {
-                                crate::narrowphase::Narrowphase::overlap_aabb_aabb(
-                                    e.desc.center,
-                                    self.half_extents_of(idx),
-                                    center,
-                                    half_extents,
-                                )
-                                .is_some()
-                            }
-                            ColliderKind::Circle { radius } => Self::overlap_circle_aabb_bool(
-                                e.desc.center,
-                                radius,
-                                center,
-                                half_extents,
-                            ),
-                            ColliderKind::Point => {
-                                crate::narrowphase::Narrowphase::overlap_point_aabb(
-                                    e.desc.center,
-                                    center,
-                                    half_extents,
-                                )
-                            }
-                        };
-                        if ov {
-                            out.push((FrameId(idx as u32), e.desc.user_key));
-                        }
-                    }
-                }
+        let Some(m) = self.tilemaps.get(map.0 as usize) else {
+            return out;
+        };
+        let h = Vec2::splat(m.cell.max(1e-5) * 0.5);
+        self.visit_tile_overlaps(map.0 as usize, m, center, Vec2::splat(radius), |t| {
+            let tile_c = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32) + h;
+            if Self::overlap_circle_aabb_bool(center, radius, tile_c, h) {
+                out.push(t.cell_xy);
+            }
+            true
+        });
+        out
+    }
+
+    fn query_polygon_tiles(&self, points: &[Vec2], mask: LayerMask) -> Vec<TileRef> {
+        use crate::narrowphase::Narrowphase;
+        let mut out = Vec::new();
+        let Some((center, he)) = Self::polygon_bounds(points) else {
+            return out;
+        };
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
             }
+            let h = Vec2::splat(m.cell.max(1e-5) * 0.5);
+            self.visit_tile_overlaps(mi, m, center, he, |t| {
+                let tile_c = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32) + h;
+                if Narrowphase::overlap_polygon_aabb(points, tile_c, h).is_some() {
+                    out.push(t);
+                }
+                true
+            });
         }
         out
     }
 
-    fn query_circle(
+    fn cast(
         &self,
-        center: Vec2,
-        radius: f32,
+        shape: ColliderKind,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
         mask: LayerMask,
-    ) -> Vec<(FrameId, Option<ColKey>)> {
-        let cs = self.cfg.cell_size.max(1e-5);
-        let min = center - Vec2::splat(radius);
-        let max = center + Vec2::splat(radius);
-        let (ix0, iy0) = self.world_to_cell(min, cs);
-        let (ix1, iy1) = self.world_to_cell(max, cs);
-        let mut out = Vec::new();
-        let mut seen = HashSet::new();
-        for iy in iy0..=iy1 {
-            for ix in ix0..=ix1 {
-                if let Some(list) = self.grid.get(&(ix, iy)) {
-                    for &idx in list {
-                        if !seen.insert(idx) {
-                            continue;
-                        }
-                        let e = &self.entries[idx];
-                        if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
-                            continue;
-                        }
-                        let ov = match e.desc.kind {
-                            ColliderKind::Aabb { .. } => Self::overlap_circle_aabb_bool(
-                                center,
-                                radius,
-                                e.desc.center,
-                                self.half_extents_of(idx),
-                            ),
-                            ColliderKind::Circle { radius: r1 } => {
-                                crate::narrowphase::Narrowphase::overlap_circle_circle(
-                                    center,
-                                    radius,
-                                    e.desc.center,
-                                    r1,
-                                )
-                                .is_some()
-                            }
-                            ColliderKind::Point => {
-                                crate::narrowphase::Narrowphase::overlap_point_circle(
-                                    e.desc.center,
-                                    center,
-                                    radius,
-                                )
-                            }
-                        };
-                        if ov {
-                            out.push((FrameId(idx as u32), e.desc.user_key));
-                        }
-                    }
-                }
+        max_t: f32,
+    ) -> Option<(BodyRef, SweepHit, Option<ColKey>)> {
+        let origin = origin.into();
+        let dir = dir.into();
+        let he = match shape.normalized() {
+            ColliderKind::Point => return self.raycast_all(origin, dir, mask, max_t),
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+        };
+        if dir.length_squared() == 0.0 || !max_t.is_finite() || max_t <= 0.0 {
+            return None;
+        }
+        let d = dir * max_t;
+        let probe = Entry {
+            desc: ColliderDesc {
+                kind: shape,
+                center: origin,
+                mask,
+                user_key: None,
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            },
+            motion: Motion::default(),
+        };
+        let mut best: Option<(BodyRef, SweepHit, Option<ColKey>)> = None;
+        let mut consider = |body: BodyRef, mut hit: SweepHit, key: Option<ColKey>| {
+            hit.scale_toi(max_t);
+            match &best {
+                Some((_, bh, _)) if !self.replaces_best(body, hit.toi, bh.toi) => {}
+                _ => best = Some((body, hit, key)),
             }
+        };
+        let min = origin.min(origin + d) - he;
+        let max = origin.max(origin + d) + he;
+        let mut scan = |view: GridView<'_>, body: &dyn Fn(usize) -> BodyRef| {
+            self.view_candidates(view, min, max, |idx| {
+                let e = &view.entries[idx];
+                if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
+                    return;
+                }
+                if let Some(hit) = self.sweep_moving(&probe, d, e, Vec2::ZERO) {
+                    consider(body(idx), hit, e.desc.user_key);
+                }
+            });
+        };
+        scan(self.dynamic_view(), &|i| {
+            BodyRef::Collider(FrameId(i as u32))
+        });
+        scan(self.static_view(), &|i| BodyRef::Static(StaticId(i as u32)));
+        if let Some((tref, hit, key)) = self.sweep_kind_tiles(shape, origin, d, mask, 0.0) {
+            consider(BodyRef::Tile(tref), hit, key);
         }
-        out
+        best
     }
 
-    fn overlap_pair(&self, a: FrameId, b: FrameId) -> Option<Overlap> {
-        self.overlap_pair_idx(a.0 as usize, b.0 as usize)
+    fn path_clear_aabb(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> bool {
+        self.path_clear(
+            ColliderKind::Aabb { half_extents },
+            center,
+            vel * self.cfg.dt,
+            mask,
+        )
     }
 
-    fn sweep_pair(&self, a: FrameId, b: FrameId) -> Option<SweepHit> {
-        self.sweep_pair_idx(a.0 as usize, b.0 as usize)
+    fn path_clear_circle(&self, center: Vec2, radius: f32, vel: Vec2, mask: LayerMask) -> bool {
+        self.path_clear(
+            ColliderKind::Circle { radius },
+            center,
+            vel * self.cfg.dt,
+            mask,
+        )
     }
 
-    fn overlap_by_key(&self, a: ColKey, b: ColKey) -> Option<Overlap> {
-        let ia = self.key_to_id.get(&a)?.0 as usize;
-        let ib = self.key_to_id.get(&b)?.0 as usize;
-        self.overlap_pair_idx(ia, ib)
+    fn path_clear_point(&self, p: Vec2, vel: Vec2, mask: LayerMask) -> bool {
+        self.path_clear(ColliderKind::Point, p, vel * self.cfg.dt, mask)
     }
 
-    fn sweep_by_key(&self, a: ColKey, b: ColKey) -> Option<SweepHit> {
-        let ia = self.key_to_id.get(&a)?.0 as usize;
-        let ib = self.key_to_id.get(&b)?.0 as usize;
-        self.sweep_pair_idx(ia, ib)
+    // --- Tile-only fast paths ----------------------------------------------
+    fn raycast_tiles(
+        &self,
+        origin: impl Into<Vec2>,
+        dir: impl Into<Vec2>,
+        max_t: f32,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let origin = origin.into();
+        let dir = dir.into();
+        self.raycast_tiles_internal(origin, dir, max_t, mask)
     }
-}
 
-impl PhysicsWorld {
-    fn compute_entry_aabb(&self, e: &Entry) -> (Vec2, Vec2) {
-        // Base extents by kind
-        let half = match e.desc.kind {
-            ColliderKind::Aabb { half_extents } => half_extents,
-            ColliderKind::Circle { radius } => Vec2::splat(radius),
-            ColliderKind::Point => Vec2::ZERO,
-        };
+    fn sweep_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let (tref, mut hit, key) =
+            self.sweep_shape_tiles(center, half_extents, vel * self.cfg.dt, mask, self.cfg.dt)?;
+        hit.impact_speed = (vel - self.tile_map_vel(tref)).dot(-hit.normal);
+        hit.scale_toi(self.toi_scale());
+        Some((tref, hit, key))
+    }
 
-        if self.cfg.tighten_swept_aabb {
-            let p0 = e.desc.center;
-            let p1 = e.desc.center + e.motion.vel * self.cfg.dt;
-            let min_c = p0.min(p1) - half;
-            let max_c = p0.max(p1) + half;
-            (min_c, max_c)
-        } else {
-            let min_c = e.desc.center - half;
-            let max_c = e.desc.center + half;
-            (min_c, max_c)
-        }
+    fn sweep_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let kind = ColliderKind::Circle { radius };
+        let (tref, mut hit, key) =
+            self.sweep_kind_tiles(kind, center, vel * self.cfg.dt, mask, self.cfg.dt)?;
+        hit.impact_speed = (vel - self.tile_map_vel(tref)).dot(-hit.normal);
+        hit.scale_toi(self.toi_scale());
+        Some((tref, hit, key))
     }
 
-    fn insert_into_grid(&mut self, idx: usize, min: Vec2, max: Vec2) {
-        let cs = self.cfg.cell_size.max(1e-5);
-        let ix0 = (min.x / cs).floor() as i32;
-        let iy0 = (min.y / cs).floor() as i32;
-        let ix1 = (max.x / cs).floor() as i32;
-        let iy1 = (max.y / cs).floor() as i32;
-        for iy in iy0..=iy1 {
-            for ix in ix0..=ix1 {
-                self.grid.entry((ix, iy)).or_default().push(idx);
-            }
-        }
+    fn sweep_capsule_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        half_height: f32,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        use crate::narrowphase::Narrowphase;
+        let core = Vec2::new(0.0, half_height);
+        let he = Vec2::new(radius, half_height + radius);
+        let (tref, mut hit, key) = self.sweep_tiles_with(
+            center,
+            he,
+            vel * self.cfg.dt,
+            mask,
+            self.cfg.dt,
+            |p, tile_min, cell| {
+                // Distance between the core segment and the tile box, per axis
+                let tile_max = tile_min + Vec2::splat(cell);
+                let dx = (tile_min.x - p.x).max(p.x - tile_max.x).max(0.0);
+                let dy = (tile_min.y - (p.y + half_height))
+                    .max((p.y - half_height) - tile_max.y)
+                    .max(0.0);
+                dx * dx + dy * dy <= radius * radius
+            },
+            |p, tile_min, cell| {
+                // Side contact when the tile spans the core segment's height, else the nearest cap
+                let mid_y = (tile_min.y + cell * 0.5).clamp(p.y - half_height, p.y + half_height);
+                if tile_min.y <= p.y + half_height && tile_min.y + cell >= p.y - half_height {
+                    Narrowphase::aabb_tile_pushout(
+                        Vec2::new(p.x, mid_y),
+                        Vec2::splat(radius),
+                        tile_min,
+                        cell,
+                    )
+                } else {
+                    let cap = if tile_min.y > p.y { p + core } else { p - core };
+                    Narrowphase::circle_tile_pushout(cap, radius, tile_min, cell)
+                }
+            },
+        )?;
+        hit.impact_speed = (vel - self.tile_map_vel(tref)).dot(-hit.normal);
+        hit.scale_toi(self.toi_scale());
+        Some((tref, hit, key))
     }
 
-    fn world_to_cell(&self, p: Vec2, cs: f32) -> (i32, i32) {
-        ((p.x / cs).floor() as i32, (p.y / cs).floor() as i32)
+    fn sweep_polygon_tiles(
+        &self,
+        points: &[Vec2],
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        use crate::narrowphase::Narrowphase;
+        let (center, he) = Self::polygon_bounds(points)?;
+        // Test the polygon at offset `p - center` by shifting the tile the other way
+        let overlap = |p: Vec2, tile_min: Vec2, cell: f32| {
+            let h = Vec2::splat(cell * 0.5);
+            let o = p - center;
+            Narrowphase::overlap_polygon_aabb(points, tile_min + h - o, h).map(|ov| (ov, o))
+        };
+        let (tref, mut hit, key) = self.sweep_tiles_with(
+            center,
+            he,
+            vel * self.cfg.dt,
+            mask,
+            self.cfg.dt,
+            |p, tile_min, cell| overlap(p, tile_min, cell).is_some(),
+            |p, tile_min, cell| match overlap(p, tile_min, cell) {
+                Some((ov, o)) => (ov.normal, ov.depth, ov.contact + o),
+                None => Narrowphase::aabb_tile_pushout(p, he, tile_min, cell),
+            },
+        )?;
+        hit.impact_speed = (vel - self.tile_map_vel(tref)).dot(-hit.normal);
+        hit.scale_toi(self.toi_scale());
+        Some((tref, hit, key))
     }
 
-    fn half_extents_of(&self, idx: usize) -> Vec2 {
-        match self.entries[idx].desc.kind {
-            ColliderKind::Aabb { half_extents } => half_extents,
-            ColliderKind::Circle { radius } => Vec2::splat(radius),
-            ColliderKind::Point => Vec2::ZERO,
-        }
+    fn overlap_aabb_tiles(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)> {
+        use crate::narrowphase::Narrowphase;
+        self.tile_overlaps_with(center, half_extents, mask, |tile_min, cell| {
+            Narrowphase::aabb_tile_pushout(center, half_extents, tile_min, cell)
+        })
     }
 
-    fn overlap_circle_aabb_bool(circle_c: Vec2, r: f32, box_c: Vec2, box_h: Vec2) -> bool {
-        let min = box_c - box_h;
-        let max = box_c + box_h;
-        let clamp = |v: f32, lo: f32, hi: f32| v.max(lo).min(hi);
-        let closest = Vec2::new(
-            clamp(circle_c.x, min.x, max.x),
-            clamp(circle_c.y, min.y, max.y),
-        );
-        (closest - circle_c).length_squared() <= r * r
+    fn overlap_circle_tiles(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(TileRef, Overlap)> {
+        use crate::narrowphase::Narrowphase;
+        self.tile_overlaps_with(center, Vec2::splat(radius), mask, |tile_min, cell| {
+            Narrowphase::circle_tile_pushout(center, radius, tile_min, cell)
+        })
     }
 
-    fn overlap_pair_idx(&self, ai: usize, bi: usize) -> Option<Overlap> {
-        use crate::api::NarrowphaseApi;
+    fn tile_surface_normal(&self, p: Vec2, mask: LayerMask) -> Option<(TileRef, Vec2)> {
         use crate::narrowphase::Narrowphase;
-        let a = &self.entries[ai];
-        let b = &self.entries[bi];
-        match (a.desc.kind, b.desc.kind) {
-            (ColliderKind::Aabb { .. }, ColliderKind::Aabb { .. }) => {
-                Narrowphase::overlap_aabb_aabb(
-                    a.desc.center,
-                    self.half_extents_of(ai),
-                    b.desc.center,
-                    self.half_extents_of(bi),
-                )
-            }
-            (ColliderKind::Circle { radius: r0 }, ColliderKind::Circle { radius: r1 }) => {
-                Narrowphase::overlap_circle_circle(a.desc.center, r0, b.desc.center, r1)
-            }
-            (ColliderKind::Point, ColliderKind::Aabb { .. }) => {
-                if Narrowphase::overlap_point_aabb(
-                    a.desc.center,
-                    b.desc.center,
-                    self.half_extents_of(bi),
-                ) {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: a.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
-            }
-            (ColliderKind::Aabb { .. }, ColliderKind::Point) => {
-                if Narrowphase::overlap_point_aabb(
-                    b.desc.center,
-                    a.desc.center,
-                    self.half_extents_of(ai),
-                ) {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: b.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
-            }
-            (ColliderKind::Point, ColliderKind::Circle { radius: r }) => {
-                if Narrowphase::overlap_point_circle(a.desc.center, b.desc.center, r) {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: a.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
-            }
-            (ColliderKind::Circle { radius: r }, ColliderKind::Point) => {
-                if Narrowphase::overlap_point_circle(b.desc.center, a.desc.center, r) {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: b.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
-            }
-            (ColliderKind::Circle { radius }, ColliderKind::Aabb { .. }) => {
-                if Self::overlap_circle_aabb_bool(
-                    a.desc.center,
-                    radius,
-                    b.desc.center,
-                    self.half_extents_of(bi),
-                ) {
-                    // Approximate normal/contact
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: a.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
-            }
-            (ColliderKind::Aabb { .. }, ColliderKind::Circle { radius }) => {
-                if Self::overlap_circle_aabb_bool(
-                    b.desc.center,
-                    radius,
-                    a.desc.center,
-                    self.half_extents_of(ai),
-                ) {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: b.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
+        let mut best: Option<(TileRef, Vec2, f32)> = None;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
             }
-            (ColliderKind::Point, ColliderKind::Point) => {
-                if a.desc.center == b.desc.center {
-                    Some(Overlap {
-                        normal: Vec2::ZERO,
-                        depth: 0.0,
-                        contact: a.desc.center,
-                        hint: ResolutionHint::default(),
-                    })
-                } else {
-                    None
-                }
+            let Some(((ix, iy), d2)) = m.nearest_solid(p) else {
+                continue;
+            };
+            if best.is_some_and(|b| b.2 <= d2) {
+                continue;
             }
+            let cell = m.cell.max(1e-5);
+            let (normal, _, _) =
+                Narrowphase::aabb_tile_pushout(p, Vec2::ZERO, m.tile_min(ix, iy), cell);
+            let tref = TileRef {
+                map: TileMapRef(mi as u32),
+                cell_xy: glam::UVec2::new(ix as u32, iy as u32),
+            };
+            best = Some((tref, normal, d2));
         }
+        best.map(|(tref, normal, _)| (tref, normal))
     }
 
-    fn sweep_pair_idx(&self, ai: usize, bi: usize) -> Option<SweepHit> {
-        use crate::api::NarrowphaseApi;
-        use crate::narrowphase::Narrowphase;
-        let a = &self.entries[ai];
-        let b = &self.entries[bi];
-        match (a.desc.kind, b.desc.kind) {
-            (ColliderKind::Aabb { .. }, ColliderKind::Aabb { .. }) => Narrowphase::sweep_aabb_aabb(
-                a.desc.center,
-                self.half_extents_of(ai),
-                a.motion.vel * self.cfg.dt,
-                b.desc.center,
-                self.half_extents_of(bi),
-                b.motion.vel * self.cfg.dt,
-            ),
-            (ColliderKind::Circle { radius: r0 }, ColliderKind::Circle { radius: r1 }) => {
-                Narrowphase::sweep_circle_circle(
-                    a.desc.center,
-                    r0,
-                    a.motion.vel * self.cfg.dt,
-                    b.desc.center,
-                    r1,
-                    b.motion.vel * self.cfg.dt,
-                )
-            }
-            (ColliderKind::Circle { radius: r }, ColliderKind::Aabb { .. }) => {
-                Narrowphase::sweep_circle_aabb(
-                    a.desc.center,
-                    r,
-                    a.motion.vel * self.cfg.dt,
-                    b.desc.center,
-                    self.half_extents_of(bi),
-                    b.motion.vel * self.cfg.dt,
-                )
-            }
-            (ColliderKind::Aabb { .. }, ColliderKind::Circle { radius: r }) => {
-                // Swap and invert normal later
-                let hit = Narrowphase::sweep_circle_aabb(
-                    b.desc.center,
-                    r,
-                    b.motion.vel * self.cfg.dt,
-                    a.desc.center,
-                    self.half_extents_of(ai),
-                    a.motion.vel * self.cfg.dt,
-                )?;
-                Some(SweepHit {
-                    toi: hit.toi,
-                    normal: -hit.normal,
-                    contact: hit.contact,
-                    hint: ResolutionHint::default(),
-                })
-            }
-            (ColliderKind::Point, ColliderKind::Aabb { .. }) => Narrowphase::sweep_circle_aabb(
-                a.desc.center,
-                0.0,
-                a.motion.vel * self.cfg.dt,
-                b.desc.center,
-                self.half_extents_of(bi),
-                b.motion.vel * self.cfg.dt,
-            ),
-            (ColliderKind::Aabb { .. }, ColliderKind::Point) => {
-                let hit = Narrowphase::sweep_circle_aabb(
-                    b.desc.center,
-                    0.0,
-                    b.motion.vel * self.cfg.dt,
-                    a.desc.center,
-                    self.half_extents_of(ai),
-                    a.motion.vel * self.cfg.dt,
-                )?;
-                Some(SweepHit {
-                    toi: hit.toi,
-                    normal: -hit.normal,
-                    contact: hit.contact,
-                    hint: ResolutionHint::default(),
-                })
-            }
-            (ColliderKind::Point, ColliderKind::Circle { radius: r }) => {
-                Narrowphase::sweep_circle_circle(
-                    a.desc.center,
-                    0.0,
-                    a.motion.vel * self.cfg.dt,
-                    b.desc.center,
-                    r,
-                    b.motion.vel * self.cfg.dt,
-                )
-            }
-            (ColliderKind::Circle { radius: r }, ColliderKind::Point) => {
-                let hit = Narrowphase::sweep_circle_circle(
-                    b.desc.center,
-                    0.0,
-                    b.motion.vel * self.cfg.dt,
-                    a.desc.center,
-                    r,
-                    a.motion.vel * self.cfg.dt,
-                )?;
-                Some(SweepHit {
-                    toi: hit.toi,
-                    normal: -hit.normal,
-                    contact: hit.contact,
-                    hint: ResolutionHint::default(),
-                })
+    fn sweep_aabb_tiles_contacts(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, TileContacts)> {
+        let mut contacts: TileContacts = [None; 2];
+        let toi = self.sweep_tile_contacts(center, half_extents, vel, mask, false, |t, n| {
+            // One contact per distinct normal
+            if contacts.iter().flatten().any(|(_, cn)| cn.dot(n) > 0.999) {
+                return true;
             }
-            (ColliderKind::Point, ColliderKind::Point) => None,
-        }
+            let Some(slot) = contacts.iter_mut().find(|c| c.is_none()) else {
+                return false;
+            };
+            *slot = Some((t, n));
+            contacts[1].is_none()
+        })?;
+        Some((toi * self.toi_scale(), contacts))
     }
 
-    /// Return debug/perf stats for the current built frame.
-    pub fn debug_stats(&self) -> WorldStats {
-        use std::collections::HashSet;
-        let entries = self.entries.len();
-        let cells = self.grid.len();
-        let mut candidate_pairs: usize = 0;
-        let mut seen: HashSet<(usize, usize)> = HashSet::new();
-        for v in self.grid.values() {
-            let n = v.len();
-            if n >= 2 {
-                candidate_pairs += n * (n - 1) / 2;
+    fn sweep_aabb_tiles_all(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+    ) -> Option<(f32, Vec<(TileRef, Vec2)>)> {
+        let mut contacts = Vec::new();
+        let toi = self.sweep_tile_contacts(center, half_extents, vel, mask, true, |t, n| {
+            contacts.push((t, n));
+            true
+        })?;
+        Some((toi * self.toi_scale(), contacts))
+    }
+
+    fn query_point(&self, p: impl Into<Vec2>, mask: LayerMask) -> Vec<(FrameId, Option<ColKey>)> {
+        let p = p.into();
+        let eps = Vec2::splat(self.cfg.point_eps);
+        self.grid_query(self.dynamic_view(), p - eps, p + eps, mask, |e| {
+            Self::entry_contains_point(e, p, self.cfg.point_eps)
+        })
+        .into_iter()
+        .map(|(idx, key)| (FrameId(idx as u32), key))
+        .collect()
+    }
+
+    fn query_aabb(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        let mut out = Vec::new();
+        let mut seen = FxHashSet::default();
+        self.query_aabb_in(
+            center.into(),
+            half_extents.into(),
+            mask,
+            &mut seen,
+            &mut out,
+        );
+        out
+    }
+
+    fn query_aabb_into(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+        seen: &mut HashSet<u32>,
+        out: &mut Vec<(FrameId, Option<ColKey>)>,
+    ) {
+        seen.clear();
+        out.clear();
+        self.query_aabb_in(center.into(), half_extents.into(), mask, seen, out);
+    }
+
+    fn query_aabb_filtered(
+        &self,
+        center: impl Into<Vec2>,
+        half_extents: impl Into<Vec2>,
+        mask: LayerMask,
+        include_unkeyed: bool,
+        keep: impl Fn(ColKey) -> bool,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        let center = center.into();
+        let half_extents = half_extents.into();
+        self.grid_query(
+            self.dynamic_view(),
+            center - half_extents,
+            center + half_extents,
+            mask,
+            |e| {
+                e.desc.user_key.map_or(include_unkeyed, &keep)
+                    && Self::entry_overlaps_aabb(e, center, half_extents)
+            },
+        )
+        .into_iter()
+        .map(|(idx, key)| (FrameId(idx as u32), key))
+        .collect()
+    }
+
+    fn query_circle(
+        &self,
+        center: impl Into<Vec2>,
+        radius: f32,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, Option<ColKey>)> {
+        let center = center.into();
+        self.grid_query_where(
+            self.dynamic_view(),
+            center - Vec2::splat(radius),
+            center + Vec2::splat(radius),
+            mask,
+            Self::cell_in_circle(center, radius),
+            |e| Self::entry_overlaps_circle(e, center, radius),
+        )
+        .into_iter()
+        .map(|(idx, key)| (FrameId(idx as u32), key))
+        .collect()
+    }
+
+    fn overlap_pair(&self, a: FrameId, b: FrameId) -> Option<Overlap> {
+        self.overlap_pair_idx(a.0 as usize, b.0 as usize)
+    }
+
+    fn manifold_pair(&self, a: FrameId, b: FrameId) -> Option<Manifold> {
+        self.overlap_pair(a, b).map(Manifold::from)
+    }
+
+    fn sweep_pair(&self, a: FrameId, b: FrameId) -> Option<SweepHit> {
+        self.sweep_pair_scaled(a.0 as usize, b.0 as usize)
+    }
+
+    fn query_aabb_swept_region(
+        &self,
+        region_center: impl Into<Vec2>,
+        region_half: impl Into<Vec2>,
+        mask: LayerMask,
+    ) -> Vec<(FrameId, f32, Option<ColKey>)> {
+        let center = region_center.into();
+        let half = region_half.into().abs();
+        let region = Entry {
+            desc: ColliderDesc {
+                kind: ColliderKind::Aabb { half_extents: half },
+                center,
+                mask,
+                user_key: None,
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            },
+            motion: Motion::default(),
+        };
+        let view = self.dynamic_view();
+        let scale = self.toi_scale();
+        let mut out = Vec::new();
+        self.view_candidates(view, center - half, center + half, |idx| {
+            let e = &view.entries[idx];
+            if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
+                return;
             }
-            for i in 0..n {
-                for j in (i + 1)..n {
-                    let a = v[i];
-                    let b = v[j];
-                    let key = if a < b { (a, b) } else { (b, a) };
-                    seen.insert(key);
-                }
+            let d = if self.sweeps_enabled() {
+                self.displacement(&e.motion)
+            } else {
+                Vec2::ZERO
+            };
+            if let Some((enter, _)) = self.overlap_interval(e, &region, d) {
+                out.push((FrameId(idx as u32), enter * scale, e.desc.user_key));
             }
-        }
-        WorldStats {
-            entries,
-            cells,
-            candidate_pairs,
-            unique_pairs: seen.len(),
-        }
+        });
+        out.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.0.cmp(&b.0.0)));
+        out
     }
 
-    /// Return timing breakdown for the last `end_frame`/`generate_events` runs.
-    pub fn timing(&self) -> Option<WorldTiming> {
-        self.last_timing
+    fn sweep_pair_interval(&self, a: FrameId, b: FrameId) -> Option<(f32, f32)> {
+        let (ea, eb) = (&self.entries[a.0 as usize], &self.entries[b.0 as usize]);
+        let rel = self.displacement(&ea.motion) - self.displacement(&eb.motion);
+        let (enter, exit) = self.overlap_interval(ea, eb, rel)?;
+        let scale = self.toi_scale();
+        Some((enter * scale, exit * scale))
     }
 
-    fn allows_pair(&self, a: LayerMask, b: LayerMask) -> bool {
-        if self.cfg.require_mutual_consent {
-            a.allows(b) && b.allows(a)
-        } else {
-            a.allows(b) || b.allows(a)
-        }
+    fn touching_pair(&self, a: FrameId, b: FrameId, eps: f32) -> bool {
+        self.overlap_pair(a, b).is_some_and(|ov| ov.depth <= eps)
     }
 
-    fn tile_at(m: &TileMap, ix: i32, iy: i32) -> Option<usize> {
-        if ix < 0 || iy < 0 {
-            return None;
+    fn cast_pair(&self, a: FrameId, b: FrameId) -> CastResult {
+        let (ai, bi) = (a.0 as usize, b.0 as usize);
+        if let Some(ov) = self.overlap_pair_idx(ai, bi) {
+            return CastResult::Overlapping(ov);
         }
-        let ux = ix as u32;
-        let uy = iy as u32;
-        if ux >= m.width || uy >= m.height {
-            return None;
+        match self.sweep_pair_scaled(ai, bi) {
+            Some(hit) => CastResult::WillHit(hit),
+            None => CastResult::Separated,
         }
-        Some((uy * m.width + ux) as usize)
     }
 
-    fn any_tile_overlap_at(&self, mi: usize, m: &TileMap, center: Vec2, he: Vec2) -> Option<TileRef> {
-        let cell = m.cell.max(1e-5);
-        let min = center - he - m.origin;
-        let max = center + he - m.origin;
-        let ix0 = (min.x / cell).floor() as i32;
-        let iy0 = (min.y / cell).floor() as i32;
-        let ix1 = (max.x / cell).floor() as i32;
-        let iy1 = (max.y / cell).floor() as i32;
-        for iy in iy0..=iy1 {
-            for ix in ix0..=ix1 {
-                if let Some(idx) = Self::tile_at(m, ix, iy)
-                    && m.solids[idx] != 0
-                {
-                    let tile_min = m.origin + Vec2::new(ix as f32 * cell, iy as f32 * cell);
-                    // quick overlap check: AABB vs tile AABB
-                    let tile_c = tile_min + Vec2::splat(cell * 0.5);
-                    let tile_h = Vec2::splat(cell * 0.5);
-                    if crate::narrowphase::Narrowphase::overlap_aabb_aabb(
-                        center, he, tile_c, tile_h,
-                    )
-                    .is_some()
-                    {
-                        return Some(TileRef {
-                            map: TileMapRef(mi as u32),
-                            cell_xy: glam::UVec2::new(ix as u32, iy as u32),
-                        });
-                    }
-                }
+    fn deepest_overlap(&self, id: FrameId, mask: LayerMask) -> Option<(BodyRef, Overlap)> {
+        use crate::narrowphase::Narrowphase;
+        let idx = id.0 as usize;
+        let e = self.entries.get(idx)?;
+        let he = Self::entry_half_extents(e);
+        let (min, max) = (e.desc.center - he, e.desc.center + he);
+        let mut best: Option<(BodyRef, Overlap)> = None;
+        let mut consider = |body: BodyRef, ov: Overlap| {
+            if best.as_ref().is_none_or(|b| ov.depth > b.1.depth) {
+                best = Some((body, ov));
             }
-        }
-        None
-    }
-
-    fn sweep_shape_tiles(
-        &self,
-        center: Vec2,
-        he: Vec2,
-        vel: Vec2,
-        mask: LayerMask,
-    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
-        let mut best: Option<(TileRef, SweepHit, Option<ColKey>)> = None;
-        let eps = self.cfg.tile_eps.max(1e-6);
-        let p0 = center;
-        let d = vel * self.cfg.dt;
+        };
+        self.view_candidates(self.dynamic_view(), min, max, |j| {
+            let other = &self.entries[j];
+            if j != idx
+                && self.allows_pair(mask, other.desc.mask)
+                && let Some(ov) = self.overlap_entries(e, other)
+            {
+                consider(BodyRef::Collider(FrameId(j as u32)), ov);
+            }
+        });
+        self.view_candidates(self.static_view(), min, max, |j| {
+            let other = &self.statics[j];
+            if self.allows_pair(mask, other.desc.mask)
+                && let Some(ov) = self.overlap_entries(e, other)
+            {
+                consider(BodyRef::Static(StaticId(j as u32)), ov);
+            }
+        });
         for (mi, m) in self.tilemaps.iter().enumerate() {
             if !self.allows_pair(mask, m.mask) {
                 continue;
             }
             let cell = m.cell.max(1e-5);
-            let len = d.length();
-            let steps_f = ((len / cell).ceil().max(1.0)) * 2.0;
-            let steps = steps_f as i32;
-            let mut t_prev = 0.0f32;
-            let mut prev_free = p0;
-            let tref_hit: Option<TileRef>;
-            for i in 1..=steps {
-                let t = (i as f32 / steps_f).min(1.0);
-                let p = p0 + d * t;
-                if let Some(tref) = self.any_tile_overlap_at(mi, m, p, he) {
-                    tref_hit = Some(tref);
-                    // binary search refine
-                    let mut lo = t_prev;
-                    let mut hi = t;
-                    for _ in 0..14 {
-                        let mid = 0.5 * (lo + hi);
-                        let q = p0 + d * mid;
-                        if self.any_tile_overlap_at(mi, m, q, he).is_some() {
-                            hi = mid;
-                        } else {
-                            lo = mid;
-                            prev_free = q;
-                        }
+            self.visit_tile_overlaps(mi, m, e.desc.center, he, |t| {
+                let tile_min = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32);
+                let (normal, depth, contact) = match e.desc.kind {
+                    ColliderKind::Aabb { half_extents } => {
+                        Narrowphase::aabb_tile_pushout(e.desc.center, half_extents, tile_min, cell)
                     }
-                    let toi = hi;
-                    let p_hit = p0 + d * toi;
-                    let tr = tref_hit.unwrap();
-                    let tile_min = m.origin
-                        + Vec2::new(tr.cell_xy.x as f32 * cell, tr.cell_xy.y as f32 * cell);
-                    let (n, _depth, contact) = crate::narrowphase::Narrowphase::aabb_tile_pushout(
-                        p_hit, he, tile_min, cell,
-                    );
-                    let mut hit = SweepHit {
-                        toi,
-                        normal: if n.length_squared() > 0.0 {
-                            n
-                        } else {
-                            (p_hit - prev_free).normalize_or_zero()
+                    ColliderKind::Circle { radius } => {
+                        Narrowphase::circle_tile_pushout(e.desc.center, radius, tile_min, cell)
+                    }
+                    ColliderKind::Point => {
+                        Narrowphase::circle_tile_pushout(e.desc.center, 0.0, tile_min, cell)
+                    }
+                };
+                // Circles can sit in a tile's bounds without touching its corner
+                if depth >= 0.0 {
+                    let hint = ResolutionHint::default();
+                    consider(
+                        BodyRef::Tile(t),
+                        Overlap {
+                            normal,
+                            depth,
+                            contact,
+                            hint,
+                            feature: ContactFeature::from_box_normal(normal),
                         },
-                        contact,
-                        hint: ResolutionHint::default(),
-                    };
-                    hit.hint.safe_pos = Some(p0 + d * (toi - eps));
-                    best = Some((tr, hit, m.user_key));
-                    break;
-                } else {
-                    t_prev = t;
-                    prev_free = p;
+                    );
                 }
-            }
-            if best.is_some() {
-                break;
-            }
+                true
+            });
         }
         best
     }
 
-    // Tile raycast helper
-    fn raycast_tiles_internal(
+    fn overlaps_between(
         &self,
-        origin: Vec2,
-        dir: Vec2,
-        max_t: f32,
-        mask: LayerMask,
-    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
-        if dir.length_squared() == 0.0 {
-            return None;
+        group_a: &[FrameId],
+        group_b: &[FrameId],
+    ) -> Vec<(FrameId, FrameId, Overlap)> {
+        // Invalid entries are exactly those whose AABB came out non-finite
+        let valid = |id: &FrameId| {
+            self.aabbs
+                .get(id.0 as usize)
+                .is_some_and(|(min, max)| min.is_finite() && max.is_finite())
+        };
+        let in_b: FxHashSet<usize> = group_b
+            .iter()
+            .filter(|id| valid(id))
+            .map(|id| id.0 as usize)
+            .collect();
+        let mut seen: FxHashSet<(usize, usize)> = FxHashSet::default();
+        let mut out = Vec::new();
+        if in_b.is_empty() {
+            return out;
         }
-        let mut best: Option<(TileRef, SweepHit, Option<ColKey>)> = None;
-        let eps = self.cfg.tile_eps.max(1e-6);
-
-        for (mi, m) in self.tilemaps.iter().enumerate() {
-            let cell = m.cell.max(1e-5);
-            let local = origin - m.origin;
-            let mut cx = (local.x / cell).floor() as i32;
-            let mut cy = (local.y / cell).floor() as i32;
+        for &a in group_a.iter().filter(|id| valid(id)) {
+            let ai = a.0 as usize;
+            let ea = &self.entries[ai];
+            let he = Self::entry_half_extents(ea);
+            let (min, max) = (ea.desc.center - he, ea.desc.center + he);
+            self.view_candidates(self.dynamic_view(), min, max, |bi| {
+                if bi != ai
+                    && in_b.contains(&bi)
+                    && seen.insert((ai.min(bi), ai.max(bi)))
+                    && let Some(ov) = self.overlap_pair_idx(ai, bi)
+                {
+                    out.push((a, FrameId(bi as u32), ov));
+                }
+            });
+        }
+        out
+    }
 
-            let step_x = if dir.x > 0.0 {
-                1
-            } else if dir.x < 0.0 {
-                -1
-            } else {
-                0
-            };
-            let step_y = if dir.y > 0.0 {
-                1
-            } else if dir.y < 0.0 {
-                -1
-            } else {
-                0
-            };
+    fn overlap_by_key(&self, a: ColKey, b: ColKey) -> Option<Overlap> {
+        let ia = self.key_to_id.get(&a)?.0 as usize;
+        let ib = self.key_to_id.get(&b)?.0 as usize;
+        self.overlap_pair_idx(ia, ib)
+    }
 
-            let next_boundary = |c: i32, step: i32| {
-                if step > 0 {
-                    (c as f32 + 1.0) * cell
-                } else {
-                    c as f32 * cell
-                }
-            };
+    fn sweep_by_key(&self, a: ColKey, b: ColKey) -> Option<SweepHit> {
+        let ia = self.key_to_id.get(&a)?.0 as usize;
+        let ib = self.key_to_id.get(&b)?.0 as usize;
+        self.sweep_pair_scaled(ia, ib)
+    }
+}
 
-            let mut t_max_x = if step_x != 0 {
-                let nb = m.origin.x + next_boundary(cx, step_x);
-                (nb - origin.x) / dir.x
-            } else {
-                f32::INFINITY
+impl PhysicsWorld {
+    /// Event generation shared by the buffered and callback paths. Stops once `count`
+    /// (events already emitted) reaches `max_events`.
+    fn emit_events(&mut self, mut count: usize, sink: &mut dyn FnMut(Event)) {
+        // Build candidate pairs from the broadphase, deduplicate, then dispatch narrowphase
+        let t_all = if self.cfg.enable_timing {
+            Some(Instant::now())
+        } else {
+            None
+        };
+        let t_scan0 = if self.cfg.enable_timing {
+            Some(Instant::now())
+        } else {
+            None
+        };
+        let toi_scale = self.toi_scale();
+        let push_event =
+            |mut ev: Event, sink: &mut dyn FnMut(Event), count: &mut usize, max: usize| {
+                if *count < max {
+                    if let Some(hit) = ev.sweep.as_mut() {
+                        hit.scale_toi(toi_scale);
+                    }
+                    sink(ev);
+                    *count += 1;
+                }
             };
 
-            let mut t_max_y = if step_y != 0 {
-                let nb = m.origin.y + next_boundary(cy, step_y);
-                (nb - origin.y) / dir.y
-            } else {
-                f32::INFINITY
-            };
+        let mut narrowphase_ms = 0.0;
+        let _ = self.for_each_candidate_pair(|a, b| {
+            if count >= self.cfg.max_events {
+                return ControlFlow::Break(());
+            }
 
-            let t_delta_x = if step_x != 0 {
-                cell / dir.x.abs()
-            } else {
-                f32::INFINITY
-            };
-            let t_delta_y = if step_y != 0 {
-                cell / dir.y.abs()
+            let t_np0 = if self.cfg.enable_timing {
+                Some(Instant::now())
             } else {
-                f32::INFINITY
+                None
             };
+            let ea = &self.entries[a];
+            let eb = &self.entries[b];
+            if !(ea.desc.event_targets.colliders() && eb.desc.event_targets.colliders()) {
+                return ControlFlow::Continue(());
+            }
+            // Mask consent (possibly mutual based on config)
+            if !self.allows_pair(ea.desc.mask, eb.desc.mask) {
+                return ControlFlow::Continue(());
+            }
 
-            let mut t_curr = 0.0f32;
-            let mut last_axis_x: Option<bool> = None; // None => starting cell
+            for ev in self
+                .pair_events(
+                    ea,
+                    eb,
+                    BodyRef::Collider(FrameId(a as u32)),
+                    BodyRef::Collider(FrameId(b as u32)),
+                )
+                .into_iter()
+                .flatten()
+            {
+                push_event(ev, sink, &mut count, self.cfg.max_events);
+            }
+            if let Some(t_np0) = t_np0 {
+                narrowphase_ms += t_np0.elapsed().as_secs_f64() * 1000.0;
+            }
+            ControlFlow::Continue(())
+        });
+        if let Some(timing) = self.last_timing.as_mut() {
+            timing.generate_narrowphase_ms += narrowphase_ms;
+        }
+        if let Some(t_scan0) = t_scan0 {
+            if self.last_timing.is_none() {
+                self.last_timing = Some(WorldTiming::default());
+            }
+            if let Some(timing) = self.last_timing.as_mut() {
+                timing.generate_scan_ms =
+                    t_scan0.elapsed().as_secs_f64() * 1000.0 - timing.generate_narrowphase_ms;
+            }
+        }
 
-            for _ in 0..20_000 {
-                if t_curr > max_t {
-                    break;
+        // Phase 1b: collider ↔ static collider events (statics never pair with each other)
+        if !self.statics.is_empty() {
+            let mut candidates: Vec<usize> = Vec::new();
+            'dynamic: for (i, ea) in self.entries.iter().enumerate() {
+                if !ea.desc.event_targets.colliders() {
+                    continue;
                 }
-
-                if cx >= 0 && cy >= 0 && (cx as u32) < m.width && (cy as u32) < m.height {
-                    let idx = cy as u32 * m.width + cx as u32;
-                    if m.solids[idx as usize] != 0 && self.allows_pair(mask, m.mask) {
-                        // hit the NEAR face: we entered this cell at t_curr
-                        let toi = t_curr.max(0.0);
-                        let normal = match last_axis_x {
-                            Some(true) => Vec2::new(-(step_x as f32), 0.0),
-                            Some(false) => Vec2::new(0.0, -(step_y as f32)),
-                            None => Vec2::ZERO, // started inside a solid tile
-                        };
-                        let mut hit = SweepHit {
-                            toi,
-                            normal,
-                            contact: origin + dir * toi,
-                            hint: ResolutionHint::default(),
-                        };
-                        hit.hint.safe_pos = Some(origin + dir * (toi - eps));
-                        let tr = TileRef {
-                            map: TileMapRef(mi as u32),
-                            cell_xy: glam::UVec2::new(cx as u32, cy as u32),
-                        };
-                        let key = m.user_key;
-
-                        match &best {
-                            Some((_, bh, _)) if hit.toi >= bh.toi => {}
-                            _ => best = Some((tr, hit, key)),
-                        }
-                        break;
+                candidates.clear();
+                if ea.desc.broadphase == BroadphaseMode::Global {
+                    candidates.extend(0..self.statics.len());
+                } else {
+                    let (min, max) = self.aabbs[i];
+                    self.view_candidates(self.static_view(), min, max, |si| candidates.push(si));
+                }
+                for &si in &candidates {
+                    if count >= self.cfg.max_events {
+                        break 'dynamic;
+                    }
+                    let es = &self.statics[si];
+                    if !self.allows_pair(ea.desc.mask, es.desc.mask) {
+                        continue;
+                    }
+                    for ev in self
+                        .pair_events(
+                            ea,
+                            es,
+                            BodyRef::Collider(FrameId(i as u32)),
+                            BodyRef::Static(StaticId(si as u32)),
+                        )
+                        .into_iter()
+                        .flatten()
+                    {
+                        push_event(ev, sink, &mut count, self.cfg.max_events);
                     }
                 }
+            }
+        }
 
-                // step to next cell; update entry time & axis
-                if t_max_x < t_max_y {
-                    cx += step_x;
-                    t_curr = t_max_x;
-                    t_max_x += t_delta_x;
-                    last_axis_x = Some(true);
-                } else {
-                    cy += step_y;
-                    t_curr = t_max_y;
-                    t_max_y += t_delta_y;
-                    last_axis_x = Some(false);
+        // Phase 2: collider ↔ tile events
+        if count < self.cfg.max_events {
+            for (i, e) in self.entries.iter().enumerate() {
+                let (min, max) = self.aabbs[i];
+                if !e.desc.event_targets.tiles() || !min.is_finite() || !max.is_finite() {
+                    continue;
+                }
+                let a = BodyRef::Collider(FrameId(i as u32));
+                self.tile_events(e, a, &mut |ev| {
+                    push_event(ev, sink, &mut count, self.cfg.max_events);
+                    count < self.cfg.max_events
+                });
+                if count >= self.cfg.max_events {
+                    break;
                 }
             }
         }
-        best
+        if let Some(t_all) = t_all {
+            if self.last_timing.is_none() {
+                self.last_timing = Some(WorldTiming::default());
+            }
+            if let Some(timing) = self.last_timing.as_mut() {
+                timing.generate_ms = t_all.elapsed().as_secs_f64() * 1000.0;
+                timing.events_emitted = count;
+                timing.frame_counter = self.frame_counter;
+            }
+            self.completed_timing = self.last_timing;
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Broadphase bounds `(min, max)` the world would use for a collider, without a world:
+    /// with `tighten`, the shape's bounds at `center` and `center + vel * dt` combined;
+    /// otherwise (or when not moving) just the bounds at `center`.
+    pub fn swept_aabb(
+        kind: ColliderKind,
+        center: Vec2,
+        vel: Vec2,
+        dt: f32,
+        tighten: bool,
+    ) -> (Vec2, Vec2) {
+        Self::bounds_over(kind, center, vel * dt, tighten)
+    }
 
-    fn cfg() -> WorldConfig {
-        WorldConfig {
-            cell_size: 1.0,
-            dt: 1.0,
-            tighten_swept_aabb: true,
-            enable_overlap_events: true,
-            enable_sweep_events: true,
-            max_events: 1024,
-            enable_timing: false,
-            tile_eps: 1e-4,
-            require_mutual_consent: true,
+    fn compute_entry_aabb(&self, e: &Entry) -> (Vec2, Vec2) {
+        // Without sweeps only start positions are ever tested, so skip the swept bounds
+        if !self.sweeps_enabled() {
+            let (min, max) = Self::bounds_over(e.desc.kind, e.desc.center, Vec2::ZERO, false);
+            return self.pad_bounds(e, min, max);
         }
+        let tighten = e.motion.tighten.unwrap_or(self.cfg.tighten_swept_aabb);
+        let (min, max) = Self::bounds_over(
+            e.desc.kind,
+            e.desc.center,
+            self.displacement(&e.motion),
+            tighten,
+        );
+        self.pad_bounds(e, min, max)
     }
 
-    #[test]
-    fn test_push_and_end_frame_grid_coverage() {
-        let mut w = PhysicsWorld::new(cfg());
-        w.begin_frame();
-        // AABB covering from (-0.5,-0.5) to (0.5,0.5)
-        let mask = LayerMask::simple(1, 1);
-        w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
-        w.end_frame();
-        // With floor indexing, bounds straddling origin cover 4 cells
-        assert_eq!(w.grid.len(), 4);
-        for k in [(-1, -1), (-1, 0), (0, -1), (0, 0)] {
-            assert!(w.grid.contains_key(&k));
-            assert_eq!(w.grid[&k].len(), 1);
+    /// Grow an entry's bounds by `contact_skin`, plus half of `point_eps` per side for a
+    /// `Point`, so shapes that count as overlapping still share a broadphase cell.
+    fn pad_bounds(&self, e: &Entry, min: Vec2, max: Vec2) -> (Vec2, Vec2) {
+        let mut pad = self.cfg.contact_skin.max(0.0);
+        if matches!(e.desc.kind, ColliderKind::Point) {
+            pad += self.cfg.point_eps * 0.5;
         }
+        let pad = Vec2::splat(pad);
+        (min - pad, max + pad)
     }
 
-    #[test]
-    fn test_mask_mutual_consent() {
-        let mut w = PhysicsWorld::new(cfg());
-        w.begin_frame();
-        let a_mask = LayerMask {
-            layer: 1,
-            collides_with: 2,
-            exclude: 0,
-        };
-        let b_mask = LayerMask {
-            layer: 2,
-            collides_with: 0,
-            exclude: 0,
+    fn bounds_over(kind: ColliderKind, center: Vec2, d: Vec2, tighten: bool) -> (Vec2, Vec2) {
+        // Base extents by kind
+        let half = match kind.normalized() {
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+            ColliderKind::Point => Vec2::ZERO,
         };
-        w.push_aabb(
-            Vec2::new(-0.5, 0.0),
-            Vec2::splat(0.5),
-            Vec2::new(1.0, 0.0),
-            a_mask,
-            None,
-        );
-        w.push_aabb(
-            Vec2::new(0.5, 0.0),
-            Vec2::splat(0.5),
-            Vec2::ZERO,
-            b_mask,
-            None,
+
+        // Zero-velocity colliders gain nothing from the swept bounds
+        if tighten && d != Vec2::ZERO {
+            let p1 = center + d;
+            (center.min(p1) - half, center.max(p1) + half)
+        } else {
+            (center - half, center + half)
+        }
+    }
+
+    /// Resolve a `BodyRef` of the current frame to its descriptor (None if stale).
+    fn body_desc(&self, body: BodyRef) -> Option<BodyDesc> {
+        match body {
+            BodyRef::Collider(id) => self
+                .entries
+                .get(id.0 as usize)
+                .map(|e| BodyDesc::Shape(e.desc)),
+            BodyRef::Static(id) => self
+                .statics
+                .get(id.0 as usize)
+                .map(|e| BodyDesc::Shape(e.desc)),
+            BodyRef::Tile(t) => {
+                let m = self.tilemaps.get(t.map.0 as usize)?;
+                let (ix, iy) = (t.cell_xy.x as i32, t.cell_xy.y as i32);
+                Some(BodyDesc::Tile {
+                    mask: m.mask,
+                    user_key: m.user_key,
+                    min: m.tile_min(ix, iy),
+                    cell: m.cell,
+                    bits: m.solids.get(Self::tile_at(m, ix, iy)?),
+                })
+            }
+        }
+    }
+
+    /// Stand-in for a rejected map in release builds: a 0x0 map that collides with nothing
+    /// but keeps the returned `TileMapRef` valid for later calls.
+    fn push_empty_tilemap(&mut self, mask: LayerMask, user_key: Option<ColKey>) -> TileMapRef {
+        self.push_tilemap(TileMap {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 0,
+            height: 0,
+            solids: TileCells::Bytes(Vec::new()),
+            solid_mask: 0xFF,
+            mask,
+            user_key,
+            vel: Vec2::ZERO,
+            dirty_rows: Vec::new(),
+            runs: TileRuns::default(),
+            solid_bounds: None,
+        })
+    }
+
+    /// Build a validated map's run tables and append it.
+    fn push_tilemap(&mut self, mut map: TileMap) -> TileMapRef {
+        map.rebuild_all_runs();
+        map.update_solid_bounds((0, 0), (map.width.max(1) - 1, map.height.max(1) - 1), false);
+        self.tilemaps.push(map);
+        TileMapRef((self.tilemaps.len() - 1) as u32)
+    }
+
+    /// Swap in a staged config; the persistent static grid is rebuilt if `cell_size` or the
+    /// bounds padding (`contact_skin`, `point_eps`) moved.
+    fn apply_config(&mut self, cfg: WorldConfig) {
+        let old = (
+            self.cfg.cell_size,
+            self.cfg.contact_skin,
+            self.cfg.point_eps,
         );
-        w.end_frame();
-        w.generate_events();
-        assert_eq!(w.drain_events().len(), 0);
+        self.cfg = cfg;
+        if (self.cfg.contact_skin, self.cfg.point_eps) != (old.1, old.2) {
+            // Static bounds carry the skin and point padding
+            let aabbs: Vec<(Vec2, Vec2)> = self
+                .statics
+                .iter()
+                .map(|e| self.compute_entry_aabb(e))
+                .collect();
+            self.static_aabbs = aabbs;
+        }
+        if (
+            self.cfg.cell_size,
+            self.cfg.contact_skin,
+            self.cfg.point_eps,
+        ) != old
+        {
+            self.static_grid.clear();
+            self.static_grid_bounds = None;
+            let cs = self.cfg.cell_size.max(1e-5);
+            for (i, &(min, max)) in self.static_aabbs.iter().enumerate() {
+                Self::insert_into_grid(
+                    &mut self.static_grid,
+                    &mut self.static_grid_bounds,
+                    cs,
+                    i,
+                    min,
+                    max,
+                );
+            }
+        }
+    }
+
+    fn insert_into_grid(
+        grid: &mut FxHashMap<(i32, i32), Vec<usize>>,
+        bounds: &mut Option<CellBounds>,
+        cs: f32,
+        idx: usize,
+        min: Vec2,
+        max: Vec2,
+    ) {
+        let ix0 = (min.x / cs).floor() as i32;
+        let iy0 = (min.y / cs).floor() as i32;
+        let ix1 = (max.x / cs).floor() as i32;
+        let iy1 = (max.y / cs).floor() as i32;
+        *bounds = Some(match *bounds {
+            Some(((bx0, by0), (bx1, by1))) => {
+                ((bx0.min(ix0), by0.min(iy0)), (bx1.max(ix1), by1.max(iy1)))
+            }
+            None => ((ix0, iy0), (ix1, iy1)),
+        });
+        for iy in iy0..=iy1 {
+            for ix in ix0..=ix1 {
+                grid.entry((ix, iy)).or_default().push(idx);
+            }
+        }
+    }
+
+    /// Whether frame collider `i` skips the broadphase and pairs with everything: marked
+    /// `Global`, or on the grid spanning more than `large_collider_cell_threshold` cells.
+    fn is_global(&self, i: usize) -> bool {
+        if self.entries[i].desc.broadphase == BroadphaseMode::Global {
+            return true;
+        }
+        let threshold = self.cfg.large_collider_cell_threshold;
+        if threshold == 0 || self.cfg.broadphase != Broadphase::Grid {
+            return false;
+        }
+        let cs = self.cfg.cell_size.max(1e-5);
+        let (min, max) = self.aabbs[i];
+        let span = (max / cs).floor() - (min / cs).floor() + Vec2::ONE;
+        // f64 so huge spans don't overflow; NaN bounds compare false and stay put
+        span.x as f64 * span.y as f64 > threshold as f64
+    }
+
+    /// Cell filter for circle queries: keep cells whose nearest point is within `radius`.
+    fn cell_in_circle(center: Vec2, radius: f32) -> impl Fn(Vec2, Vec2) -> bool {
+        move |min, max| center.clamp(min, max).distance_squared(center) <= radius * radius
+    }
+
+    fn world_to_cell(&self, p: Vec2, cs: f32) -> (i32, i32) {
+        ((p.x / cs).floor() as i32, (p.y / cs).floor() as i32)
+    }
+
+    fn entry_half_extents(e: &Entry) -> Vec2 {
+        match e.desc.kind {
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+            ColliderKind::Point => Vec2::ZERO,
+        }
+    }
+
+    fn dynamic_view(&self) -> GridView<'_> {
+        GridView {
+            grid: &self.grid,
+            grid_bounds: self.grid_bounds,
+            globals: &self.globals,
+            bvh: (self.cfg.broadphase == Broadphase::Bvh).then_some(&self.bvh),
+            sap: (self.cfg.broadphase == Broadphase::Sap).then_some(&self.sap),
+            entries: &self.entries,
+            aabbs: &self.aabbs,
+        }
+    }
+
+    fn static_view(&self) -> GridView<'_> {
+        GridView {
+            grid: &self.static_grid,
+            grid_bounds: self.static_grid_bounds,
+            globals: &[],
+            bvh: None,
+            sap: None,
+            entries: &self.statics,
+            aabbs: &self.static_aabbs,
+        }
+    }
+
+    /// Visit each entry whose broadphase cells/leaves touch `[min, max]`, once.
+    fn view_candidates(&self, view: GridView<'_>, min: Vec2, max: Vec2, f: impl FnMut(usize)) {
+        self.view_candidates_where(view, min, max, |_, _| true, f);
+    }
+
+    /// `view_candidates`, skipping grid cells `(cell_min, cell_max)` for which `keep_cell` is false.
+    /// Trees ignore `keep_cell`; their leaves are already tight.
+    fn view_candidates_where(
+        &self,
+        view: GridView<'_>,
+        min: Vec2,
+        max: Vec2,
+        keep_cell: impl Fn(Vec2, Vec2) -> bool,
+        f: impl FnMut(usize),
+    ) {
+        self.view_candidates_in(view, min, max, keep_cell, &mut FxHashSet::default(), f);
+    }
+
+    /// `view_candidates_where` deduplicating through a caller-owned `seen` set.
+    fn view_candidates_in<S: BuildHasher>(
+        &self,
+        view: GridView<'_>,
+        min: Vec2,
+        max: Vec2,
+        keep_cell: impl Fn(Vec2, Vec2) -> bool,
+        seen: &mut HashSet<u32, S>,
+        mut f: impl FnMut(usize),
+    ) {
+        if let Some(bvh) = view.bvh {
+            bvh.query(min, max, |idx| {
+                if seen.insert(idx as u32) {
+                    f(idx)
+                }
+            });
+            return;
+        }
+        if let Some(sap) = view.sap {
+            // SAP reports each entry once
+            sap.query(view.aabbs, min, max, f);
+            return;
+        }
+        if !(min.is_finite() && max.is_finite()) {
+            return;
+        }
+        for &idx in view.globals {
+            if seen.insert(idx as u32) {
+                f(idx);
+            }
+        }
+        // Out-of-grid boxes would otherwise walk a huge cell range
+        let Some(((bx0, by0), (bx1, by1))) = view.grid_bounds else {
+            return;
+        };
+        let cs = self.cfg.cell_size.max(1e-5);
+        let (ix0, iy0) = self.world_to_cell(min, cs);
+        let (ix1, iy1) = self.world_to_cell(max, cs);
+        for iy in iy0.max(by0)..=iy1.min(by1) {
+            for ix in ix0.max(bx0)..=ix1.min(bx1) {
+                let cell_min = Vec2::new(ix as f32, iy as f32) * cs;
+                if !keep_cell(cell_min, cell_min + Vec2::splat(cs)) {
+                    continue;
+                }
+                if let Some(list) = view.grid.get(&(ix, iy)) {
+                    for &idx in list {
+                        if seen.insert(idx as u32) {
+                            f(idx);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// `query_aabb` body shared by the allocating and buffer-reusing variants.
+    fn query_aabb_in<S: BuildHasher>(
+        &self,
+        center: Vec2,
+        half_extents: Vec2,
+        mask: LayerMask,
+        seen: &mut HashSet<u32, S>,
+        out: &mut Vec<(FrameId, Option<ColKey>)>,
+    ) {
+        let view = self.dynamic_view();
+        let (min, max) = (center - half_extents, center + half_extents);
+        self.view_candidates_in(
+            view,
+            min,
+            max,
+            |_, _| true,
+            seen,
+            |idx| {
+                let e = &view.entries[idx];
+                if mask.allows(e.desc.mask)
+                    && e.desc.mask.allows(mask)
+                    && Self::entry_overlaps_aabb(e, center, half_extents)
+                {
+                    out.push((FrameId(idx as u32), e.desc.user_key));
+                }
+            },
+        );
+    }
+
+    /// Masked lookup of broadphase candidates in `[min, max]` that pass `test`.
+    fn grid_query(
+        &self,
+        view: GridView<'_>,
+        min: Vec2,
+        max: Vec2,
+        mask: LayerMask,
+        test: impl Fn(&Entry) -> bool,
+    ) -> Vec<(usize, Option<ColKey>)> {
+        self.grid_query_where(view, min, max, mask, |_, _| true, test)
+    }
+
+    /// `grid_query` over only the grid cells that pass `keep_cell`.
+    fn grid_query_where(
+        &self,
+        view: GridView<'_>,
+        min: Vec2,
+        max: Vec2,
+        mask: LayerMask,
+        keep_cell: impl Fn(Vec2, Vec2) -> bool,
+        test: impl Fn(&Entry) -> bool,
+    ) -> Vec<(usize, Option<ColKey>)> {
+        let mut out = Vec::new();
+        self.view_candidates_where(view, min, max, keep_cell, |idx| {
+            let e = &view.entries[idx];
+            if mask.allows(e.desc.mask) && e.desc.mask.allows(mask) && test(e) {
+                out.push((idx, e.desc.user_key));
+            }
+        });
+        out
+    }
+
+    /// Visit candidate frame-collider pairs `(a, b)`, `a < b`, from the configured
+    /// broadphase until `f` breaks. Each pair is visited once.
+    fn for_each_candidate_pair(
+        &self,
+        mut f: impl FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let is_global = |i: usize| !self.globals.is_empty() && self.is_global(i);
+        // Trees paired globals by bounds; skip those and pair them with everything below
+        let mut visit = |a: usize, b: usize| {
+            if is_global(a) || is_global(b) {
+                ControlFlow::Continue(())
+            } else {
+                f(a, b)
+            }
+        };
+        match self.cfg.broadphase {
+            Broadphase::Grid => {
+                let mut seen_pairs: FxHashSet<(usize, usize)> = FxHashSet::default();
+                for indices in self.grid.values() {
+                    for i0 in 0..indices.len() {
+                        for i1 in (i0 + 1)..indices.len() {
+                            let a = indices[i0];
+                            let b = indices[i1];
+                            let key = if a < b { (a, b) } else { (b, a) };
+                            if seen_pairs.insert(key) {
+                                visit(key.0, key.1)?;
+                            }
+                        }
+                    }
+                }
+            }
+            Broadphase::Bvh => self.bvh.pairs(&self.aabbs, &mut visit)?,
+            Broadphase::Sap => self.sap.pairs(&self.aabbs, &mut visit)?,
+        }
+        for &g in &self.globals {
+            for j in 0..self.entries.len() {
+                // Global-global pairs are emitted once, from the larger index
+                if j == g || (is_global(j) && j > g) {
+                    continue;
+                }
+                f(j.min(g), j.max(g))?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn entry_contains_point(e: &Entry, p: Vec2, point_eps: f32) -> bool {
+        match e.desc.kind {
+            ColliderKind::Aabb { half_extents } => {
+                crate::narrowphase::Narrowphase::overlap_point_aabb(p, e.desc.center, half_extents)
+            }
+            ColliderKind::Circle { radius } => {
+                crate::narrowphase::Narrowphase::overlap_point_circle(p, e.desc.center, radius)
+            }
+            ColliderKind::Point => (p - e.desc.center).length_squared() <= point_eps * point_eps,
+        }
+    }
+
+    fn entry_overlaps_aabb(e: &Entry, center: Vec2, half_extents: Vec2) -> bool {
+        match e.desc.kind {
+            ColliderKind::Aabb { half_extents: h } => {
+                crate::narrowphase::Narrowphase::overlap_aabb_aabb(
+                    e.desc.center,
+                    h,
+                    center,
+                    half_extents,
+                )
+                .is_some()
+            }
+            ColliderKind::Circle { radius } => {
+                Self::overlap_circle_aabb_bool(e.desc.center, radius, center, half_extents)
+            }
+            ColliderKind::Point => crate::narrowphase::Narrowphase::overlap_point_aabb(
+                e.desc.center,
+                center,
+                half_extents,
+            ),
+        }
+    }
+
+    fn entry_overlaps_circle(e: &Entry, center: Vec2, radius: f32) -> bool {
+        match e.desc.kind {
+            ColliderKind::Aabb { half_extents } => {
+                Self::overlap_circle_aabb_bool(center, radius, e.desc.center, half_extents)
+            }
+            ColliderKind::Circle { radius: r1 } => {
+                crate::narrowphase::Narrowphase::overlap_circle_circle(
+                    center,
+                    radius,
+                    e.desc.center,
+                    r1,
+                )
+                .is_some()
+            }
+            ColliderKind::Point => {
+                crate::narrowphase::Narrowphase::overlap_point_circle(e.desc.center, center, radius)
+            }
+        }
+    }
+
+    /// Ray test against one entry after mask consent; hits outside `[0, max_t]` are dropped.
+    fn ray_entry(
+        view: GridView<'_>,
+        idx: usize,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<SweepHit> {
+        let e = &view.entries[idx];
+        // Mask mutual consent between ray mask and collider mask
+        if !(mask.allows(e.desc.mask) && e.desc.mask.allows(mask)) {
+            return None;
+        }
+        Self::ray_shape(view, idx, origin, dir, max_t)
+    }
+
+    /// Ray test against one entry's shape, without masks; hits outside `[0, max_t]` are dropped.
+    fn ray_shape(
+        view: GridView<'_>,
+        idx: usize,
+        origin: Vec2,
+        dir: Vec2,
+        max_t: f32,
+    ) -> Option<SweepHit> {
+        let e = &view.entries[idx];
+        let mut h = match e.desc.kind {
+            ColliderKind::Aabb { .. } => {
+                let (min, max) = view.aabbs[idx];
+                crate::narrowphase::Narrowphase::ray_aabb(origin, dir, min, max)
+            }
+            ColliderKind::Circle { radius } => {
+                crate::narrowphase::Narrowphase::ray_circle(origin, dir, e.desc.center, radius)
+            }
+            ColliderKind::Point => {
+                crate::narrowphase::Narrowphase::ray_circle(origin, dir, e.desc.center, 0.0)
+            }
+        }?;
+        if h.toi < 0.0 || h.toi > max_t {
+            return None;
+        }
+        h.hint = ResolutionHint::default();
+        Some(h)
+    }
+
+    /// Raycast over one collider store (grid DDA or BVH); returns the closest entry index and hit.
+    fn raycast_grid(
+        &self,
+        view: GridView<'_>,
+        origin: Vec2,
+        dir: Vec2,
+        mask: LayerMask,
+        max_t: f32,
+    ) -> Option<(usize, SweepHit)> {
+        if dir.length_squared() == 0.0 {
+            return None;
+        }
+        let mut best: Option<(usize, SweepHit)> = None;
+        if let Some(bvh) = view.bvh {
+            bvh.query_ray(origin, dir, max_t, |idx| {
+                if let Some(h) = Self::ray_entry(view, idx, origin, dir, mask, max_t) {
+                    match &best {
+                        Some((_, bh)) if h.toi >= bh.toi => {}
+                        _ => best = Some((idx, h)),
+                    }
+                }
+            });
+            return best;
+        }
+        if let Some(sap) = view.sap {
+            // Candidates from the ray's bounding box; zero dir components stay at the origin
+            let end = origin + Vec2::select(dir.cmpeq(Vec2::ZERO), Vec2::ZERO, dir * max_t);
+            sap.query(view.aabbs, origin.min(end), origin.max(end), |idx| {
+                if let Some(h) = Self::ray_entry(view, idx, origin, dir, mask, max_t) {
+                    match &best {
+                        Some((_, bh)) if h.toi >= bh.toi => {}
+                        _ => best = Some((idx, h)),
+                    }
+                }
+            });
+            return best;
+        }
+        let cs = self.cfg.cell_size.max(1e-5);
+        // Setup DDA
+        let mut tested: FxHashSet<usize> = FxHashSet::default();
+        for &idx in view.globals {
+            tested.insert(idx);
+            if let Some(h) = Self::ray_entry(view, idx, origin, dir, mask, max_t) {
+                match &best {
+                    Some((_, bh)) if h.toi >= bh.toi => {}
+                    _ => best = Some((idx, h)),
+                }
+            }
+        }
+
+        let mut cell = self.world_to_cell(origin, cs);
+        let step_x = if dir.x > 0.0 {
+            1
+        } else if dir.x < 0.0 {
+            -1
+        } else {
+            0
+        };
+        let step_y = if dir.y > 0.0 {
+            1
+        } else if dir.y < 0.0 {
+            -1
+        } else {
+            0
+        };
+        let next_boundary = |c: i32, step: i32| -> f32 {
+            if step > 0 {
+                (c as f32 + 1.0) * cs
+            } else {
+                c as f32 * cs
+            }
+        };
+        let _cell_origin = Vec2::new(cell.0 as f32 * cs, cell.1 as f32 * cs);
+        let mut t_max_x = if step_x != 0 {
+            let nb = next_boundary(cell.0, step_x);
+            (nb - origin.x) / dir.x
+        } else {
+            f32::INFINITY
+        };
+        let mut t_max_y = if step_y != 0 {
+            let nb = next_boundary(cell.1, step_y);
+            (nb - origin.y) / dir.y
+        } else {
+            f32::INFINITY
+        };
+        let t_delta_x = if step_x != 0 {
+            cs / dir.x.abs()
+        } else {
+            f32::INFINITY
+        };
+        let t_delta_y = if step_y != 0 {
+            cs / dir.y.abs()
+        } else {
+            f32::INFINITY
+        };
+
+        let mut t_curr = 0.0f32;
+        // Visit cells until exceeding max_t
+        for _ in 0..10_000 {
+            // safety cap
+            if t_curr > max_t {
+                break;
+            }
+            if let Some(list) = view.grid.get(&cell) {
+                for &idx in list {
+                    if !tested.insert(idx) {
+                        continue;
+                    }
+                    if let Some(h) = Self::ray_entry(view, idx, origin, dir, mask, max_t) {
+                        match &best {
+                            Some((_, bh)) if h.toi >= bh.toi => {}
+                            _ => best = Some((idx, h)),
+                        }
+                    }
+                }
+            }
+
+            // Step to next cell
+            if t_max_x < t_max_y {
+                cell.0 += step_x;
+                t_curr = t_max_x;
+                t_max_x += t_delta_x;
+            } else {
+                cell.1 += step_y;
+                t_curr = t_max_y;
+                t_max_y += t_delta_y;
+            }
+        }
+
+        best
+    }
+
+    fn overlap_circle_aabb_bool(circle_c: Vec2, r: f32, box_c: Vec2, box_h: Vec2) -> bool {
+        let min = box_c - box_h;
+        let max = box_c + box_h;
+        let clamp = |v: f32, lo: f32, hi: f32| v.max(lo).min(hi);
+        let closest = Vec2::new(
+            clamp(circle_c.x, min.x, max.x),
+            clamp(circle_c.y, min.y, max.y),
+        );
+        (closest - circle_c).length_squared() <= r * r
+    }
+
+    fn overlap_pair_idx(&self, ai: usize, bi: usize) -> Option<Overlap> {
+        self.overlap_entries(&self.entries[ai], &self.entries[bi])
+    }
+
+    /// Overlap of two entries; `contact_skin` grows `a` for the test and is taken back off
+    /// the depth, so shapes within the skin report a negative depth.
+    fn overlap_entries(&self, a: &Entry, b: &Entry) -> Option<Overlap> {
+        let skin = self.cfg.contact_skin;
+        if skin <= 0.0 {
+            return self.overlap_shapes(a.desc.kind, a.desc.center, b.desc.kind, b.desc.center);
+        }
+        let grown = match a.desc.kind {
+            ColliderKind::Aabb { half_extents } => ColliderKind::Aabb {
+                half_extents: half_extents + Vec2::splat(skin),
+            },
+            ColliderKind::Circle { radius } => ColliderKind::Circle {
+                radius: radius + skin,
+            },
+            // Point-Point overlaps within `point_eps`; keep that on top of the skin
+            ColliderKind::Point => ColliderKind::Circle {
+                radius: skin
+                    + if matches!(b.desc.kind, ColliderKind::Point) {
+                        self.cfg.point_eps
+                    } else {
+                        0.0
+                    },
+            },
+        };
+        let ov = self.overlap_shapes(grown, a.desc.center, b.desc.kind, b.desc.center)?;
+        Some(Overlap {
+            depth: ov.depth - skin,
+            ..ov
+        })
+    }
+
+    fn overlap_shapes(
+        &self,
+        ka: ColliderKind,
+        ca: Vec2,
+        kb: ColliderKind,
+        cb: Vec2,
+    ) -> Option<Overlap> {
+        use crate::narrowphase::Narrowphase;
+        let half = |k: ColliderKind| match k {
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+            ColliderKind::Point => Vec2::ZERO,
+        };
+        match (ka, kb) {
+            (ColliderKind::Aabb { .. }, ColliderKind::Aabb { .. }) => {
+                Narrowphase::overlap_aabb_aabb(ca, half(ka), cb, half(kb))
+            }
+            (ColliderKind::Circle { radius: r0 }, ColliderKind::Circle { radius: r1 }) => {
+                Narrowphase::overlap_circle_circle(ca, r0, cb, r1)
+            }
+            // Points are zero-radius circles so penetration depth is still reported
+            (ColliderKind::Point, ColliderKind::Aabb { .. }) => {
+                Narrowphase::overlap_circle_aabb(ca, 0.0, cb, half(kb))
+            }
+            (ColliderKind::Aabb { .. }, ColliderKind::Point) => {
+                let ov = Narrowphase::overlap_circle_aabb(cb, 0.0, ca, half(ka))?;
+                Some(Overlap {
+                    normal: -ov.normal,
+                    ..ov
+                })
+            }
+            (ColliderKind::Point, ColliderKind::Circle { radius: r }) => {
+                Narrowphase::overlap_circle_circle(ca, 0.0, cb, r)
+            }
+            (ColliderKind::Circle { radius: r }, ColliderKind::Point) => {
+                Narrowphase::overlap_circle_circle(ca, r, cb, 0.0)
+            }
+            (ColliderKind::Circle { radius }, ColliderKind::Aabb { .. }) => {
+                Narrowphase::overlap_circle_aabb(ca, radius, cb, half(kb))
+            }
+            (ColliderKind::Aabb { .. }, ColliderKind::Circle { radius }) => {
+                // Swap and invert normal so it still points from B into A
+                let ov = Narrowphase::overlap_circle_aabb(cb, radius, ca, half(ka))?;
+                Some(Overlap {
+                    normal: -ov.normal,
+                    ..ov
+                })
+            }
+            (ColliderKind::Point, ColliderKind::Point) => {
+                Narrowphase::overlap_circle_circle(ca, 0.0, cb, self.cfg.point_eps)
+            }
+        }
+    }
+
+    /// Whether a cast hit on `body` at `toi` displaces the current best at `best_toi`:
+    /// strictly earlier, or a tile tying a collider/static under `TiePreference::Tile`.
+    fn replaces_best(&self, body: BodyRef, toi: f32, best_toi: f32) -> bool {
+        toi < best_toi
+            || (toi == best_toi
+                && matches!(body, BodyRef::Tile(_))
+                && self.cfg.ray_tie_preference == TiePreference::Tile)
+    }
+
+    /// Multiplier from frame-fraction TOI to the reported unit (`dt` with `toi_in_seconds`).
+    fn toi_scale(&self) -> f32 {
+        if self.cfg.toi_in_seconds {
+            self.cfg.dt
+        } else {
+            1.0
+        }
+    }
+
+    /// `sweep_pair_idx` with `toi` in the reported unit.
+    fn sweep_pair_scaled(&self, ai: usize, bi: usize) -> Option<SweepHit> {
+        let mut hit = self.sweep_pair_idx(ai, bi)?;
+        hit.scale_toi(self.toi_scale());
+        Some(hit)
+    }
+
+    fn sweep_pair_idx(&self, ai: usize, bi: usize) -> Option<SweepHit> {
+        self.sweep_entries(&self.entries[ai], &self.entries[bi])
+    }
+
+    fn sweep_entries(&self, a: &Entry, b: &Entry) -> Option<SweepHit> {
+        self.sweep_moving(
+            a,
+            self.displacement(&a.motion),
+            b,
+            self.displacement(&b.motion),
+        )
+    }
+
+    /// Frame displacement: `delta` when given, else `vel * dt` (the motion's own `dt` first).
+    fn displacement(&self, m: &Motion) -> Vec2 {
+        m.delta
+            .unwrap_or_else(|| m.vel * m.dt.unwrap_or(self.cfg.dt))
+    }
+
+    /// Velocity consistent with `displacement` (units per frame, like `vel`).
+    fn frame_vel(&self, m: &Motion) -> Vec2 {
+        match (m.delta, m.dt) {
+            (None, None) => m.vel,
+            _ if self.cfg.dt != 0.0 => self.displacement(m) / self.cfg.dt,
+            _ => Vec2::ZERO,
+        }
+    }
+
+    /// Sweep `a` by displacement `da` against `b` moving by `db`; `toi` is a fraction of the step.
+    fn sweep_moving(&self, a: &Entry, da: Vec2, b: &Entry, db: Vec2) -> Option<SweepHit> {
+        use crate::narrowphase::Narrowphase;
+        let mut hit = match (a.desc.kind, b.desc.kind) {
+            (ColliderKind::Aabb { .. }, ColliderKind::Aabb { .. }) => Narrowphase::sweep_aabb_aabb(
+                a.desc.center,
+                Self::entry_half_extents(a),
+                da,
+                b.desc.center,
+                Self::entry_half_extents(b),
+                db,
+            ),
+            (ColliderKind::Circle { radius: r0 }, ColliderKind::Circle { radius: r1 }) => {
+                Narrowphase::sweep_circle_circle(a.desc.center, r0, da, b.desc.center, r1, db)
+            }
+            (ColliderKind::Circle { radius: r }, ColliderKind::Aabb { .. }) => {
+                Narrowphase::sweep_circle_aabb(
+                    a.desc.center,
+                    r,
+                    da,
+                    b.desc.center,
+                    Self::entry_half_extents(b),
+                    db,
+                )
+            }
+            (ColliderKind::Aabb { .. }, ColliderKind::Circle { radius: r }) => {
+                Narrowphase::sweep_aabb_circle(
+                    a.desc.center,
+                    Self::entry_half_extents(a),
+                    da,
+                    b.desc.center,
+                    r,
+                    db,
+                )
+            }
+            (ColliderKind::Point, ColliderKind::Aabb { .. }) => Narrowphase::sweep_circle_aabb(
+                a.desc.center,
+                0.0,
+                da,
+                b.desc.center,
+                Self::entry_half_extents(b),
+                db,
+            ),
+            (ColliderKind::Aabb { .. }, ColliderKind::Point) => {
+                let hit = Narrowphase::sweep_circle_aabb(
+                    b.desc.center,
+                    0.0,
+                    db,
+                    a.desc.center,
+                    Self::entry_half_extents(a),
+                    da,
+                )?;
+                Some(SweepHit {
+                    toi: hit.toi,
+                    normal: -hit.normal,
+                    contact: hit.contact,
+                    hint: ResolutionHint::default(),
+                    impact_speed: 0.0,
+                })
+            }
+            (ColliderKind::Point, ColliderKind::Circle { radius: r }) => {
+                Narrowphase::sweep_circle_circle(a.desc.center, 0.0, da, b.desc.center, r, db)
+            }
+            (ColliderKind::Circle { radius: r }, ColliderKind::Point) => {
+                let hit =
+                    Narrowphase::sweep_circle_circle(b.desc.center, 0.0, db, a.desc.center, r, da)?;
+                Some(SweepHit {
+                    toi: hit.toi,
+                    normal: -hit.normal,
+                    contact: hit.contact,
+                    hint: ResolutionHint::default(),
+                    impact_speed: 0.0,
+                })
+            }
+            (ColliderKind::Point, ColliderKind::Point) if (da - db).length_squared() > 0.0 => {
+                // Both must reach the crossing together: `a`'s relative motion against `b`
+                // grown to `point_eps`, as in point-point overlaps
+                let rel = da - db;
+                let mut hit = Narrowphase::sweep_circle_circle(
+                    a.desc.center,
+                    0.0,
+                    da,
+                    b.desc.center,
+                    self.cfg.point_eps,
+                    db,
+                )?;
+                if hit.normal == Vec2::ZERO {
+                    hit.normal = -rel.normalize_or_zero();
+                }
+                hit.contact = a.desc.center + da * hit.toi;
+                Some(hit)
+            }
+            (ColliderKind::Point, ColliderKind::Point) => {
+                // Moving together: they hit at the start iff they already coincide
+                let delta = a.desc.center - b.desc.center;
+                (delta.length() <= self.cfg.point_eps).then(|| SweepHit {
+                    toi: 0.0,
+                    normal: delta.normalize_or_zero(),
+                    contact: a.desc.center,
+                    hint: ResolutionHint::default(),
+                    impact_speed: 0.0,
+                })
+            }
+        }?;
+        hit.hint.slide = Some(Self::slide_along(da - db, hit.toi, hit.normal));
+        let a_at_hit = a.desc.center + da * hit.toi;
+        hit.hint.safe_pos = Some(a_at_hit + hit.normal * self.cfg.skin_width);
+        hit.hint.start_embedded = hit.toi == 0.0;
+        Some(hit)
+    }
+
+    /// Times in `[0, 1]` during which `a` moving by `rel` relative to `b` overlaps it: the
+    /// Minkowski box or circle around `b` crossed by `a`'s center (circle-box uses the box
+    /// grown by the radius, like `sweep_circle_aabb`).
+    fn overlap_interval(&self, a: &Entry, b: &Entry, rel: Vec2) -> Option<(f32, f32)> {
+        use crate::narrowphase::{circle_interval, slab_interval};
+        let radius = |e: &Entry| match e.desc.kind {
+            ColliderKind::Circle { radius } => Some(radius),
+            ColliderKind::Point => Some(0.0),
+            ColliderKind::Aabb { .. } => None,
+        };
+        let (enter, exit) = match (radius(a), radius(b)) {
+            (Some(ra), Some(rb)) => {
+                let points = matches!(
+                    (a.desc.kind, b.desc.kind),
+                    (ColliderKind::Point, ColliderKind::Point)
+                );
+                let r = if points { self.cfg.point_eps } else { ra + rb };
+                circle_interval(a.desc.center, rel, b.desc.center, r)?
+            }
+            _ => {
+                let grow = Self::entry_half_extents(a) + Self::entry_half_extents(b);
+                slab_interval(
+                    a.desc.center,
+                    rel,
+                    b.desc.center - grow,
+                    b.desc.center + grow,
+                )?
+            }
+        };
+        (exit >= 0.0 && enter <= 1.0).then(|| (enter.max(0.0), exit.min(1.0)))
+    }
+
+    /// Sweep and/or overlap events for a pair, selected by `overlap_policy`.
+    fn pair_events(&self, ea: &Entry, eb: &Entry, a: BodyRef, b: BodyRef) -> [Option<Event>; 2] {
+        // Discrete mode: overlaps at current positions only, no velocity math
+        let rel = if self.sweeps_enabled() {
+            self.frame_vel(&ea.motion) - self.frame_vel(&eb.motion)
+        } else {
+            Vec2::ZERO
+        };
+        let dynamic = rel.length_squared() > 1e-12;
+        let pair_id = Self::pair_id(ea.desc.user_key, a, eb.desc.user_key, b);
+        let round = Self::is_round(ea.desc.kind) || Self::is_round(eb.desc.kind);
+        let event = |kind, overlap, sweep| Event {
+            kind,
+            a,
+            b,
+            a_key: ea.desc.user_key,
+            b_key: eb.desc.user_key,
+            a_layer: ea.desc.mask.layer,
+            b_layer: eb.desc.mask.layer,
+            a_tag: ea.desc.tag,
+            b_tag: eb.desc.tag,
+            pair_id,
+            contact_id: Self::contact_id(pair_id, overlap, sweep, round),
+            overlap,
+            sweep,
+        };
+        let sweep = if dynamic {
+            self.sweep_entries(ea, eb).map(|mut hit| {
+                hit.impact_speed = rel.dot(-hit.normal);
+                event(EventKind::Sweep, None, Some(hit))
+            })
+        } else {
+            None
+        };
+        let overlap = if self.overlaps_enabled(sweep.is_some()) {
+            self.overlap_entries(ea, eb).map(|mut ov| {
+                // Overlaps are tested at the start positions, so any penetration predates motion
+                ov.hint = ResolutionHint {
+                    start_embedded: ov.depth > 0.0,
+                    ..ResolutionHint::default()
+                };
+                event(EventKind::Overlap, Some(ov), None)
+            })
+        } else {
+            None
+        };
+        [sweep, overlap]
+    }
+
+    /// Tile events for collider `e` (as `a`), passed to `emit` until it returns false: the
+    /// first tile sweep hit, then the first start-embedded tile overlap (every one with
+    /// `tile_events_per_cell`), each gated like `pair_events`.
+    fn tile_events(&self, e: &Entry, a: BodyRef, emit: &mut dyn FnMut(Event) -> bool) {
+        use crate::narrowphase::Narrowphase;
+        let he = match e.desc.kind {
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+            ColliderKind::Point => Vec2::ZERO,
+        };
+        let mask_a = e.desc.mask;
+        // Discrete mode never looks at motion
+        let d = if self.sweeps_enabled() {
+            self.displacement(&e.motion)
+        } else {
+            Vec2::ZERO
+        };
+        // A resting collider can still be swept into by a moving map
+        let moving = self.sweeps_enabled() && self.tilemaps.iter().any(|m| m.vel != Vec2::ZERO);
+        let mut sweep = None;
+        if (d.length_squared() > 1e-12 || moving)
+            && let Some((tref, mut hit, key_b)) =
+                self.sweep_kind_tiles(e.desc.kind, e.desc.center, d, mask_a, self.cfg.dt)
+        {
+            hit.impact_speed =
+                (self.frame_vel(&e.motion) - self.tile_map_vel(tref)).dot(-hit.normal);
+            sweep = Some(self.tile_event(e, a, tref, key_b, None, Some(hit)));
+        }
+        if let Some(ev) = sweep
+            && !emit(ev)
+        {
+            return;
+        }
+        if !self.overlaps_enabled(sweep.is_some()) {
+            return;
+        }
+        // Check start embedded
+        let mut open = true;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !open {
+                break;
+            }
+            if !self.allows_pair(mask_a, m.mask) {
+                continue;
+            }
+            self.visit_tile_overlaps(mi, m, e.desc.center, he, |tref| {
+                // Build overlap with pushout hint
+                let cell = m.cell.max(1e-5);
+                let tile_min = m.tile_min(tref.cell_xy.x as i32, tref.cell_xy.y as i32);
+                let (normal, depth, contact) = if he == Vec2::ZERO {
+                    Narrowphase::circle_tile_pushout(e.desc.center, 0.0, tile_min, cell)
+                } else if he.x == he.y {
+                    // treat as circle for simplicity when square
+                    Narrowphase::circle_tile_pushout(e.desc.center, he.x, tile_min, cell)
+                } else {
+                    Narrowphase::aabb_tile_pushout(e.desc.center, he, tile_min, cell)
+                };
+                let mut ov = Overlap {
+                    normal,
+                    depth,
+                    contact,
+                    hint: ResolutionHint::default(),
+                    feature: ContactFeature::from_box_normal(normal),
+                };
+                ov.hint.start_embedded = true;
+                open = emit(self.tile_event(e, a, tref, m.user_key, Some(ov), None))
+                    && self.cfg.tile_events_per_cell;
+                open
+            });
+        }
+    }
+
+    /// Event between collider `e` (as `a`) and tile `tref`; the kind follows the payload.
+    fn tile_event(
+        &self,
+        e: &Entry,
+        a: BodyRef,
+        tref: TileRef,
+        b_key: Option<ColKey>,
+        overlap: Option<Overlap>,
+        sweep: Option<SweepHit>,
+    ) -> Event {
+        let b = BodyRef::Tile(tref);
+        let pair_id = Self::pair_id(e.desc.user_key, a, b_key, b);
+        Event {
+            kind: if sweep.is_some() {
+                EventKind::Sweep
+            } else {
+                EventKind::Overlap
+            },
+            a,
+            b,
+            a_key: e.desc.user_key,
+            b_key,
+            a_layer: e.desc.mask.layer,
+            b_layer: self.tilemaps[tref.map.0 as usize].mask.layer,
+            a_tag: e.desc.tag,
+            b_tag: 0,
+            pair_id,
+            contact_id: Self::contact_id(pair_id, overlap, sweep, Self::is_round(e.desc.kind)),
+            overlap,
+            sweep,
+        }
+    }
+
+    /// `pair_id` mixed with the contact's feature. Sweeps use the box feature facing the
+    /// normal, or a single `Round` feature when a round shape is involved (its normal turns
+    /// continuously, so faces and corners would flicker between frames).
+    fn contact_id(
+        pair_id: u64,
+        overlap: Option<Overlap>,
+        sweep: Option<SweepHit>,
+        round: bool,
+    ) -> u64 {
+        let feature = match (overlap, sweep) {
+            (Some(ov), _) => ov.feature,
+            (None, Some(_)) if round => ContactFeature::Round,
+            (None, Some(hit)) => ContactFeature::from_box_normal(hit.normal),
+            (None, None) => ContactFeature::None,
+        };
+        let x = (pair_id ^ feature.code()).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        x ^ (x >> 29)
+    }
+
+    fn is_round(kind: ColliderKind) -> bool {
+        matches!(kind, ColliderKind::Circle { .. } | ColliderKind::Point)
+    }
+
+    /// Order-independent id for a pair: keys when present, else body handles. Tiles
+    /// always include their cell since the key is shared by the whole map.
+    fn pair_id(a_key: Option<ColKey>, a: BodyRef, b_key: Option<ColKey>, b: BodyRef) -> u64 {
+        // splitmix64 finalizer
+        fn mix(mut x: u64) -> u64 {
+            x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            x ^ (x >> 31)
+        }
+        let token = |key: Option<ColKey>, body: BodyRef| match (key, body) {
+            (_, BodyRef::Tile(t)) => {
+                let map = key.unwrap_or(3 << 62 | t.map.0 as u64);
+                mix(map) ^ mix((t.cell_xy.x as u64) << 32 | t.cell_xy.y as u64).rotate_left(17)
+            }
+            (Some(k), _) => mix(k),
+            (None, BodyRef::Collider(id)) => mix(1 << 62 | id.0 as u64),
+            (None, BodyRef::Static(id)) => mix(2 << 62 | id.0 as u64),
+        };
+        let (ta, tb) = (token(a_key, a), token(b_key, b));
+        mix(ta.min(tb).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ ta.max(tb))
+    }
+
+    /// False in the discrete (overlap-only) mode, which then ignores velocity entirely.
+    fn sweeps_enabled(&self) -> bool {
+        self.cfg.enable_sweep_events && self.cfg.overlap_policy != OverlapPolicy::OverlapOnly
+    }
+
+    /// Whether to look for an overlap given that a sweep event was (or wasn't) emitted.
+    fn overlaps_enabled(&self, swept: bool) -> bool {
+        self.cfg.enable_overlap_events
+            && match self.cfg.overlap_policy {
+                OverlapPolicy::SweepOnly => false,
+                OverlapPolicy::OverlapOnly | OverlapPolicy::Both => true,
+                OverlapPolicy::SweepThenOverlapFallback => !swept,
+            }
+    }
+
+    /// Reorder overlap events deepest-first (stable on ties) within the slots they already
+    /// occupy, so sweep events keep their positions.
+    fn sort_overlaps_by_depth(events: &mut [Event]) {
+        let slots: Vec<usize> = (0..events.len())
+            .filter(|&i| matches!(events[i].kind, EventKind::Overlap))
+            .collect();
+        let mut overlaps: Vec<Event> = slots.iter().map(|&i| events[i]).collect();
+        let depth = |e: &Event| e.overlap.map_or(0.0, |o| o.depth);
+        overlaps.sort_by(|a, b| depth(b).total_cmp(&depth(a)));
+        for (slot, ev) in slots.into_iter().zip(overlaps) {
+            events[slot] = ev;
+        }
+    }
+
+    /// Remaining displacement `d * (1 - toi)` with its component along `normal` removed.
+    fn slide_along(d: Vec2, toi: f32, normal: Vec2) -> Vec2 {
+        let rem = d * (1.0 - toi);
+        rem - normal * rem.dot(normal)
+    }
+
+    /// Return debug/perf stats for the current built frame.
+    pub fn debug_stats(&self) -> WorldStats {
+        let entries = self.entries.len();
+        let cells = self.grid.len();
+        let mut candidate_pairs: usize = 0;
+        let mut seen: FxHashSet<(usize, usize)> = FxHashSet::default();
+        for v in self.grid.values() {
+            let n = v.len();
+            if n >= 2 {
+                candidate_pairs += n * (n - 1) / 2;
+            }
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    let a = v[i];
+                    let b = v[j];
+                    let key = if a < b { (a, b) } else { (b, a) };
+                    seen.insert(key);
+                }
+            }
+        }
+        if self.cfg.broadphase != Broadphase::Grid {
+            // BVH/SAP pairs are already unique
+            let mut unique_pairs = 0;
+            let _ = self.for_each_candidate_pair(|_, _| {
+                unique_pairs += 1;
+                ControlFlow::Continue(())
+            });
+            return WorldStats {
+                entries,
+                cells,
+                invalid_entries: self.invalid.len(),
+                mutual_consent_rejections: self.consent_rejections.load(Ordering::Relaxed),
+                candidate_pairs: unique_pairs,
+                unique_pairs,
+                bvh_nodes: self.bvh.node_count(),
+                bvh_max_depth: self.bvh.max_depth(),
+            };
+        }
+        WorldStats {
+            entries,
+            cells,
+            candidate_pairs,
+            unique_pairs: seen.len(),
+            invalid_entries: self.invalid.len(),
+            mutual_consent_rejections: self.consent_rejections.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    /// Colliders skipped by `end_frame` because their AABB is not finite (NaN center,
+    /// infinite extents or velocity). They never appear in pairs, queries, or raycasts.
+    pub fn invalid_colliders(&self) -> Vec<FrameId> {
+        self.invalid.iter().map(|&i| FrameId(i as u32)).collect()
+    }
+
+    /// `debug_stats` plus the busiest grid cell, reproducible across runs for CI assertions.
+    pub fn debug_stats_detailed(&self) -> WorldStatsDetailed {
+        let mut max_cell_occupancy = 0;
+        let mut busiest_cell = None;
+        for (&cell, list) in &self.grid {
+            let n = list.len();
+            let better = n > max_cell_occupancy
+                || (n == max_cell_occupancy && busiest_cell.is_some_and(|b| cell < b));
+            if better {
+                max_cell_occupancy = n;
+                busiest_cell = Some(cell);
+            }
+        }
+        WorldStatsDetailed {
+            stats: self.debug_stats(),
+            max_cell_occupancy,
+            busiest_cell,
+        }
+    }
+
+    /// Capacities of the frame-local buffers, for spotting growth that never shrinks.
+    pub fn memory_stats(&self) -> MemoryStats {
+        MemoryStats {
+            entries_cap: self.entries.capacity(),
+            aabbs_cap: self.aabbs.capacity(),
+            grid_buckets: self.grid.capacity(),
+            grid_bucket_cap_sum: self.grid.values().map(Vec::capacity).sum(),
+            events_cap: self.events.capacity(),
+        }
+    }
+
+    /// Dynamic grid occupancy: index `i` counts cells holding `i` colliders, with the last
+    /// bucket collecting everything at or above the cap. Bucket 0 counts empty cells inside
+    /// the occupied grid bounds. All zeros with a non-grid broadphase or no colliders.
+    pub fn grid_occupancy_histogram(&self) -> Vec<usize> {
+        let mut hist = vec![0usize; GRID_HISTOGRAM_BUCKETS];
+        let Some(((x0, y0), (x1, y1))) = self.grid_bounds else {
+            return hist;
+        };
+        let mut occupied = 0usize;
+        for list in self.grid.values().filter(|l| !l.is_empty()) {
+            hist[list.len().min(GRID_HISTOGRAM_BUCKETS - 1)] += 1;
+            occupied += 1;
+        }
+        let area = (i64::from(x1) - i64::from(x0) + 1) * (i64::from(y1) - i64::from(y0) + 1);
+        hist[0] = usize::try_from(area)
+            .unwrap_or(usize::MAX)
+            .saturating_sub(occupied);
+        hist
+    }
+
+    /// Re-run the event decision for one pair: mask consent, broadphase, relative velocity,
+    /// which branches ran and what they found. Results match the events `generate_events`
+    /// would emit for the pair (`a` first); this ignores `max_events`.
+    pub fn explain_pair(&self, a: FrameId, b: FrameId) -> PairExplanation {
+        let (ai, bi) = (a.0 as usize, b.0 as usize);
+        let (Some(ea), Some(eb)) = (self.entries.get(ai), self.entries.get(bi)) else {
+            return PairExplanation {
+                miss: Some(PairMiss::UnknownId),
+                ..PairExplanation::default()
+            };
+        };
+        let finite = |i: usize| self.aabbs[i].0.is_finite() && self.aabbs[i].1.is_finite();
+        let key = (ai.min(bi), ai.max(bi));
+        let mut out = PairExplanation {
+            mask_allowed: self.allows_pair(ea.desc.mask, eb.desc.mask),
+            broadphase_candidate: ai != bi
+                && self
+                    .for_each_candidate_pair(|a, b| {
+                        if (a, b) == key {
+                            ControlFlow::Break(())
+                        } else {
+                            ControlFlow::Continue(())
+                        }
+                    })
+                    .is_break(),
+            rel_vel: if self.sweeps_enabled() {
+                self.frame_vel(&ea.motion) - self.frame_vel(&eb.motion)
+            } else {
+                Vec2::ZERO
+            },
+            ..PairExplanation::default()
+        };
+        // `generate_events` drops the pair before any narrowphase when either side opts out
+        let targeted = ea.desc.event_targets.colliders() && eb.desc.event_targets.colliders();
+        if targeted {
+            out.sweep_tested = out.rel_vel.length_squared() > 1e-12;
+            let [sweep, overlap] =
+                self.pair_events(ea, eb, BodyRef::Collider(a), BodyRef::Collider(b));
+            out.sweep = sweep.and_then(|ev| ev.sweep).map(|mut hit| {
+                hit.scale_toi(self.toi_scale());
+                hit
+            });
+            out.overlap_tested = self.overlaps_enabled(out.sweep.is_some());
+            out.overlap = overlap.and_then(|ev| ev.overlap);
+        }
+        out.miss = if !(finite(ai) && finite(bi)) {
+            Some(PairMiss::InvalidBounds)
+        } else if !out.broadphase_candidate {
+            Some(PairMiss::NotCandidates)
+        } else if !out.mask_allowed {
+            Some(PairMiss::MaskRejected)
+        } else if !targeted {
+            Some(PairMiss::TargetsExcluded)
+        } else if !out.sweep_tested && !out.overlap_tested {
+            Some(PairMiss::EventsDisabled)
+        } else if out.sweep.is_none() && out.overlap.is_none() {
+            Some(PairMiss::NoContact)
+        } else {
+            None
+        };
+        out
+    }
+
+    /// Broadphase load for one collider: (grid cells occupied, distinct neighbors across them).
+    /// With the BVH/SAP broadphase, cells is 0 and neighbors are broadphase candidates.
+    /// Global colliders report (0, every other collider).
+    /// Unknown ids return (0, 0).
+    pub fn collider_broadphase_load(&self, id: FrameId) -> (usize, usize) {
+        let idx = id.0 as usize;
+        let Some(&(min, max)) = self.aabbs.get(idx) else {
+            return (0, 0);
+        };
+        if self.is_global(idx) {
+            return (0, self.entries.len() - 1);
+        }
+        if self.cfg.broadphase != Broadphase::Grid {
+            let mut neighbors = 0;
+            self.view_candidates(self.dynamic_view(), min, max, |j| {
+                neighbors += usize::from(j != idx)
+            });
+            return (0, neighbors);
+        }
+        let cs = self.cfg.cell_size.max(1e-5);
+        let (ix0, iy0) = self.world_to_cell(min, cs);
+        let (ix1, iy1) = self.world_to_cell(max, cs);
+        let mut cells = 0;
+        let mut neighbors = FxHashSet::default();
+        for iy in iy0..=iy1 {
+            for ix in ix0..=ix1 {
+                if let Some(list) = self.grid.get(&(ix, iy)) {
+                    cells += 1;
+                    neighbors.extend(list.iter().copied().filter(|&j| j != idx));
+                }
+            }
+        }
+        (cells, neighbors.len())
+    }
+
+    /// Timing breakdown of the most recently completed timed `generate_events`, including
+    /// that frame's `end_frame` if it was timed too. It is kept across frames, so compare
+    /// `WorldTiming::frame_counter` with `frame_counter` to spot a stale reading. None until
+    /// a `generate_events` runs with `enable_timing`.
+    pub fn timing(&self) -> Option<WorldTiming> {
+        self.completed_timing
+    }
+
+    fn allows_pair(&self, a: LayerMask, b: LayerMask) -> bool {
+        if self.cfg.require_mutual_consent {
+            let (ab, ba) = (a.allows(b), b.allows(a));
+            if ab != ba {
+                self.consent_rejections.fetch_add(1, Ordering::Relaxed);
+            }
+            ab && ba
+        } else {
+            a.allows(b) || b.allows(a)
+        }
+    }
+
+    fn tile_at(m: &TileMap, ix: i32, iy: i32) -> Option<usize> {
+        if ix < 0 || iy < 0 {
+            return None;
+        }
+        let ux = ix as u32;
+        let uy = iy as u32;
+        if ux >= m.width || uy >= m.height {
+            return None;
+        }
+        Some((uy * m.width + ux) as usize)
+    }
+
+    /// All consenting solid tiles under the `he` box at `center`, resolved with `pushout`
+    /// (`tile_min, cell -> (normal, depth, contact)`); negative depth means no overlap.
+    fn tile_overlaps_with(
+        &self,
+        center: Vec2,
+        he: Vec2,
+        mask: LayerMask,
+        pushout: impl Fn(Vec2, f32) -> (Vec2, f32, Vec2),
+    ) -> Vec<(TileRef, Overlap)> {
+        let mut out = Vec::new();
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
+            }
+            self.visit_tile_overlaps(mi, m, center, he, |tref| {
+                let tile_min = m.tile_min(tref.cell_xy.x as i32, tref.cell_xy.y as i32);
+                let (normal, depth, contact) = pushout(tile_min, m.cell.max(1e-5));
+                if depth >= 0.0 {
+                    let hint = ResolutionHint {
+                        start_embedded: depth > 0.0,
+                        ..ResolutionHint::default()
+                    };
+                    out.push((
+                        tref,
+                        Overlap {
+                            normal,
+                            depth,
+                            contact,
+                            hint,
+                            feature: ContactFeature::from_box_normal(normal),
+                        },
+                    ));
+                }
+                true
+            });
+        }
+        out
+    }
+
+    /// Call `f` for each solid tile of `m` overlapping the AABB; stop early when `f` returns false.
+    fn visit_tile_overlaps(
+        &self,
+        mi: usize,
+        m: &TileMap,
+        center: Vec2,
+        he: Vec2,
+        mut f: impl FnMut(TileRef) -> bool,
+    ) {
+        let cell = m.cell.max(1e-5);
+        let Some((ix0, iy0, ix1, iy1)) = m.cell_range(center - he, center + he) else {
+            return;
+        };
+        for iy in iy0..=iy1 {
+            for ix in ix0..=ix1 {
+                if let Some(idx) = Self::tile_at(m, ix, iy)
+                    && m.is_solid(idx)
+                {
+                    let tile_min = m.tile_min(ix, iy);
+                    // quick overlap check: AABB vs tile AABB
+                    let tile_c = tile_min + Vec2::splat(cell * 0.5);
+                    let tile_h = Vec2::splat(cell * 0.5);
+                    if crate::narrowphase::Narrowphase::overlap_aabb_aabb(
+                        center, he, tile_c, tile_h,
+                    )
+                    .is_some()
+                        && !f(TileRef {
+                            map: TileMapRef(mi as u32),
+                            cell_xy: glam::UVec2::new(ix as u32, iy as u32),
+                        })
+                    {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Face normals of the solid tiles touching an AABB at the first TOI of its sweep, passed
+    /// to `add` until it returns false. Only exposed faces that oppose the motion are
+    /// reported unless `all` asks for every touching cell; with none, the sweep's own hit is.
+    fn sweep_tile_contacts(
+        &self,
+        center: Vec2,
+        he: Vec2,
+        vel: Vec2,
+        mask: LayerMask,
+        all: bool,
+        mut add: impl FnMut(TileRef, Vec2) -> bool,
+    ) -> Option<f32> {
+        let d = vel * self.cfg.dt;
+        let (first_tile, first, _) = self.sweep_shape_tiles(center, he, d, mask, self.cfg.dt)?;
+        // Contacts reached within the sweep's backoff distance count as simultaneous.
+        let slop = self.cfg.tile_eps.max(1e-6) * d.length().max(1.0);
+        let mut any = false;
+        let mut open = true;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !open {
+                break;
+            }
+            if !self.allows_pair(mask, m.mask) {
+                continue;
+            }
+            // Position and motion in the map's own frame
+            let d = d - m.vel * self.cfg.dt;
+            let p = center + d * first.toi;
+            let cell = m.cell.max(1e-5);
+            self.visit_tile_overlaps(mi, m, p, he + Vec2::splat(slop), |tref| {
+                let (ix, iy) = (tref.cell_xy.x as i32, tref.cell_xy.y as i32);
+                let tile_min = m.tile_min(ix, iy);
+                let (n, _depth, _contact) =
+                    crate::narrowphase::Narrowphase::aabb_tile_pushout(p, he, tile_min, cell);
+                if n.length_squared() == 0.0 {
+                    return true;
+                }
+                if !all {
+                    if n.dot(d) >= 0.0 {
+                        return true;
+                    }
+                    // A face shared with another solid cell is internal, not a contact surface.
+                    let (nx, ny) = (n.x.round() as i32, n.y.round() as i32);
+                    if let Some(idx) = Self::tile_at(m, ix + nx, iy + ny)
+                        && m.is_solid(idx)
+                    {
+                        return true;
+                    }
+                }
+                any = true;
+                open = add(tref, n);
+                open
+            });
+        }
+        if !any {
+            add(first_tile, first.normal);
+        }
+        Some(first.toi)
+    }
+
+    /// Sweep an AABB (`he`) from `center` by displacement `d` against all tilemaps.
+    fn sweep_shape_tiles(
+        &self,
+        center: Vec2,
+        he: Vec2,
+        d: Vec2,
+        mask: LayerMask,
+        map_dt: f32,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let (tref, mut hit, key) = self.sweep_tiles_with(
+            center,
+            he,
+            d,
+            mask,
+            map_dt,
+            |_, _, _| true,
+            |p, tile_min, cell| {
+                crate::narrowphase::Narrowphase::aabb_tile_pushout(p, he, tile_min, cell)
+            },
+        )?;
+        // Faces crossed: the gap closes from the last free sample (apart or touching) to the hit
+        let (tile_min, tile_max) = self.tile_aabb(tref)?;
+        // Where the map has moved to by the hit, like the translated hit positions
+        let shift = self.tilemaps[tref.map.0 as usize].vel * map_dt * hit.toi;
+        let (tile_min, tile_max) = (tile_min + shift, tile_max + shift);
+        let gap = |c: Vec2| (tile_min - (c + he)).max((c - he) - tile_max);
+        let free = hit.hint.last_free_pos.map_or(Vec2::NEG_INFINITY, gap);
+        let hit_gap = gap(center + d * hit.toi);
+        let crossed = |f: f32, h: f32| f >= 0.0 && h <= 0.0 && f > h;
+        hit.hint.contact_axis = match (crossed(free.x, hit_gap.x), crossed(free.y, hit_gap.y)) {
+            (true, true) => Some(Axis::Both),
+            (true, false) => Some(Axis::X),
+            (false, true) => Some(Axis::Y),
+            (false, false) => None,
+        };
+        Some((tref, hit, key))
+    }
+
+    /// Velocity of the map `t` belongs to (zero if it's gone).
+    fn tile_map_vel(&self, t: TileRef) -> Vec2 {
+        self.tilemaps
+            .get(t.map.0 as usize)
+            .map_or(Vec2::ZERO, |m| m.vel)
+    }
+
+    /// Bounds `(center, half_extents)` of a polygon; None for fewer than 3 points.
+    fn polygon_bounds(points: &[Vec2]) -> Option<(Vec2, Vec2)> {
+        if points.len() < 3 {
+            return None;
+        }
+        let (min, max) = points
+            .iter()
+            .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(lo, hi), &p| {
+                (lo.min(p), hi.max(p))
+            });
+        Some(((min + max) * 0.5, (max - min) * 0.5))
+    }
+
+    /// Whether `shape` at `center` moves by `d` without a hit. Candidates stop being swept
+    /// once one blocks, and the tile sweep only runs if a solid tile is near the path.
+    fn path_clear(&self, shape: ColliderKind, center: Vec2, d: Vec2, mask: LayerMask) -> bool {
+        let shape = shape.normalized();
+        let probe = Entry {
+            desc: ColliderDesc {
+                kind: shape,
+                center,
+                mask,
+                user_key: None,
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            },
+            motion: Motion::default(),
+        };
+        let (min, max) = Self::bounds_over(shape, center, d, true);
+        let mut blocked = false;
+        for view in [self.dynamic_view(), self.static_view()] {
+            self.view_candidates(view, min, max, |idx| {
+                let e = &view.entries[idx];
+                blocked = blocked
+                    || (mask.allows(e.desc.mask)
+                        && e.desc.mask.allows(mask)
+                        && self.sweep_moving(&probe, d, e, Vec2::ZERO).is_some());
+            });
+            if blocked {
+                return false;
+            }
+        }
+        let mut near_solid = false;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if self.allows_pair(mask, m.mask) {
+                // The path in the map's own frame
+                let (min, max) = Self::bounds_over(shape, center, d - m.vel * self.cfg.dt, true);
+                let (c, he) = ((min + max) * 0.5, (max - min) * 0.5);
+                self.visit_tile_overlaps(mi, m, c, he, |_| {
+                    near_solid = true;
+                    false
+                });
+            }
+            if near_solid {
+                return self
+                    .sweep_kind_tiles(shape, center, d, mask, self.cfg.dt)
+                    .is_none();
+            }
+        }
+        true
+    }
+
+    /// Tile sweep for a collider shape: circles use the exact rounded-tile TOI unless
+    /// `square_circle_tile_sweep` is set; everything else sweeps its bounding box.
+    fn sweep_kind_tiles(
+        &self,
+        kind: ColliderKind,
+        center: Vec2,
+        d: Vec2,
+        mask: LayerMask,
+        map_dt: f32,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let he = match kind {
+            ColliderKind::Circle { radius } if !self.cfg.square_circle_tile_sweep => {
+                return self.sweep_circle_tiles_exact(center, radius, d, mask, map_dt);
+            }
+            ColliderKind::Circle { radius } => Vec2::splat(radius),
+            ColliderKind::Aabb { half_extents } => half_extents,
+            ColliderKind::Point => Vec2::ZERO,
+        };
+        self.sweep_shape_tiles(center, he, d, mask, map_dt)
+    }
+
+    /// Earliest hit of a circle moving by `d` against every solid tile under its swept
+    /// bounds, across all allowed maps (ties keep the lower map index). Each map is swept
+    /// in its own frame, moving by `vel * map_dt`.
+    fn sweep_circle_tiles_exact(
+        &self,
+        center: Vec2,
+        r: f32,
+        d: Vec2,
+        mask: LayerMask,
+        map_dt: f32,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let he = Vec2::splat(r);
+        let mut best: Option<(TileRef, f32, Vec2, Option<ColKey>, Vec2)> = None;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
+            }
+            let d = d - m.vel * map_dt;
+            // No relative motion: nothing to sweep (resting contacts are overlaps)
+            if d.length_squared() <= 1e-12 {
+                continue;
+            }
+            let min = center.min(center + d) - he;
+            let max = center.max(center + d) + he;
+            let cell = m.cell.max(1e-5);
+            self.visit_tile_overlaps(mi, m, (min + max) * 0.5, (max - min) * 0.5, |t| {
+                let tile_min = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32);
+                if let Some((toi, n)) = Self::circle_tile_toi(center, r, d, tile_min, cell)
+                    && best.is_none_or(|b| toi < b.1)
+                {
+                    best = Some((t, toi, n, m.user_key, m.vel * map_dt));
+                }
+                true
+            });
+        }
+        let (tref, toi, normal, key, map_d) = best?;
+        let d = d - map_d;
+        let mut hit = SweepHit {
+            toi,
+            normal,
+            contact: center + d * toi - normal * r,
+            hint: ResolutionHint::default(),
+            impact_speed: 0.0,
+        };
+        hit.hint.safe_pos = Some(center + d * toi + normal * self.cfg.skin_width);
+        hit.hint.slide = Some(Self::slide_along(d, toi, normal));
+        hit.hint.start_embedded = toi == 0.0;
+        hit.translate(map_d * toi);
+        Some((tref, hit, key))
+    }
+
+    /// Exact TOI of a circle moving by `d` against one tile: a ray against the tile's
+    /// Minkowski sum with the circle (box faces plus rounded corners). Starting overlaps hit
+    /// at 0; grazing contacts (`d` not moving into the normal) are ignored.
+    fn circle_tile_toi(c: Vec2, r: f32, d: Vec2, tile_min: Vec2, cell: f32) -> Option<(f32, Vec2)> {
+        let tile_max = tile_min + Vec2::splat(cell);
+        let (n0, depth, _) =
+            crate::narrowphase::Narrowphase::circle_tile_pushout(c, r, tile_min, cell);
+        if depth >= 0.0 {
+            return (depth > 0.0 || d.dot(n0) < 0.0).then_some((0.0, n0));
+        }
+        // Slabs of the tile expanded by r
+        let (min, max) = (tile_min - Vec2::splat(r), tile_max + Vec2::splat(r));
+        let (mut t0, mut t1, mut normal) = (0.0f32, 1.0f32, Vec2::ZERO);
+        for axis in 0..2 {
+            if d[axis] == 0.0 {
+                if c[axis] < min[axis] || c[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let (a, b) = (
+                (min[axis] - c[axis]) / d[axis],
+                (max[axis] - c[axis]) / d[axis],
+            );
+            let mut n = Vec2::ZERO;
+            n[axis] = -d[axis].signum();
+            if a.min(b) > t0 {
+                t0 = a.min(b);
+                normal = n;
+            }
+            t1 = t1.min(a.max(b));
+            if t0 > t1 {
+                return None;
+            }
+        }
+        // Entry point in a corner square: the real boundary there is the corner's circle
+        let p = c + d * t0;
+        let side = |v: f32, lo: f32, hi: f32| {
+            if v < lo {
+                Some(lo)
+            } else if v > hi {
+                Some(hi)
+            } else {
+                None
+            }
+        };
+        if let (Some(x), Some(y)) = (
+            side(p.x, tile_min.x, tile_max.x),
+            side(p.y, tile_min.y, tile_max.y),
+        ) {
+            let corner = Vec2::new(x, y);
+            let m = c - corner;
+            let (a, b, k) = (d.dot(d), m.dot(d), m.dot(m) - r * r);
+            let disc = b * b - a * k;
+            if disc < 0.0 {
+                return None;
+            }
+            let t = (-b - disc.sqrt()) / a;
+            if !(0.0..=1.0).contains(&t) {
+                return None;
+            }
+            normal = (c + d * t - corner).normalize_or_zero();
+            t0 = t;
+        }
+        (d.dot(normal) < 0.0).then_some((t0, normal))
+    }
+
+    /// Sampled + bisected tile sweep of a shape bounded by `he`. `hits(p, tile_min, cell)`
+    /// confirms a bounds overlap precisely; `pushout` gives (normal, depth, contact) at the hit.
+    /// Each map is swept in its own frame, moving by `vel * map_dt`.
+    #[allow(clippy::too_many_arguments)]
+    fn sweep_tiles_with(
+        &self,
+        center: Vec2,
+        he: Vec2,
+        d: Vec2,
+        mask: LayerMask,
+        map_dt: f32,
+        hits: impl Fn(Vec2, Vec2, f32) -> bool,
+        pushout: impl Fn(Vec2, Vec2, f32) -> (Vec2, f32, Vec2),
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        let mut best: Option<(TileRef, SweepHit, Option<ColKey>)> = None;
+        let p0 = center;
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            if !self.allows_pair(mask, m.mask) {
+                continue;
+            }
+            let map_d = m.vel * map_dt;
+            let d = d - map_d;
+            // No relative motion: nothing to sweep (resting contacts are overlaps)
+            if d.length_squared() <= 1e-12 {
+                continue;
+            }
+            let cell = m.cell.max(1e-5);
+            let first_hit = |q: Vec2| {
+                let mut found = None;
+                self.visit_tile_overlaps(mi, m, q, he, |t| {
+                    let tile_min = m.tile_min(t.cell_xy.x as i32, t.cell_xy.y as i32);
+                    if hits(q, tile_min, cell) {
+                        found = Some(t);
+                    }
+                    found.is_none()
+                });
+                found
+            };
+            let len = d.length();
+            let steps_f = ((len / cell).ceil().max(1.0)) * 2.0;
+            let steps = steps_f as i32;
+            let mut t_prev = 0.0f32;
+            // Only positions `first_hit` has rejected; `p0` itself is never sampled
+            let mut prev_free: Option<Vec2> = None;
+            let tref_hit: Option<TileRef>;
+            for i in 1..=steps {
+                let t = (i as f32 / steps_f).min(1.0);
+                let p = p0 + d * t;
+                if let Some(tref) = first_hit(p) {
+                    tref_hit = Some(tref);
+                    // binary search refine
+                    let mut lo = t_prev;
+                    let mut hi = t;
+                    for _ in 0..14 {
+                        let mid = 0.5 * (lo + hi);
+                        let q = p0 + d * mid;
+                        if first_hit(q).is_some() {
+                            hi = mid;
+                        } else {
+                            lo = mid;
+                            prev_free = Some(q);
+                        }
+                    }
+                    let toi = hi;
+                    let toi_error = hi - lo;
+                    let p_hit = p0 + d * toi;
+                    let tr = tref_hit.unwrap();
+                    let tile_min = m.tile_min(tr.cell_xy.x as i32, tr.cell_xy.y as i32);
+                    let (n, _depth, contact) = pushout(p_hit, tile_min, cell);
+                    let mut hit = SweepHit {
+                        toi,
+                        normal: if n.length_squared() > 0.0 {
+                            n
+                        } else {
+                            (p_hit - prev_free.unwrap_or(p0)).normalize_or_zero()
+                        },
+                        contact,
+                        hint: ResolutionHint::default(),
+                        impact_speed: 0.0,
+                    };
+                    // `toi` is the blocked side of the refine bracket; back off to its free side
+                    hit.hint.safe_pos =
+                        Some(p0 + d * (toi - toi_error) + hit.normal * self.cfg.skin_width);
+                    hit.hint.slide = Some(Self::slide_along(d, toi, hit.normal));
+                    let start_blocked = first_hit(p0).is_some();
+                    hit.hint.last_free_pos = prev_free.or_else(|| (!start_blocked).then_some(p0));
+                    // The refine never reaches exactly 0, so a blocked start counts too
+                    hit.hint.start_embedded = toi == 0.0 || start_blocked;
+                    hit.hint.toi_error = toi_error;
+                    hit.translate(map_d * toi);
+                    best = Some((tr, hit, m.user_key));
+                    break;
+                } else {
+                    t_prev = t;
+                    prev_free = Some(p);
+                }
+            }
+            if best.is_some() {
+                break;
+            }
+        }
+        best
+    }
+
+    // Tile raycast helper
+    fn raycast_tiles_internal(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        max_t: f32,
+        mask: LayerMask,
+    ) -> Option<(TileRef, SweepHit, Option<ColKey>)> {
+        if dir.length_squared() == 0.0 {
+            return None;
+        }
+        let mut best: Option<(TileRef, SweepHit, Option<ColKey>)> = None;
+        let eps = self.cfg.tile_eps.max(1e-6);
+
+        for (mi, m) in self.tilemaps.iter().enumerate() {
+            // Skip maps whose solid cells the ray never reaches
+            let Some((smin, smax)) = m.solid_bounds_world() else {
+                continue;
+            };
+            match crate::narrowphase::slab_interval(origin, dir, smin, smax) {
+                Some((enter, exit)) if exit >= 0.0 && enter <= max_t => {}
+                _ => continue,
+            }
+            let cell = m.cell.max(1e-5);
+            let local = origin - m.origin;
+            let mut cx = (local.x / cell).floor() as i32;
+            let mut cy = (local.y / cell).floor() as i32;
+
+            let step_x = if dir.x > 0.0 {
+                1
+            } else if dir.x < 0.0 {
+                -1
+            } else {
+                0
+            };
+            let step_y = if dir.y > 0.0 {
+                1
+            } else if dir.y < 0.0 {
+                -1
+            } else {
+                0
+            };
+
+            let next_boundary = |c: i32, step: i32| {
+                if step > 0 {
+                    (c as f32 + 1.0) * cell
+                } else {
+                    c as f32 * cell
+                }
+            };
+
+            let mut t_max_x = if step_x != 0 {
+                let nb = m.origin.x + next_boundary(cx, step_x);
+                (nb - origin.x) / dir.x
+            } else {
+                f32::INFINITY
+            };
+
+            let mut t_max_y = if step_y != 0 {
+                let nb = m.origin.y + next_boundary(cy, step_y);
+                (nb - origin.y) / dir.y
+            } else {
+                f32::INFINITY
+            };
+
+            let t_delta_x = if step_x != 0 {
+                cell / dir.x.abs()
+            } else {
+                f32::INFINITY
+            };
+            let t_delta_y = if step_y != 0 {
+                cell / dir.y.abs()
+            } else {
+                f32::INFINITY
+            };
+
+            let mut t_curr = 0.0f32;
+            let mut last_axis_x: Option<bool> = None; // None => starting cell
+
+            for _ in 0..20_000 {
+                if t_curr > max_t {
+                    break;
+                }
+
+                if cx >= 0 && cy >= 0 && (cx as u32) < m.width && (cy as u32) < m.height {
+                    let idx = cy as u32 * m.width + cx as u32;
+                    // Optionally step out of the solid cell the ray starts in
+                    let skip = last_axis_x.is_none() && self.cfg.raycast_skip_start_solid;
+                    if !skip && m.is_solid(idx as usize) && self.allows_pair(mask, m.mask) {
+                        // hit the NEAR face: we entered this cell at t_curr
+                        let toi = t_curr.max(0.0);
+                        let normal = match last_axis_x {
+                            Some(true) => Vec2::new(-(step_x as f32), 0.0),
+                            Some(false) => Vec2::new(0.0, -(step_y as f32)),
+                            None => Vec2::ZERO, // started inside a solid tile
+                        };
+                        // Snap the contact onto the face of the axis that stepped us in
+                        let face =
+                            |c: i32, step: i32| c as f32 * cell + if step < 0 { cell } else { 0.0 };
+                        let mut contact = origin + dir * toi;
+                        match last_axis_x {
+                            Some(true) => contact.x = m.origin.x + face(cx, step_x),
+                            Some(false) => contact.y = m.origin.y + face(cy, step_y),
+                            None => {}
+                        }
+                        let mut hit = SweepHit {
+                            toi,
+                            normal,
+                            contact,
+                            hint: ResolutionHint::default(),
+                            impact_speed: 0.0,
+                        };
+                        hit.hint.safe_pos = Some(origin + dir * (toi - eps));
+                        let tr = TileRef {
+                            map: TileMapRef(mi as u32),
+                            cell_xy: glam::UVec2::new(cx as u32, cy as u32),
+                        };
+                        let key = m.user_key;
+
+                        match &best {
+                            Some((_, bh, _)) if hit.toi >= bh.toi => {}
+                            _ => best = Some((tr, hit, key)),
+                        }
+                        break;
+                    }
+
+                    // Empty cell: jump across the empty run along the dominant axis while the
+                    // ray stays in this row/column. One step is held back so float drift in
+                    // the jumped `t_max` can never carry us past the crossing.
+                    if dir.x.abs() >= dir.y.abs() && t_max_x < t_max_y {
+                        let in_row = ((t_max_y - t_max_x) / t_delta_x) as i64;
+                        let runs = if step_x > 0 {
+                            &m.runs.pos_x
+                        } else {
+                            &m.runs.neg_x
+                        };
+                        let run = match runs.get(idx as usize) {
+                            Some(&run) => run as i64,
+                            None => {
+                                let limit = in_row.clamp(0, u32::MAX as i64) as u32;
+                                let cell_xy = (cx as u32, cy as u32);
+                                m.bit_run(cell_xy, true, step_x, limit) as i64
+                            }
+                        } - 1;
+                        let k = run.min(in_row - 1);
+                        if k > 0 {
+                            cx += step_x * k as i32;
+                            t_curr = t_max_x + t_delta_x * (k - 1) as f32;
+                            t_max_x += t_delta_x * k as f32;
+                            last_axis_x = Some(true);
+                            continue;
+                        }
+                    } else if dir.y.abs() > dir.x.abs() && t_max_y <= t_max_x {
+                        let in_col = ((t_max_x - t_max_y) / t_delta_y) as i64;
+                        let runs = if step_y > 0 {
+                            &m.runs.pos_y
+                        } else {
+                            &m.runs.neg_y
+                        };
+                        let run = match runs.get(idx as usize) {
+                            Some(&run) => run as i64,
+                            None => {
+                                let limit = in_col.clamp(0, u32::MAX as i64) as u32;
+                                let cell_xy = (cx as u32, cy as u32);
+                                m.bit_run(cell_xy, false, step_y, limit) as i64
+                            }
+                        } - 1;
+                        let k = run.min(in_col - 1);
+                        if k > 0 {
+                            cy += step_y * k as i32;
+                            t_curr = t_max_y + t_delta_y * (k - 1) as f32;
+                            t_max_y += t_delta_y * k as f32;
+                            last_axis_x = Some(false);
+                            continue;
+                        }
+                    }
+                }
+
+                // step to next cell; update entry time & axis
+                if t_max_x < t_max_y {
+                    cx += step_x;
+                    t_curr = t_max_x;
+                    t_max_x += t_delta_x;
+                    last_axis_x = Some(true);
+                } else {
+                    cy += step_y;
+                    t_curr = t_max_y;
+                    t_max_y += t_delta_y;
+                    last_axis_x = Some(false);
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg() -> WorldConfig {
+        WorldConfig {
+            cell_size: 1.0,
+            dt: 1.0,
+            tighten_swept_aabb: true,
+            enable_overlap_events: true,
+            enable_sweep_events: true,
+            max_events: 1024,
+            enable_timing: false,
+            tile_eps: 1e-4,
+            require_mutual_consent: true,
+            skin_width: 0.0,
+            broadphase: Broadphase::Grid,
+            overlap_policy: OverlapPolicy::SweepThenOverlapFallback,
+            square_circle_tile_sweep: false,
+            grid_shrink_interval: 0,
+            sort_overlaps_by_depth: false,
+            toi_in_seconds: false,
+            ray_tie_preference: TiePreference::Collider,
+            raycast_skip_start_solid: false,
+            world_bounds: None,
+            point_eps: 0.0,
+            tile_events_per_cell: false,
+            large_collider_cell_threshold: 0,
+            contact_skin: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_push_and_end_frame_grid_coverage() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        // AABB covering from (-0.5,-0.5) to (0.5,0.5)
+        let mask = LayerMask::simple(1, 1);
+        w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        w.end_frame();
+        // With floor indexing, bounds straddling origin cover 4 cells
+        assert_eq!(w.grid.len(), 4);
+        for k in [(-1, -1), (-1, 0), (0, -1), (0, 0)] {
+            assert!(w.grid.contains_key(&k));
+            assert_eq!(w.grid[&k].len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_mask_mutual_consent() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let a_mask = LayerMask {
+            layer: 1,
+            collides_with: 2,
+            exclude: 0,
+        };
+        let b_mask = LayerMask {
+            layer: 2,
+            collides_with: 0,
+            exclude: 0,
+        };
+        w.push_aabb(
+            Vec2::new(-0.5, 0.0),
+            Vec2::splat(0.5),
+            Vec2::new(1.0, 0.0),
+            a_mask,
+            None,
+        );
+        w.push_aabb(
+            Vec2::new(0.5, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            b_mask,
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        assert_eq!(w.drain_events().len(), 0);
+    }
+
+    #[test]
+    fn test_generate_sweep_event_and_drain() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        let a = w.push_circle(
+            Vec2::new(-2.0, 0.0),
+            0.5,
+            Vec2::new(4.0, 0.0),
+            mask,
+            Some(11),
+        );
+        let b = w.push_circle(Vec2::new(0.0, 0.0), 0.5, Vec2::ZERO, mask, Some(22));
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 1);
+        let ev = evs[0];
+        assert!(matches!(ev.kind, crate::types::EventKind::Sweep));
+        match ev.a {
+            BodyRef::Collider(id) => assert_eq!(id, a),
+            _ => panic!("expected collider A"),
+        }
+        match ev.b {
+            BodyRef::Collider(id) => assert_eq!(id, b),
+            _ => panic!("expected collider B"),
+        }
+        assert!(ev.sweep.is_some());
+        // Drained; buffer should be empty now
+        assert!(w.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_queries_and_pairwise() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        let id_a = w.push_aabb(
+            Vec2::new(0.0, 0.0),
+            Vec2::splat(1.0),
+            Vec2::ZERO,
+            mask,
+            Some(100),
+        );
+        let id_b = w.push_circle(Vec2::new(3.0, 0.0), 1.0, Vec2::ZERO, mask, Some(200));
+        w.end_frame();
+        // point inside AABB
+        let q1 = w.query_point(Vec2::new(0.5, 0.5), mask);
+        assert!(q1.iter().any(|(id, _)| *id == id_a));
+        // aabb overlaps a
+        let q2 = w.query_aabb(Vec2::new(0.0, 0.0), Vec2::splat(0.5), mask);
+        assert!(q2.iter().any(|(id, _)| *id == id_a));
+        // circle query hits circle b
+        let q3 = w.query_circle(Vec2::new(3.0, 0.0), 1.0, mask);
+        assert!(q3.iter().any(|(id, _)| *id == id_b));
+        // pairwise overlap between aabb and circle should be false
+        assert!(w.overlap_pair(id_a, id_b).is_none());
+        // by key lookup
+        assert!(w.overlap_by_key(100, 200).is_none());
+    }
+
+    #[test]
+    fn test_raycast_hits_closest() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        let id_a = w.push_aabb(
+            Vec2::new(2.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(1),
+        );
+        let _id_b = w.push_aabb(
+            Vec2::new(4.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(2),
+        );
+        w.end_frame();
+        let hit = w
+            .raycast(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), mask, 10.0)
+            .unwrap();
+        assert_eq!(hit.0, id_a);
+        let hit2 = w.raycast(Vec2::new(0.0, 0.0), Vec2::new(-1.0, 0.0), mask, 10.0);
+        assert!(hit2.is_none());
+    }
+
+    // --- Tile tests ---------------------------------------------------------
+
+    fn simple_map_bits() -> Vec<u8> {
+        // 3x1 with middle solid
+        vec![0, 1, 0]
+    }
+
+    #[test]
+    fn test_tile_raycast_basic() {
+        let mut w = PhysicsWorld::new(cfg());
+        let map = TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 1,
+            solids: &simple_map_bits(),
+            mask: LayerMask::simple(2, 1),
+            user_key: Some(77),
+            solid_mask: 0xFF,
+        };
+        w.attach_tilemap(map);
+        // ray from left hits middle cell at x=1 boundary
+        let origin = Vec2::new(-0.5, 0.5);
+        let dir = Vec2::new(1.0, 0.0);
+        let mask = LayerMask::simple(1, 2);
+        let hit = w.raycast_all(origin, dir, mask, 10.0).unwrap();
+        match hit.0 {
+            BodyRef::Tile(t) => {
+                assert_eq!(t.cell_xy.x, 1);
+            }
+            _ => panic!("expected tile hit"),
+        }
+    }
+
+    #[test]
+    fn test_query_aabb_all_tiles() {
+        let mut w = PhysicsWorld::new(cfg());
+        let map = TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 1,
+            solids: &simple_map_bits(),
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        w.attach_tilemap(map);
+        let res = w.query_aabb_all(
+            Vec2::new(1.0, 0.5),
+            Vec2::splat(0.6),
+            LayerMask::simple(1, 2),
+        );
+        assert!(
+            res.iter().any(
+                |(b, _)| matches!(b, BodyRef::Tile(TileRef { cell_xy, .. }) if cell_xy.x == 1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_sweep_aabb_tiles_basic() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0]; // 3x3 column in middle
+        let map = TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 3,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        w.attach_tilemap(map);
+        let start = Vec2::new(0.2, 1.5);
+        let he = Vec2::splat(0.3);
+        let vel = Vec2::new(2.0, 0.0);
+        let res = w
+            .sweep_aabb_tiles(start, he, vel, LayerMask::simple(1, 2))
+            .unwrap();
+        assert!(res.1.toi > 0.0 && res.1.toi <= 1.0);
+        // normal should be -X (hitting vertical face)
+        assert!(res.1.normal.x < -0.5);
+        assert!(res.1.hint.safe_pos.is_some());
+    }
+
+    #[test]
+    fn test_tile_raycast_monotonicity() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0, 1, 0]; // 3x1, solid at x=1
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let origin = Vec2::new(0.1, 0.5);
+        let dir = Vec2::new(1.0, 0.0);
+        let mask = LayerMask::simple(1, 2);
+        let h1 = w.raycast_tiles(origin, dir, 0.8, mask);
+        assert!(h1.is_none());
+        let h2 = w.raycast_tiles(origin, dir, 10.0, mask).unwrap();
+        let t2 = h2.1.toi;
+        assert!(t2 > 0.8);
+        let h3 = w.raycast_tiles(origin, dir, t2, mask).unwrap();
+        assert!((h3.1.toi - t2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_safe_pos_no_overlap_after_sweep() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 3,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let start = Vec2::new(0.2, 1.5);
+        let he = Vec2::splat(0.4);
+        let vel = Vec2::new(3.0, 0.0);
+        let (_tref, hit, _key) = w
+            .sweep_aabb_tiles(start, he, vel, LayerMask::simple(1, 2))
+            .unwrap();
+        let p = hit.hint.safe_pos.expect("safe_pos should exist");
+        let hits = w.query_aabb_all(p, he, LayerMask::simple(1, 2));
+        assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
+    }
+
+    #[test]
+    fn test_start_embedded_emits_overlap_event() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1]; // 1x1 solid at origin cell [0,0]
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: Some(42),
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        // AABB entirely inside the tile, no motion
+        let mask = LayerMask::simple(1, 2);
+        w.push_aabb(
+            Vec2::new(0.5, 0.5),
+            Vec2::splat(0.1),
+            Vec2::ZERO,
+            mask,
+            Some(7),
+        );
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert!(evs.iter().any(|e| matches!(e.kind, EventKind::Overlap)
+            && matches!(e.b, BodyRef::Tile(_))
+            && e.overlap.unwrap().hint.start_embedded));
+    }
+
+    #[test]
+    fn test_tile_raycast_monotonicity_random() {
+        let mut w = PhysicsWorld::new(cfg());
+        // map with a single solid column at x=10
+        let width = 32u32;
+        let height = 16u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for y in 0..height {
+            solids[(y * width + 10) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let mut seed = 1234567u32;
+        let lcg = |s: &mut u32| {
+            *s = s.wrapping_mul(1664525).wrapping_add(1013904223);
+            *s
+        };
+        for _ in 0..50 {
+            let ry = (lcg(&mut seed) as f32 / u32::MAX as f32) * (height as f32 - 1.0) + 0.5;
+            let ox = (lcg(&mut seed) as f32 / u32::MAX as f32) * 5.0; // start in [0,5)
+            let origin = Vec2::new(ox, ry);
+            let dir = Vec2::new(1.0, 0.0);
+            let small = 1.0; // < distance to column at x=10
+            let big = 100.0;
+            let h_small = w.raycast_tiles(origin, dir, small, mask);
+            let h_big = w.raycast_tiles(origin, dir, big, mask);
+            if let Some((_tref_s, hs, _)) = h_small {
+                let (_tref_b, hb, _) = h_big.expect("big max_t should retain hit");
+                assert!((hs.toi - hb.toi).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_safe_pos_invariant_random() {
+        let mut w = PhysicsWorld::new(cfg());
+        // vertical wall at x=5 across all rows
+        let width = 16u32;
+        let height = 16u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for y in 0..height {
+            solids[(y * width + 5) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let mut seed = 42u32;
+        let lcg = |s: &mut u32| {
+            *s = s.wrapping_mul(1664525).wrapping_add(1013904223);
+            *s
+        };
+        for _ in 0..40 {
+            let y = (lcg(&mut seed) as f32 / u32::MAX as f32) * 10.0 + 2.0;
+            let start_x = (lcg(&mut seed) as f32 / u32::MAX as f32) * 3.0;
+            let start = Vec2::new(start_x, y);
+            let he = Vec2::new(0.2, 0.3);
+            let vel = Vec2::new(4.0 + (lcg(&mut seed) as f32 / u32::MAX as f32) * 2.0, 0.0);
+            if let Some((_tref, hit, _)) = w.sweep_aabb_tiles(start, he, vel, mask)
+                && let Some(p) = hit.hint.safe_pos
+            {
+                let hits = w.query_aabb_all(p, he, mask);
+                assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_last_free_pos_invariant_random() {
+        let mut w = PhysicsWorld::new(cfg());
+        // vertical wall at x=5 across all rows
+        let width = 16u32;
+        let height = 16u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for y in 0..height {
+            solids[(y * width + 5) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let mut seed = 7u32;
+        let lcg = |s: &mut u32| {
+            *s = s.wrapping_mul(1664525).wrapping_add(1013904223);
+            *s
+        };
+        let mut checked = 0;
+        for _ in 0..40 {
+            let y = (lcg(&mut seed) as f32 / u32::MAX as f32) * 10.0 + 2.0;
+            let start_x = (lcg(&mut seed) as f32 / u32::MAX as f32) * 3.0;
+            let start = Vec2::new(start_x, y);
+            let he = Vec2::new(0.2, 0.3);
+            let vel = Vec2::new(4.0 + (lcg(&mut seed) as f32 / u32::MAX as f32) * 2.0, 0.0);
+            if let Some((_tref, hit, _)) = w.sweep_aabb_tiles(start, he, vel, mask)
+                && let Some(p) = hit.hint.last_free_pos
+            {
+                // Free of tiles, on the path, and no further along than the wall face
+                let hits = w.query_aabb_all(p, he, mask);
+                assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
+                assert!((p.y - start.y).abs() < 1e-5);
+                assert!(p.x >= start.x - 1e-5 && p.x + he.x <= 5.0 + 1e-5);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_tile_raycast_diagonal_hits_correct_cell() {
+        let mut w = PhysicsWorld::new(cfg());
+        // 16x16 map with a single solid at (5,5)
+        let width = 16u32;
+        let height = 16u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        solids[(5 * width + 5) as usize] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let origin = Vec2::new(0.25, 0.25);
+        let dir = Vec2::new(1.0, 1.0).normalize();
+        let (_tref, hit, _key) = w
+            .raycast_tiles(origin, dir, 100.0, mask)
+            .expect("expected tile hit");
+        // The cell index should be (5,5)
+        if let Some((TileRef { cell_xy, .. }, _, _)) = w.raycast_tiles(origin, dir, 100.0, mask) {
+            assert_eq!(cell_xy.x, 5);
+            assert_eq!(cell_xy.y, 5);
+            // Ties step Y first, so (5,5) is entered through its left face (x = 5)
+            assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+            assert_eq!(hit.contact.x, 5.0);
+            assert!((hit.contact.y - 5.0).abs() < 1e-4);
+        } else {
+            panic!("no tile hit");
+        }
+    }
+
+    #[test]
+    fn test_circle_sweep_minkowski_equivalence() {
+        let mut w = PhysicsWorld::new(cfg());
+        // vertical wall at x=5 across all rows
+        let width = 16u32;
+        let height = 16u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for y in 0..height {
+            solids[(y * width + 5) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let c = Vec2::new(1.5, 3.5);
+        let r = 0.4;
+        let vel = Vec2::new(6.0, 0.0);
+        let (_t_aabb, hit_aabb, _) = w.sweep_aabb_tiles(c, Vec2::splat(r), vel, mask).unwrap();
+        let (_t_circ, hit_circ, _) = w.sweep_circle_tiles(c, r, vel, mask).unwrap();
+        assert!((hit_aabb.toi - hit_circ.toi).abs() < 5e-3);
+        // Normals should closely match
+        let dn = (hit_aabb.normal - hit_circ.normal).length();
+        assert!(dn < 1e-3);
+    }
+
+    // Note: diagonal raycast octants are covered by test_tile_raycast_diagonal_hits_correct_cell.
+
+    #[test]
+    fn test_circle_sweep_diagonal_vel_and_radii() {
+        let mut w = PhysicsWorld::new(cfg());
+        // 32x32 map with vertical wall at x=16
+        let width = 32u32;
+        let height = 32u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for y in 0..height {
+            solids[(y * width + 16) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let center = Vec2::new(12.5, 10.5);
+        let radii = [0.1f32, 0.25, 0.5, 0.9];
+        let vels = [
+            Vec2::new(6.0, 3.0),
+            Vec2::new(12.0, -6.0),
+            Vec2::new(8.0, 4.0),
+        ];
+        for &r in &radii {
+            for &v in &vels {
+                let (_tr1, hit_c, _k1) = w
+                    .sweep_circle_tiles(center, r, v, mask)
+                    .expect("circle sweep should hit");
+                let (_tr2, hit_a, _k2) = w
+                    .sweep_aabb_tiles(center, Vec2::splat(r), v, mask)
+                    .expect("aabb(r) sweep should hit");
+                assert!(
+                    (hit_c.toi - hit_a.toi).abs() < 5e-3,
+                    "toi mismatch r={} v=({},{})",
+                    r,
+                    v.x,
+                    v.y
+                );
+                let dn = (hit_c.normal - hit_a.normal).length();
+                assert!(dn < 1e-2, "normal mismatch r={} v=({},{})", r, v.x, v.y);
+                assert!(hit_c.hint.safe_pos.is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sweep_slide_projects_remaining_motion() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0]; // 3x3 column in middle
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 3,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let vel = Vec2::new(2.0, 0.5);
+        let (_tref, hit, _) = w
+            .sweep_aabb_tiles(
+                Vec2::new(0.2, 1.5),
+                Vec2::splat(0.3),
+                vel,
+                LayerMask::simple(1, 2),
+            )
+            .unwrap();
+        let slide = hit.hint.slide.expect("tile sweep should report slide");
+        assert!(slide.x.abs() < 1e-5);
+        assert!((slide.y - vel.y * (1.0 - hit.toi)).abs() < 1e-4);
+
+        // Collider sweep: head-on circles leave nothing to slide along.
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        w.push_circle(Vec2::new(-2.0, 0.0), 0.5, Vec2::new(4.0, 0.0), mask, None);
+        w.push_circle(Vec2::new(0.0, 0.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        let s = evs[0].sweep.unwrap();
+        assert!(s.hint.slide.unwrap().length() < 1e-4);
+    }
+
+    #[test]
+    fn test_sweep_tiles_contacts_inside_corner() {
+        let mut w = PhysicsWorld::new(cfg());
+        // 4x4 L: floor row y=0 and wall column x=3
+        let width = 4u32;
+        let height = 4u32;
+        let mut solids = vec![0u8; (width * height) as usize];
+        for x in 0..width {
+            solids[x as usize] = 1;
+        }
+        for y in 0..height {
+            solids[(y * width + 3) as usize] = 1;
+        }
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let (toi, contacts) = w
+            .sweep_aabb_tiles_contacts(
+                Vec2::new(1.6, 2.4),
+                Vec2::splat(0.4),
+                Vec2::new(2.0, -2.0),
+                LayerMask::simple(1, 2),
+            )
+            .expect("corner landing should hit");
+        assert!((toi - 0.5).abs() < 1e-3);
+        assert!(contacts.iter().all(Option::is_some));
+        assert!(
+            contacts
+                .iter()
+                .flatten()
+                .any(|(_, n)| (*n - Vec2::new(-1.0, 0.0)).length() < 1e-5)
+        );
+        assert!(
+            contacts
+                .iter()
+                .flatten()
+                .any(|(_, n)| (*n - Vec2::new(0.0, 1.0)).length() < 1e-5)
+        );
+    }
+
+    #[test]
+    fn test_circle_resting_in_aabb_emits_directed_overlap() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        // Circle pushed first so it is `a`; half of it sinks into the box's top face.
+        let circle = w.push_circle(Vec2::new(0.0, 1.0), 0.5, Vec2::ZERO, mask, None);
+        w.push_aabb(Vec2::ZERO, Vec2::new(2.0, 1.0), Vec2::ZERO, mask, None);
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 1);
+        assert!(matches!(evs[0].kind, EventKind::Overlap));
+        assert_eq!(evs[0].a, BodyRef::Collider(circle));
+        let ov = evs[0].overlap.unwrap();
+        assert!(
+            ov.normal.y > 0.99,
+            "normal should point out of the box: {:?}",
+            ov.normal
+        );
+        assert!((ov.depth - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_skin_width_backs_off_safe_pos() {
+        let mut c = cfg();
+        c.skin_width = 0.05;
+        // The clearance doesn't depend on the shared tile epsilon
+        c.tile_eps = 0.2;
+        let mut w = PhysicsWorld::new(c);
+        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0]; // 3x3 column in middle
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 3,
+            height: 3,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let he = Vec2::splat(0.3);
+        let (_tref, hit, _) = w
+            .sweep_aabb_tiles(
+                Vec2::new(0.2, 1.5),
+                he,
+                Vec2::new(2.0, 0.0),
+                LayerMask::simple(1, 2),
+            )
+            .unwrap();
+        let safe = hit.hint.safe_pos.unwrap();
+        // At least `skin_width` from the wall, and no further than the refine bracket allows
+        let gap = 1.0 - (safe.x + he.x);
+        assert!(gap >= 0.05 - 1e-5);
+        assert!(gap <= 0.05 + hit.hint.toi_error * 2.0 + 1e-5);
+        assert_eq!(safe.y, 1.5);
+        let (_tref, hit, _) = w
+            .sweep_circle_tiles(
+                Vec2::new(0.2, 1.5),
+                0.3,
+                Vec2::new(2.0, 0.0),
+                LayerMask::simple(1, 2),
+            )
+            .unwrap();
+        let safe = hit.hint.safe_pos.unwrap();
+        assert!((1.0 - (safe.x + 0.3) - 0.05).abs() < 1e-5);
+
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        w.push_circle(Vec2::new(-2.0, 0.0), 0.5, Vec2::new(4.0, 0.0), mask, None);
+        w.push_circle(Vec2::new(0.0, 0.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        let safe = evs[0].sweep.unwrap().hint.safe_pos.unwrap();
+        assert!(safe.length() - 1.0 >= 0.05 - 1e-4);
+    }
+
+    #[test]
+    fn test_point_point_crossing_paths_emit_sweep() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        // Both reach (0, 0) halfway through the frame
+        let a = w.push_point(Vec2::new(-1.0, 0.0), Vec2::new(2.0, 0.0), mask, None);
+        w.push_point(Vec2::new(0.0, -1.0), Vec2::new(0.0, 2.0), mask, None);
+        // Paths cross at (10.5, 10) but at t = 0.75 and t = 0.5: they never meet
+        w.push_point(Vec2::new(9.0, 10.0), Vec2::new(2.0, 0.0), mask, None);
+        w.push_point(Vec2::new(10.5, 9.0), Vec2::new(0.0, 2.0), mask, None);
+        // Parallel pair never reports
+        w.push_point(Vec2::new(-1.0, 5.0), Vec2::new(2.0, 0.0), mask, None);
+        w.push_point(Vec2::new(-1.0, 5.5), Vec2::new(2.0, 0.0), mask, None);
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 1);
+        assert_eq!(evs[0].a, BodyRef::Collider(a));
+        let s = evs[0].sweep.unwrap();
+        assert!((s.toi - 0.5).abs() < 1e-4);
+        assert!(s.contact.length() < 1e-4);
+        assert!(s.normal.length() > 0.99);
+
+        // A near miss counts once the points pass within `point_eps` of each other
+        let mut w = PhysicsWorld::new(WorldConfig {
+            point_eps: 0.1,
+            ..cfg()
+        });
+        w.begin_frame();
+        w.push_point(Vec2::new(-1.0, 0.05), Vec2::new(2.0, 0.0), mask, None);
+        w.push_point(Vec2::new(0.0, -1.0), Vec2::new(0.0, 2.0), mask, None);
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 1);
+        assert!(evs[0].sweep.unwrap().toi < 0.5);
+
+        // No relative motion: only points already within `point_eps` hit, at the start
+        w.begin_frame();
+        let vel = Vec2::new(1.0, 0.0);
+        let a = w.push_point(Vec2::new(0.0, 0.0), vel, mask, None);
+        let b = w.push_point(Vec2::new(0.05, 0.0), vel, mask, None);
+        let c = w.push_point(Vec2::new(5.0, 0.0), vel, mask, None);
+        w.end_frame();
+        let hit = w.sweep_pair(a, b).unwrap();
+        assert_eq!((hit.toi, hit.normal), (0.0, Vec2::NEG_X));
+        assert!(hit.hint.start_embedded);
+        assert!(w.sweep_pair(a, c).is_none());
+    }
+
+    #[test]
+    fn test_collider_broadphase_load() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        // Spans a 4x4 block of cells
+        let big = w.push_aabb(
+            Vec2::new(2.0, 2.0),
+            Vec2::splat(1.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        let small = w.push_circle(Vec2::new(0.5, 0.5), 0.25, Vec2::ZERO, mask, None);
+        w.push_circle(Vec2::new(3.5, 3.5), 0.25, Vec2::ZERO, mask, None);
+        w.push_circle(Vec2::new(9.5, 9.5), 0.25, Vec2::ZERO, mask, None);
+        w.end_frame();
+        assert_eq!(w.collider_broadphase_load(big), (16, 2));
+        assert_eq!(w.collider_broadphase_load(small), (1, 1));
+        assert_eq!(w.collider_broadphase_load(FrameId(99)), (0, 0));
+    }
+
+    #[test]
+    fn test_motion_tighten_override() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let desc = ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::ZERO,
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        w.begin_frame();
+        let swept = w.push(
+            desc,
+            Motion {
+                vel: Vec2::new(3.0, 0.0),
+                tighten: None,
+                delta: None,
+                dt: None,
+            },
+        );
+        let fixed = w.push(
+            desc,
+            Motion {
+                vel: Vec2::new(3.0, 0.0),
+                tighten: Some(false),
+                delta: None,
+                dt: None,
+            },
+        );
+        w.end_frame();
+        assert!((w.aabbs[swept.0 as usize].1.x - 3.5).abs() < 1e-6);
+        assert!((w.aabbs[fixed.0 as usize].1.x - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_statics_persist_and_pair_with_frame_colliders() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let wall = w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::new(0.5, 2.0),
+            },
+            center: Vec2::new(3.0, 0.0),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        for _ in 0..2 {
+            w.begin_frame();
+            let mover = w.push_circle(Vec2::new(0.0, 0.0), 0.5, Vec2::new(4.0, 0.0), mask, None);
+            w.end_frame();
+            w.generate_events();
+            let evs = w.drain_events();
+            assert_eq!(evs.len(), 1);
+            assert_eq!(evs[0].a, BodyRef::Collider(mover));
+            assert_eq!(evs[0].b, BodyRef::Static(wall));
+            assert!((evs[0].sweep.unwrap().toi - 0.5).abs() < 1e-4);
+        }
+
+        // Statics are visible to unified queries but not collider-only ones
+        let hits = w.query_point_all(Vec2::new(3.0, 1.5), mask);
+        assert_eq!(hits, vec![(BodyRef::Static(wall), None)]);
+        assert!(w.query_point(Vec2::new(3.0, 1.5), mask).is_empty());
+        let (body, hit, _) = w
+            .raycast_all(Vec2::new(0.0, 1.0), Vec2::X, mask, 10.0)
+            .unwrap();
+        assert_eq!(body, BodyRef::Static(wall));
+        assert!((hit.toi - 2.5).abs() < 1e-5);
+
+        w.clear_statics();
+        assert!(w.query_point_all(Vec2::new(3.0, 1.5), mask).is_empty());
+    }
+
+    #[test]
+    fn test_alt_broadphases_match_grid() {
+        let run = |broadphase: Broadphase| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                broadphase,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            w.begin_frame();
+            // A giant boss box plus small movers of wildly different scale
+            w.push_aabb(Vec2::ZERO, Vec2::splat(40.0), Vec2::ZERO, mask, Some(0));
+            for i in 0..30u64 {
+                let c = Vec2::new(i as f32 * 3.0 - 45.0, (i % 5) as f32 * 20.0 - 45.0);
+                w.push_circle(c, 0.5, Vec2::new(1.5, 0.0), mask, Some(i + 1));
+            }
+            w.end_frame();
+            w.generate_events();
+            let mut evs: Vec<(Option<ColKey>, Option<ColKey>)> = w
+                .drain_events()
+                .iter()
+                .map(|e| (e.a_key, e.b_key))
+                .collect();
+            evs.sort();
+            let mut q: Vec<Option<ColKey>> = w
+                .query_aabb(Vec2::new(-40.0, -45.0), Vec2::splat(5.0), mask)
+                .into_iter()
+                .map(|(_, k)| k)
+                .collect();
+            q.sort();
+            let ray = w
+                .raycast(Vec2::new(-100.0, -45.0), Vec2::X, mask, 1000.0)
+                .map(|(_, h, k)| (k, h.toi));
+            (evs, q, ray, w.debug_stats())
+        };
+        let (grid_evs, grid_q, grid_ray, _) = run(Broadphase::Grid);
+        assert!(!grid_evs.is_empty());
+        for broadphase in [Broadphase::Bvh, Broadphase::Sap] {
+            let (evs, q, ray, stats) = run(broadphase);
+            assert_eq!(grid_evs, evs);
+            assert_eq!(grid_q, q);
+            assert_eq!(grid_ray, ray);
+            assert_eq!(stats.cells, 0);
+            if broadphase == Broadphase::Bvh {
+                assert!(stats.bvh_nodes > 1 && stats.bvh_max_depth >= 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_tiles_tracks_dirty_rows() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0u8; 16];
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 4,
+            height: 4,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        assert!(w.dirty_rows(map).is_empty());
+        w.update_tiles(map, (1, 2, 2, 2), &[1, 1, 1, 1]);
+        w.update_tiles(map, (0, 0, 1, 1), &[1]);
+        w.update_tiles(map, (3, 3, 1, 1), &[1]);
+        w.update_tiles(map, (0, 2, 1, 1), &[1]);
+        assert_eq!(w.dirty_rows(map), &[0, 2, 3]);
+        w.clear_dirty(map);
+        assert!(w.dirty_rows(map).is_empty());
+        assert!(w.dirty_rows(TileMapRef(7)).is_empty());
+    }
+
+    #[test]
+    fn test_tile_raycast_skips_sparse_runs() {
+        let mut w = PhysicsWorld::new(cfg());
+        let (width, height) = (300u32, 300u32);
+        let mut solids = vec![0u8; (width * height) as usize];
+        solids[(150 * width + 290) as usize] = 1; // far along +X in row 150
+        solids[(10 * width + 40) as usize] = 1; // far along -Y in column 40
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let (tref, hit, _) = w
+            .raycast_tiles(Vec2::new(0.5, 150.5), Vec2::new(1.0, 0.001), 1000.0, mask)
+            .unwrap();
+        assert_eq!(tref.cell_xy, glam::UVec2::new(290, 150));
+        assert!((hit.toi - 289.5).abs() < 1e-3);
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        let (tref, hit, _) = w
+            .raycast_tiles(Vec2::new(40.5, 299.5), Vec2::new(0.0, -1.0), 1000.0, mask)
+            .unwrap();
+        assert_eq!(tref.cell_xy, glam::UVec2::new(40, 10));
+        assert_eq!(hit.normal, Vec2::new(0.0, 1.0));
+
+        // Runs are rebuilt by update_tiles
+        w.update_tiles(map, (100, 150, 1, 1), &[1]);
+        let (tref, _, _) = w
+            .raycast_tiles(Vec2::new(0.5, 150.5), Vec2::new(1.0, 0.001), 1000.0, mask)
+            .unwrap();
+        assert_eq!(tref.cell_xy, glam::UVec2::new(100, 150));
+        w.update_tiles(map, (40, 10, 1, 1), &[0]);
+        assert!(
+            w.raycast_tiles(Vec2::new(40.5, 299.5), Vec2::new(0.0, -1.0), 1000.0, mask)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_tile_raycast_skip_matches_stepping_random() {
+        let (width, height) = (64u32, 64u32);
+        let mut seed = 424242u32;
+        let mut lcg = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            seed as f32 / u32::MAX as f32
+        };
+        let mut solids = vec![0u8; (width * height) as usize];
+        for s in solids.iter_mut() {
+            *s = (lcg() < 0.02) as u8;
+        }
+        let desc = TileMapDesc {
+            origin: Vec2::new(-3.0, 2.0),
+            cell: 1.5,
+            width,
+            height,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        let mut fast = PhysicsWorld::new(cfg());
+        fast.attach_tilemap(desc.clone());
+        let mut slow = PhysicsWorld::new(cfg());
+        slow.attach_tilemap(desc);
+        // Zeroed runs disable skipping
+        slow.tilemaps[0].runs = TileRuns {
+            pos_x: vec![0; solids.len()],
+            neg_x: vec![0; solids.len()],
+            pos_y: vec![0; solids.len()],
+            neg_y: vec![0; solids.len()],
+        };
+        let mask = LayerMask::simple(1, 2);
+        for _ in 0..500 {
+            let origin = Vec2::new(lcg() * 96.0 - 3.0, lcg() * 96.0 + 2.0);
+            let dir = Vec2::new(lcg() * 2.0 - 1.0, lcg() * 2.0 - 1.0);
+            let a = fast.raycast_tiles(origin, dir, 500.0, mask);
+            let b = slow.raycast_tiles(origin, dir, 500.0, mask);
+            assert_eq!(a.map(|h| h.0), b.map(|h| h.0));
+            if let (Some((_, ha, _)), Some((_, hb, _))) = (a, b) {
+                assert!((ha.toi - hb.toi).abs() < 1e-3);
+                assert_eq!(ha.normal, hb.normal);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_value_returns_raw_byte() {
+        let mut w = PhysicsWorld::new(cfg());
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 2,
+            height: 2,
+            solids: &[0, 7, 3, 0],
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        assert_eq!(w.tile_value(map, glam::UVec2::new(1, 0)), Some(7));
+        assert_eq!(w.tile_value(map, glam::UVec2::new(0, 1)), Some(3));
+        assert_eq!(w.tile_value(map, glam::UVec2::new(2, 0)), None);
+        assert_eq!(w.tile_value(TileMapRef(3), glam::UVec2::ZERO), None);
+    }
+
+    #[test]
+    fn test_tilemap_solid_mask_filters_bits() {
+        let mut w = PhysicsWorld::new(cfg());
+        // bit 0 = collision, bit 1 = one-way flag only
+        let solids = vec![0, 2, 3, 2];
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 4,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0b01,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let (tref, _, _) = w
+            .raycast_tiles(Vec2::new(0.5, 0.5), Vec2::X, 10.0, mask)
+            .unwrap();
+        assert_eq!(tref.cell_xy.x, 2);
+        assert!(w.query_point_all(Vec2::new(1.5, 0.5), mask).is_empty());
+        assert_eq!(
+            w.query_aabb_all(Vec2::new(2.0, 0.5), Vec2::splat(0.9), mask)
+                .len(),
+            1
+        );
+        let (tref, _, _) = w
+            .sweep_aabb_tiles(
+                Vec2::new(0.5, 0.5),
+                Vec2::splat(0.25),
+                Vec2::new(3.0, 0.0),
+                mask,
+            )
+            .unwrap();
+        assert_eq!(tref.cell_xy.x, 2);
+        // Raw bytes are untouched
+        assert_eq!(w.tile_value(map, glam::UVec2::new(1, 0)), Some(2));
+    }
+
+    #[test]
+    fn test_tile_raycast_grazing_contact_snaps_to_face() {
+        let mut w = PhysicsWorld::new(cfg());
+        let width = 16u32;
+        let mut solids = vec![0u8; (width * 8) as usize];
+        solids[(5 * width + 10) as usize] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.3, 0.0),
+            cell: 1.0,
+            width,
+            height: 8,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        // Shallow ray crosses into row 5 a couple of cells early, then enters (10,5) from the left
+        let dir = Vec2::new(1.0, 0.0125).normalize();
+        let (tref, hit, _) = w
+            .raycast_tiles(Vec2::new(0.8, 4.9), dir, 100.0, mask)
+            .unwrap();
+        assert_eq!(tref.cell_xy, glam::UVec2::new(10, 5));
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(hit.contact.x, 10.3);
+        assert!(hit.contact.y > 5.0 && hit.contact.y < 6.0);
+    }
+
+    #[test]
+    fn test_cast_dispatches_on_shape() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![0u8, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, 0.0),
+            cell: 1.0,
+            width: 10,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: Some(9),
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        let target = w.push_circle(Vec2::new(6.0, 3.0), 0.5, Vec2::ZERO, mask, Some(1));
+        w.end_frame();
+
+        // Circle at y=0.5 only reaches the tile at x=9
+        let circle = ColliderKind::Circle { radius: 0.25 };
+        let (body, hit, key) = w
+            .cast(circle, Vec2::new(0.5, 0.5), Vec2::X, mask, 20.0)
+            .unwrap();
+        assert!(matches!(body, BodyRef::Tile(_)));
+        assert_eq!(key, Some(9));
+        assert!((hit.toi - 8.25).abs() < 1e-2);
+
+        // A tall box along y=2.5 catches the collider first
+        let tall = ColliderKind::Aabb {
+            half_extents: Vec2::new(0.5, 0.6),
+        };
+        let (body, hit, _) = w
+            .cast(tall, Vec2::new(0.5, 2.5), Vec2::X, mask, 20.0)
+            .unwrap();
+        assert_eq!(body, BodyRef::Collider(target));
+        assert!(hit.toi > 4.0 && hit.toi < 5.0);
+
+        // Points are plain rays
+        let (body, hit, _) = w
+            .cast(
+                ColliderKind::Point,
+                Vec2::new(0.0, 3.0),
+                Vec2::X,
+                mask,
+                20.0,
+            )
+            .unwrap();
+        assert_eq!(body, BodyRef::Collider(target));
+        assert!((hit.toi - 5.5).abs() < 1e-5);
+        assert!(
+            w.cast(circle, Vec2::new(0.5, 5.0), Vec2::X, mask, f32::INFINITY)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_layer_mask_helpers() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let a = w.push_aabb(
+            Vec2::new(3.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(2, 1),
+            None,
+        );
+        w.push_aabb(
+            Vec2::new(6.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(4, 1),
+            None,
+        );
+        w.end_frame();
+        let hit = w.raycast(Vec2::ZERO, Vec2::X, LayerMask::all(), 10.0);
+        assert_eq!(hit.map(|h| h.0), Some(a));
+        assert!(
+            w.raycast(Vec2::ZERO, Vec2::X, LayerMask::none(), 10.0)
+                .is_none()
+        );
+        let hit = w.raycast(Vec2::ZERO, Vec2::X, LayerMask::only(4), 10.0);
+        assert!(hit.is_some_and(|h| h.0 != a));
+        let skip_two = LayerMask::all().with_exclude(2);
+        assert!(
+            w.raycast(Vec2::ZERO, Vec2::X, skip_two, 10.0)
+                .is_some_and(|h| h.0 != a)
+        );
+    }
+
+    #[test]
+    fn test_event_layers() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1u8];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(2.0, 0.0),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(4, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        w.push_circle(
+            Vec2::new(0.5, 0.5),
+            0.25,
+            Vec2::ZERO,
+            LayerMask::simple(1, 6),
+            None,
+        );
+        w.push_circle(
+            Vec2::new(0.6, 0.5),
+            0.25,
+            Vec2::ZERO,
+            LayerMask::simple(2, 1),
+            None,
+        );
+        w.push_circle(
+            Vec2::new(1.0, 2.5),
+            0.25,
+            Vec2::new(2.0, -2.0),
+            LayerMask::simple(1, 4),
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        let pair = evs
+            .iter()
+            .find(|e| matches!(e.b, BodyRef::Collider(_)))
+            .unwrap();
+        assert_eq!((pair.a_layer, pair.b_layer), (1, 2));
+        let tile = evs
+            .iter()
+            .find(|e| matches!(e.b, BodyRef::Tile(_)))
+            .unwrap();
+        assert_eq!((tile.a_layer, tile.b_layer), (1, 4));
+    }
+
+    #[test]
+    fn test_overlap_policy_selects_event_kinds() {
+        let kinds = |policy: OverlapPolicy| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                overlap_policy: policy,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            w.begin_frame();
+            // Overlapping at start and moving into each other: both kinds apply
+            w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::X, mask, None);
+            w.push_aabb(
+                Vec2::new(0.8, 0.0),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            // At rest and overlapping: only an overlap can apply
+            w.push_circle(Vec2::new(10.0, 0.0), 0.5, Vec2::ZERO, mask, None);
+            w.push_circle(Vec2::new(10.5, 0.0), 0.5, Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            let mut k: Vec<bool> = w
+                .drain_events()
+                .iter()
+                .map(|e| matches!(e.kind, EventKind::Sweep))
+                .collect();
+            k.sort();
+            k
+        };
+        assert_eq!(
+            kinds(OverlapPolicy::SweepThenOverlapFallback),
+            vec![false, true]
+        );
+        assert_eq!(kinds(OverlapPolicy::SweepOnly), vec![true]);
+        assert_eq!(kinds(OverlapPolicy::OverlapOnly), vec![false, false]);
+        assert_eq!(kinds(OverlapPolicy::Both), vec![false, false, true]);
+    }
+
+    #[test]
+    fn test_sweep_event_impact_speed() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        w.push_circle(Vec2::ZERO, 0.5, Vec2::new(2.0, 0.0), mask, None);
+        w.push_aabb(
+            Vec2::new(3.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::new(-1.0, 0.0),
+            mask,
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        let hit = evs[0].sweep.unwrap();
+        assert!((hit.impact_speed - 3.0).abs() < 1e-5);
+
+        let solids = vec![1u8];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, -3.0),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let (_, hit, _) = w
+            .sweep_circle_tiles(Vec2::new(0.5, 0.0), 0.25, Vec2::new(0.0, -4.0), mask)
+            .unwrap();
+        assert!((hit.impact_speed - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_touching_vs_penetrating() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        let b = w.push_aabb(
+            Vec2::new(1.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        let c = w.push_circle(Vec2::new(0.0, 1.5), 1.6, Vec2::ZERO, mask, None);
+        let p = w.push_point(Vec2::new(0.3, 0.0), Vec2::ZERO, mask, None);
+        w.end_frame();
+        assert!(w.touching_pair(a, b, 1e-5));
+        assert!(!w.touching_pair(a, c, 1e-5));
+        // Point 0.2 inside the box's right face reports that depth
+        let ov = w.overlap_pair(p, a).unwrap();
+        assert!((ov.depth - 0.2).abs() < 1e-5);
+        assert_eq!(ov.normal, Vec2::X);
+        assert!(!w.touching_pair(p, a, 1e-5));
+        let ov = w.overlap_pair(p, c).unwrap();
+        assert!(ov.depth > 0.0);
+    }
+
+    #[test]
+    fn test_push_many_registers_range_and_keys() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let first = w.push_point(Vec2::new(-5.0, 0.0), Vec2::ZERO, mask, None);
+        let descs: Vec<ColliderDesc> = (0..3)
+            .map(|i| ColliderDesc {
+                kind: ColliderKind::Circle { radius: 0.25 },
+                center: Vec2::new(i as f32, 0.0),
+                mask,
+                user_key: Some(100 + i),
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            })
+            .collect();
+        let motions = [Motion {
+            vel: Vec2::X,
+            ..Default::default()
+        }];
+        let range = w.push_many(&descs, &motions);
+        w.end_frame();
+        assert_eq!(first, FrameId(0));
+        assert_eq!(range, 1..4);
+        assert!(w.overlap_by_key(101, 101).is_some());
+        assert!(w.sweep_by_key(100, 101).is_some());
+        assert!(w.sweep_by_key(101, 102).is_none());
+    }
+
+    #[test]
+    fn test_into_vec2_arguments() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_aabb([2.0, 0.0], (0.5, 0.5), [0.0, 0.0], mask, None);
+        w.end_frame();
+        let hit = w.raycast((0.0, 0.0), [1.0, 0.0], mask, 10.0).unwrap();
+        assert_eq!(hit.0, a);
+        assert_eq!(w.query_point([2.0, 0.25], mask).len(), 1);
+    }
+
+    #[test]
+    fn test_tile_aabb_and_bounds() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0u8; 6];
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(-1.0, 2.0),
+            cell: 0.5,
+            width: 3,
+            height: 2,
+            solids: &solids,
+            mask: LayerMask::simple(1, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let t = TileRef {
+            map,
+            cell_xy: glam::UVec2::new(2, 1),
+        };
+        assert_eq!(
+            w.tile_aabb(t),
+            Some((Vec2::new(0.0, 2.5), Vec2::new(0.5, 3.0)))
+        );
+        assert_eq!(
+            w.tilemap_bounds(map),
+            Some((Vec2::new(-1.0, 2.0), Vec2::new(0.5, 3.0)))
+        );
+        let outside = TileRef {
+            map,
+            cell_xy: glam::UVec2::new(3, 0),
+        };
+        assert!(w.tile_aabb(outside).is_none());
+        assert!(w.tilemap_bounds(TileMapRef(7)).is_none());
+    }
+
+    #[test]
+    fn test_debug_stats_detailed_busiest_cell() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        // Two cells with two points each; the smaller coordinate wins the tie
+        for p in [[5.5, 5.5], [5.6, 5.6], [-3.5, 2.5], [-3.4, 2.6], [9.5, 0.5]] {
+            w.push_point(p, Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let d = w.debug_stats_detailed();
+        assert_eq!(d.max_cell_occupancy, 2);
+        assert_eq!(d.busiest_cell, Some((-4, 2)));
+        assert_eq!(d.stats.entries, 5);
+    }
+
+    #[test]
+    fn test_pair_id_stable_across_frames() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let mut ids = |swap: bool, shift: f32| {
+            w.begin_frame();
+            let mut push = |key: u64, x: f32| {
+                w.push_circle(Vec2::new(x + shift, 0.0), 0.5, Vec2::ZERO, mask, Some(key));
+            };
+            if swap {
+                push(2, 0.6);
+                push(1, 0.0);
+            } else {
+                push(1, 0.0);
+                push(2, 0.6);
+            }
+            push(3, 10.0);
+            push(4, 10.6);
+            w.end_frame();
+            w.generate_events();
+            let mut ids: Vec<u64> = w.drain_events().iter().map(|e| e.pair_id).collect();
+            ids.sort();
+            ids
+        };
+        let first = ids(false, 0.0);
+        assert_eq!(first.len(), 2);
+        assert_ne!(first[0], first[1]);
+        assert_eq!(ids(true, 0.1), first);
+    }
+
+    #[test]
+    fn test_generate_events_with_sink_respects_cap() {
+        let mut w = PhysicsWorld::new(WorldConfig {
+            max_events: 2,
+            ..cfg()
+        });
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        for i in 0..4 {
+            let x = i as f32 * 10.0;
+            w.push_circle(Vec2::new(x, 0.0), 0.5, Vec2::ZERO, mask, None);
+            w.push_circle(Vec2::new(x + 0.5, 0.0), 0.5, Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let mut seen = 0;
+        w.generate_events_with(|ev| {
+            assert!(matches!(ev.kind, EventKind::Overlap));
+            seen += 1;
+        });
+        assert_eq!(seen, 2);
+        assert!(w.drain_events().is_empty());
+        w.generate_events();
+        assert_eq!(w.drain_events().len(), 2);
+    }
+
+    #[test]
+    fn test_query_aabb_solid_cells_dedupes_stacked_maps() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let a = vec![1u8, 1, 0, 0];
+        let b = vec![0u8, 1, 1, 0];
+        let mut maps = Vec::new();
+        for solids in [&a, &b] {
+            maps.push(w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 4,
+                height: 1,
+                solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            }));
+        }
+        let center = Vec2::new(2.0, 0.5);
+        let he = Vec2::new(1.9, 0.4);
+        assert_eq!(w.query_aabb_all(center, he, mask).len(), 4);
+        let cells = w.query_aabb_solid_cells(center, he, mask);
+        assert_eq!(
+            cells,
+            vec![
+                (maps[0], glam::UVec2::new(0, 0)),
+                (maps[0], glam::UVec2::new(1, 0)),
+                (maps[1], glam::UVec2::new(2, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_attach_tilemap_rejects_degenerate_maps() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1u8; 4];
+        let desc = |cell: f32, width: u32| TileMapDesc {
+            origin: Vec2::ZERO,
+            cell,
+            width,
+            height: 2,
+            solids: &solids,
+            mask: LayerMask::simple(1, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        assert_eq!(
+            w.try_attach_tilemap(desc(0.0, 2)),
+            Err(TileMapError::InvalidCell(0.0))
+        );
+        assert!(matches!(
+            w.try_attach_tilemap(desc(f32::NAN, 2)),
+            Err(TileMapError::InvalidCell(_))
+        ));
+        assert_eq!(
+            w.try_attach_tilemap(desc(1.0, 3)),
+            Err(TileMapError::SizeMismatch {
+                expected: 6,
+                got: 4
+            })
+        );
+        assert!(
+            w.raycast_tiles(Vec2::new(-1.0, 0.5), Vec2::X, 10.0, LayerMask::all())
+                .is_none()
+        );
+        assert_eq!(w.try_attach_tilemap(desc(1.0, 2)), Ok(TileMapRef(0)));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "attach_tilemap"))]
+    fn test_attach_tilemap_invalid_desc_attaches_empty_map() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8; 4];
+        // Release builds keep going with an empty map instead of panicking
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 3,
+            height: 2,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        assert_eq!(map, TileMapRef(0));
+        assert_eq!(w.tile_value(map, glam::UVec2::new(0, 0)), None);
+        assert!(
+            w.query_aabb_all(Vec2::splat(1.0), Vec2::splat(1.0), mask)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "attach_tilemap_bits"))]
+    fn test_attach_tilemap_bits_invalid_desc_attaches_empty_map() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        // Release builds keep going with an empty map instead of panicking
+        let map = w.attach_tilemap_bits(TileMapBitsDesc {
+            origin: Vec2::ZERO,
+            cell: 0.0,
+            width: 8,
+            height: 8,
+            bits: &[u64::MAX],
+            mask,
+            user_key: None,
+        });
+        assert_eq!(map, TileMapRef(0));
+        w.update_tiles(map, (0, 0, 0, 0), &[]);
+        assert_eq!(w.tile_value(map, glam::UVec2::new(0, 0)), None);
+        assert!(
+            w.raycast_tiles(Vec2::new(-1.0, 0.5), Vec2::X, 10.0, mask)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_queries_with_absurd_extents_stay_bounded() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8; 4];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 2,
+            height: 2,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(5.0, 5.0),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        w.begin_frame();
+        w.push_circle(Vec2::new(-5.0, 0.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        // Would span ~1e19 grid cells unclamped
+        let huge = Vec2::splat(1e9);
+        assert_eq!(w.query_aabb(Vec2::ZERO, huge, mask).len(), 1);
+        assert_eq!(w.query_aabb_all(Vec2::ZERO, huge, mask).len(), 6);
+        assert_eq!(w.query_circle_all(Vec2::ZERO, 1e9, mask).len(), 6);
+        let nan = Vec2::splat(f32::NAN);
+        assert!(w.query_aabb_all(nan, Vec2::ONE, mask).is_empty());
+        assert!(
+            w.query_aabb_all(Vec2::ZERO, Vec2::INFINITY, mask)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_global_collider_pairs_with_everything() {
+        for bp in [Broadphase::Grid, Broadphase::Bvh, Broadphase::Sap] {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                broadphase: bp,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            w.add_static(ColliderDesc {
+                kind: ColliderKind::Aabb {
+                    half_extents: Vec2::splat(0.5),
+                },
+                center: Vec2::new(40.0, 40.0),
+                mask,
+                user_key: None,
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            });
+            w.begin_frame();
+            let bounds = w.push(
+                ColliderDesc {
+                    kind: ColliderKind::Aabb {
+                        half_extents: Vec2::splat(100.0),
+                    },
+                    center: Vec2::ZERO,
+                    mask,
+                    user_key: None,
+                    broadphase: BroadphaseMode::Global,
+                    tag: 0,
+                    event_targets: EventTargets::Both,
+                },
+                Motion::default(),
+            );
+            for i in 0..3 {
+                w.push_point(Vec2::new(i as f32 * 20.0, 0.0), Vec2::ZERO, mask, None);
+            }
+            w.end_frame();
+            if bp == Broadphase::Grid {
+                assert!(w.grid.values().all(|l| !l.contains(&0)));
+                assert_eq!(w.collider_broadphase_load(bounds), (0, 3));
+            }
+            w.generate_events();
+            let evs = w.drain_events();
+            let with_bounds = evs
+                .iter()
+                .filter(|e| e.a == BodyRef::Collider(bounds) || e.b == BodyRef::Collider(bounds))
+                .count();
+            assert_eq!(with_bounds, 4, "{bp:?}");
+            assert_eq!(evs.len(), 4, "{bp:?}");
+            // Queries still find it
+            assert!(
+                w.query_point(Vec2::new(-50.0, 50.0), mask)
+                    .contains(&(bounds, None))
+            );
+        }
+    }
+
+    #[test]
+    fn test_sweep_capsule_tiles() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 2);
+        let solids = vec![1u8];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, -3.0),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        // Falling onto the tile: bottom cap lands on the top face
+        let (_, hit, _) = w
+            .sweep_capsule_tiles(Vec2::new(0.5, 0.0), 0.25, 0.5, Vec2::new(0.0, -4.0), mask)
+            .expect("capsule should land on tile");
+        assert!((hit.toi - 0.3125).abs() < 1e-3, "toi={}", hit.toi);
+        assert!((hit.normal - Vec2::Y).length() < 1e-3);
+        assert!((hit.impact_speed - 4.0).abs() < 1e-3);
+
+        // Moving sideways into the tile: hits with the flat side
+        let (_, hit, _) = w
+            .sweep_capsule_tiles(Vec2::new(-1.0, -2.5), 0.25, 0.5, Vec2::new(4.0, 0.0), mask)
+            .expect("capsule side should hit tile");
+        assert!((hit.toi - 0.1875).abs() < 1e-3, "toi={}", hit.toi);
+        assert!((hit.normal + Vec2::X).length() < 1e-3);
+
+        // Rounded cap clears the tile corner that its bounding box overlaps
+        let center = Vec2::new(1.2, -1.3);
+        let vel = Vec2::new(4.0, 0.0);
+        assert_eq!(
+            w.query_aabb_solid_cells(center, Vec2::new(0.25, 0.75), mask)
+                .len(),
+            1
+        );
+        assert!(
+            w.sweep_capsule_tiles(center, 0.25, 0.5, vel, mask)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_polygon_tiles_sweep_and_query() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 2);
+        let solids = vec![1u8];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(0.0, -3.0),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        // Downward-pointing triangle: its tip lands on the tile's top face
+        let tri = [
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.5, 0.0),
+        ];
+        let (tref, hit, _) = w
+            .sweep_polygon_tiles(&tri, Vec2::new(0.0, -4.0), mask)
+            .expect("triangle should land on tile");
+        assert_eq!(tref.cell_xy, glam::UVec2::ZERO);
+        assert!((hit.toi - 0.5).abs() < 1e-3, "toi={}", hit.toi);
+        assert!((hit.normal - Vec2::Y).length() < 1e-3);
+        assert!((hit.impact_speed - 4.0).abs() < 1e-3);
+
+        // Diamond whose bounds overlap the tile corner but whose edge clears it
+        let diamond = [
+            Vec2::new(1.5, -2.4),
+            Vec2::new(2.4, -1.5),
+            Vec2::new(1.5, -0.6),
+            Vec2::new(0.6, -1.5),
+        ];
+        assert!(w.query_polygon_tiles(&diamond, mask).is_empty());
+        let shifted: Vec<Vec2> = diamond.iter().map(|&p| p - Vec2::splat(0.2)).collect();
+        assert_eq!(w.query_polygon_tiles(&shifted, mask).len(), 1);
+        assert!(w.query_polygon_tiles(&diamond[..2], mask).is_empty());
+    }
+
+    #[test]
+    fn test_grid_occupancy_histogram() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        for p in [[5.5, 5.5], [5.6, 5.6], [-3.5, 2.5], [-3.4, 2.6], [9.5, 0.5]] {
+            w.push_point(p, Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let h = w.grid_occupancy_histogram();
+        assert_eq!(h.len(), GRID_HISTOGRAM_BUCKETS);
+        // Bounds span cells x -4..=9, y 0..=5: 84 cells, 3 occupied
+        assert_eq!(&h[..3], &[81, 1, 2]);
+
+        w.begin_frame();
+        for i in 0..40 {
+            w.push_point([0.5, 0.01 * i as f32], Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let h = w.grid_occupancy_histogram();
+        assert_eq!(h[GRID_HISTOGRAM_BUCKETS - 1], 1);
+        assert_eq!(h.iter().sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_tile_world_key_survives_reattach() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1u8; 4];
+        let desc = |origin: Vec2| TileMapDesc {
+            origin,
+            cell: 1.0,
+            width: 2,
+            height: 2,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        let a = w.attach_tilemap(desc(Vec2::new(-2.0, 0.0)));
+        let b = w.attach_tilemap(desc(Vec2::new(-1.0, 1.0)));
+        let ta = TileRef {
+            map: a,
+            cell_xy: glam::UVec2::new(1, 1),
+        };
+        let tb = TileRef {
+            map: b,
+            cell_xy: glam::UVec2::new(0, 0),
+        };
+        let key = w.tile_world_key(ta).unwrap();
+        assert_eq!(w.tile_world_key(tb), Some(key));
+        assert_eq!(key, ((-1i32 as u32 as u64) << 32) | 1);
+        w.detach_tilemap(a);
+        let c = w.attach_tilemap(desc(Vec2::new(-1.0, 1.0)));
+        assert_eq!(
+            w.tile_world_key(TileRef {
+                map: c,
+                cell_xy: glam::UVec2::ZERO
+            }),
+            Some(key)
+        );
+        assert_eq!(
+            w.tile_world_key(TileRef {
+                map: c,
+                cell_xy: glam::UVec2::new(5, 0)
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_tile_world_key_exact_at_large_origins() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1u8; 4];
+        // f32 spacing is 2.0 out here, so tile corners can't tell neighbors apart
+        let desc = |origin: Vec2| TileMapDesc::new(origin, 1.0, 4, 1, &solids, LayerMask::all());
+        let a = w.attach_tilemap(desc(Vec2::new(3.0e7, -3.0e7)));
+        let b = w.attach_tilemap(desc(Vec2::new(3.0e7 + 2.0, -3.0e7)));
+        let key = |map, x| {
+            w.tile_world_key(TileRef {
+                map,
+                cell_xy: glam::UVec2::new(x, 0),
+            })
+            .unwrap()
+        };
+        let keys: Vec<u64> = (0..4).map(|x| key(a, x)).collect();
+        for (x, &k) in keys.iter().enumerate() {
+            let cell_x = 30_000_000u32 + x as u32;
+            assert_eq!(k, ((cell_x as u64) << 32) | (-30_000_000i32 as u32 as u64));
+        }
+        assert_eq!(key(b, 0), keys[2]);
+        assert_eq!(key(b, 1), keys[3]);
+    }
+
+    #[test]
+    fn test_circle_tile_sweep_rounds_corners() {
+        let solids = vec![1u8];
+        let mask = LayerMask::simple(1, 2);
+        let sweep = |square: bool| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                square_circle_tile_sweep: square,
+                ..cfg()
+            });
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 1,
+                height: 1,
+                solids: &solids,
+                mask: LayerMask::simple(2, 1),
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.sweep_circle_tiles(Vec2::new(-1.0, 1.3), 0.5, Vec2::new(2.0, 0.0), mask)
+                .unwrap()
+                .1
+        };
+        // Passing over the top-left corner: the circle touches at x = -0.4, the square at -0.5
+        let exact = sweep(false);
+        assert!((exact.toi - 0.3).abs() < 1e-5, "toi={}", exact.toi);
+        assert!((exact.normal - Vec2::new(-0.8, 0.6)).length() < 1e-4);
+        assert!((exact.contact - Vec2::new(0.0, 1.0)).length() < 1e-4);
+        let square = sweep(true);
+        assert!((square.toi - 0.25).abs() < 1e-3, "toi={}", square.toi);
+        assert!((square.normal + Vec2::X).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_deepest_overlap_picks_max_depth() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(-0.5, -1.4),
+            cell: 1.0,
+            width: 1,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(0.8, 0.0),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        w.begin_frame();
+        let me = w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        let other = w.push_aabb(
+            Vec2::new(-0.9, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        w.end_frame();
+        // Depths: collider 0.1, tile 0.1 (from below), static 0.2
+        let (body, ov) = w.deepest_overlap(me, mask).unwrap();
+        assert!(matches!(body, BodyRef::Static(_)));
+        assert!((ov.depth - 0.2).abs() < 1e-5);
+        assert!((ov.normal + Vec2::X).length() < 1e-6);
+        // Masked-out bodies are skipped
+        assert!(w.deepest_overlap(me, LayerMask::simple(1, 2)).is_none());
+        let (body, _) = w.deepest_overlap(other, mask).unwrap();
+        assert!(matches!(body, BodyRef::Collider(id) if id == me));
+    }
+
+    #[test]
+    fn test_collider_overlap_flags_start_embedded() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        // Penetrating pair and a merely touching pair
+        w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, Some(1));
+        w.push_aabb(
+            Vec2::new(0.8, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(2),
+        );
+        w.push_aabb(
+            Vec2::new(10.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(3),
+        );
+        w.push_aabb(
+            Vec2::new(11.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(4),
+        );
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 2);
+        for ev in &evs {
+            let ov = ev.overlap.unwrap();
+            assert_eq!(
+                ov.hint.start_embedded,
+                ev.a_key == Some(1) || ev.b_key == Some(1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_memory_stats_retains_capacity() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        for i in 0..64 {
+            w.push_point([i as f32 * 3.0, 0.0], Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let built = w.memory_stats();
+        assert!(built.entries_cap >= 64 && built.aabbs_cap >= 64);
+        assert!(built.grid_buckets >= 64 && built.grid_bucket_cap_sum >= 64);
+        // Clearing keeps the allocations; the emptied grid holds no lists
+        w.begin_frame();
+        let cleared = w.memory_stats();
+        assert_eq!(cleared.entries_cap, built.entries_cap);
+        assert_eq!(cleared.grid_buckets, built.grid_buckets);
+        assert_eq!(cleared.grid_bucket_cap_sum, 0);
+    }
+
+    #[test]
+    fn test_grid_shrink_interval_releases_capacity() {
+        let mut w = PhysicsWorld::new(WorldConfig {
+            grid_shrink_interval: 2,
+            ..cfg()
+        });
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        for i in 0..256 {
+            w.push_point([i as f32 * 3.0, 0.0], Vec2::ZERO, mask, None);
+        }
+        w.end_frame();
+        let busy = w.memory_stats().grid_buckets;
+        // frame_counter 1 -> 2 shrinks; the next one only clears
+        w.begin_frame();
+        assert!(w.memory_stats().grid_buckets < busy);
+        w.push_point([0.5, 0.5], Vec2::ZERO, mask, None);
+        w.end_frame();
+        let small = w.memory_stats().grid_buckets;
+        w.begin_frame();
+        assert_eq!(w.memory_stats().grid_buckets, small);
+    }
+
+    #[test]
+    fn test_non_finite_colliders_are_skipped() {
+        for broadphase in [Broadphase::Grid, Broadphase::Bvh, Broadphase::Sap] {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                broadphase,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            let solids = vec![1u8];
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::new(5.0, 5.0),
+                cell: 1.0,
+                width: 1,
+                height: 1,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.begin_frame();
+            let ok = w.push_circle(Vec2::ZERO, 0.5, Vec2::ZERO, mask, None);
+            w.push_circle(Vec2::new(0.5, 0.0), 0.5, Vec2::ZERO, mask, None);
+            let nan = w.push_circle(Vec2::NAN, 0.5, Vec2::ZERO, mask, None);
+            let huge = w.push_aabb(
+                Vec2::ZERO,
+                Vec2::splat(f32::INFINITY),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            let fast = w.push_point(Vec2::ZERO, Vec2::new(f32::INFINITY, 0.0), mask, None);
+            w.end_frame();
+            assert_eq!(w.invalid_colliders(), vec![nan, huge, fast]);
+            assert_eq!(w.debug_stats().invalid_entries, 3);
+            w.generate_events();
+            let evs = w.drain_events();
+            assert_eq!(evs.len(), 1, "{broadphase:?}");
+            assert_eq!(w.query_point_all(Vec2::ZERO, mask).len(), 2);
+            assert!(
+                w.query_point_all(Vec2::ZERO, mask)
+                    .iter()
+                    .any(|(b, _)| *b == BodyRef::Collider(ok))
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_overlaps_by_depth() {
+        let mut w = PhysicsWorld::new(WorldConfig {
+            sort_overlaps_by_depth: true,
+            ..cfg()
+        });
+        let player = LayerMask::simple(1, 2);
+        let zone = LayerMask::simple(2, 1);
+        w.begin_frame();
+        w.push_circle(Vec2::ZERO, 0.5, Vec2::ZERO, player, Some(0));
+        for (key, x) in [(1u64, 0.9f32), (2, 0.2), (3, 0.6)] {
+            w.push_aabb(
+                Vec2::new(x, 0.0),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                zone,
+                Some(key),
+            );
+        }
+        w.end_frame();
+        w.generate_events();
+        let keys: Vec<_> = w
+            .drain_events()
+            .iter()
+            .map(|e| e.b_key.max(e.a_key))
+            .collect();
+        assert_eq!(keys, vec![Some(2), Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn test_cast_pair_states() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        // Overlapping and moving away still reports the overlap
+        let b = w.push_aabb(Vec2::new(0.8, 0.0), Vec2::splat(0.5), Vec2::X, mask, None);
+        let c = w.push_aabb(
+            Vec2::new(-3.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::new(4.0, 0.0),
+            mask,
+            None,
+        );
+        let d = w.push_aabb(Vec2::new(0.0, 5.0), Vec2::splat(0.5), Vec2::Y, mask, None);
+        w.end_frame();
+        assert!(
+            matches!(w.cast_pair(a, b), CastResult::Overlapping(ov) if (ov.depth - 0.2).abs() < 1e-6)
+        );
+        assert!(matches!(w.cast_pair(a, c), CastResult::WillHit(h) if (h.toi - 0.5).abs() < 1e-6));
+        assert!(matches!(w.cast_pair(a, d), CastResult::Separated));
+    }
+
+    #[test]
+    fn test_motion_from_delta_sweeps_exact_displacement() {
+        let mut w = PhysicsWorld::new(WorldConfig {
+            dt: 1.0 / 60.0,
+            ..cfg()
+        });
+        let mask = LayerMask::simple(1, 1);
+        let desc = |x: f32| ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(x, 0.0),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        w.begin_frame();
+        let a = w.push(desc(0.0), Motion::from_delta(Vec2::new(4.0, 0.0)));
+        let b = w.push(desc(3.0), Motion::default());
+        w.end_frame();
+        let hit = w.sweep_pair(a, b).unwrap();
+        assert!((hit.toi - 0.5).abs() < 1e-6);
+        w.generate_events();
+        let ev = w.drain_events()[0];
+        assert!((ev.sweep.unwrap().impact_speed - 240.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_swept_aabb_matches_frame_bounds() {
+        let kind = ColliderKind::Circle { radius: 0.5 };
+        let (center, vel) = (Vec2::new(1.0, 2.0), Vec2::new(-4.0, 2.0));
+        let (min, max) = PhysicsWorld::swept_aabb(kind, center, vel, 0.5, true);
+        assert_eq!((min, max), (Vec2::new(-1.5, 1.5), Vec2::new(1.5, 3.5)));
+        let (min, max) = PhysicsWorld::swept_aabb(kind, center, vel, 0.5, false);
+        assert_eq!((min, max), (Vec2::new(0.5, 1.5), Vec2::new(1.5, 2.5)));
+
+        let mut w = PhysicsWorld::new(WorldConfig { dt: 0.5, ..cfg() });
+        w.begin_frame();
+        let id = w.push_circle(center, 0.5, vel, LayerMask::simple(1, 1), None);
+        w.end_frame();
+        assert_eq!(
+            w.aabbs[id.0 as usize],
+            PhysicsWorld::swept_aabb(kind, center, vel, 0.5, true)
+        );
+    }
+
+    #[test]
+    fn test_query_circle_prunes_corner_cells() {
+        let keep = PhysicsWorld::cell_in_circle(Vec2::ZERO, 5.0);
+        assert!(!keep(Vec2::new(4.0, 4.0), Vec2::new(5.0, 5.0)));
+        assert!(keep(Vec2::new(0.0, 4.0), Vec2::new(1.0, 5.0)));
+        assert!(keep(Vec2::new(-1.0, -1.0), Vec2::new(0.0, 0.0)));
+
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let mask = LayerMask::simple(1, 1);
+        let corner = w.push_aabb(
+            Vec2::new(4.5, 4.5),
+            Vec2::splat(0.1),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        let edge = w.push_aabb(
+            Vec2::new(0.5, 4.85),
+            Vec2::splat(0.1),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        w.end_frame();
+        let ids: Vec<FrameId> = w
+            .query_circle(Vec2::ZERO, 5.0, mask)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec![edge]);
+        assert!(!ids.contains(&corner));
+    }
+
+    #[test]
+    fn test_grouped_queries_split_by_kind() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8, 0, 0, 0];
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 2,
+            height: 2,
+            solids: &solids,
+            mask,
+            user_key: Some(9),
+            solid_mask: 0xFF,
+        });
+        let st = w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(3.0, 0.5),
+            mask,
+            user_key: Some(7),
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        w.begin_frame();
+        let id = w.push_circle(Vec2::new(1.5, 0.5), 0.25, Vec2::ZERO, mask, Some(5));
+        w.end_frame();
+        let g = w.query_aabb_grouped(Vec2::new(1.5, 0.5), Vec2::new(2.0, 0.5), mask);
+        assert_eq!(g.colliders, vec![(id, Some(5))]);
+        assert_eq!(g.statics, vec![(st, Some(7))]);
+        let tile = TileRef {
+            map,
+            cell_xy: glam::UVec2::new(0, 0),
+        };
+        assert_eq!(g.tiles, vec![(tile, Some(9))]);
+        let flat = w.query_aabb_all(Vec2::new(1.5, 0.5), Vec2::new(2.0, 0.5), mask);
+        assert_eq!(g.len(), flat.len());
+        let p = w.query_point_grouped(Vec2::new(0.5, 0.5), mask);
+        assert!(p.colliders.is_empty() && p.statics.is_empty());
+        assert_eq!(p.tiles.len(), 1);
+        let c = w.query_circle_grouped(Vec2::new(1.5, 0.5), 0.1, mask);
+        assert_eq!(c.colliders, vec![(id, Some(5))]);
+        assert_eq!(c.len(), 1);
+    }
+
+    #[test]
+    fn test_toi_in_seconds_scales_sweeps_by_dt() {
+        let run = |toi_in_seconds: bool| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                dt: 0.5,
+                toi_in_seconds,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            let solids = vec![0u8, 0, 1];
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::new(0.0, 5.0),
+                cell: 1.0,
+                width: 3,
+                height: 1,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.begin_frame();
+            // 4 units/s over dt 0.5 = 2 units; 1 unit gap → half the frame, 0.25 s
+            let vel = Vec2::new(4.0, 0.0);
+            let a = w.push_aabb(Vec2::new(0.5, 0.5), Vec2::splat(0.5), vel, mask, None);
+            let b = w.push_aabb(
+                Vec2::new(2.5, 0.5),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.end_frame();
+            w.generate_events();
+            let ev = w.drain_events();
+            assert_eq!(ev.len(), 1);
+            let tile = w.sweep_aabb_tiles(Vec2::new(0.5, 5.5), Vec2::splat(0.5), vel, mask);
+            [
+                ev[0].sweep.unwrap().toi,
+                w.sweep_pair(a, b).unwrap().toi,
+                tile.unwrap().1.toi,
+            ]
+        };
+        for toi in run(false) {
+            assert!((toi - 0.5).abs() < 1e-3, "{toi}");
+        }
+        for toi in run(true) {
+            assert!((toi - 0.25).abs() < 1e-3, "{toi}");
+        }
+    }
+
+    #[test]
+    fn test_discrete_mode_ignores_velocity() {
+        let mut w = PhysicsWorld::new(WorldConfig {
+            enable_sweep_events: false,
+            ..cfg()
+        });
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        // Would sweep into b; discrete mode only sees the start positions
+        let a = w.push_aabb(
+            Vec2::ZERO,
+            Vec2::splat(0.5),
+            Vec2::new(10.0, 0.0),
+            mask,
+            None,
+        );
+        w.push_aabb(
+            Vec2::new(5.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        w.end_frame();
+        assert_eq!(w.aabbs[a.0 as usize], (Vec2::splat(-0.5), Vec2::splat(0.5)));
+        assert_eq!(w.debug_stats().candidate_pairs, 0);
+        w.generate_events();
+        assert!(w.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_explain_pair_reports_branches() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_aabb(
+            Vec2::ZERO,
+            Vec2::splat(0.5),
+            Vec2::new(2.0, 0.0),
+            mask,
+            None,
+        );
+        let b = w.push_aabb(
+            Vec2::new(2.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        let c = w.push_aabb(
+            Vec2::new(0.0, 0.5),
+            Vec2::splat(0.5),
+            Vec2::new(2.0, 0.0),
+            mask,
+            None,
+        );
+        let far = w.push_aabb(
+            Vec2::new(50.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        let shy = w.push_aabb(
+            Vec2::new(2.0, 1.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(2, 2),
+            None,
+        );
+        w.end_frame();
+
+        let ab = w.explain_pair(a, b);
+        assert!(ab.mask_allowed && ab.broadphase_candidate && ab.sweep_tested);
+        assert_eq!(ab.rel_vel, Vec2::new(2.0, 0.0));
+        assert!((ab.sweep.unwrap().toi - 0.5).abs() < 1e-4);
+        // SweepThenOverlapFallback: the sweep hit suppresses the overlap test
+        assert!(!ab.overlap_tested && ab.overlap.is_none());
+        assert_eq!(ab.miss, None);
+
+        // Same velocity: no sweep, overlap at start positions
+        let ac = w.explain_pair(a, c);
+        assert!(!ac.sweep_tested && ac.overlap_tested);
+        assert!(ac.overlap.is_some());
+        assert_eq!(ac.miss, None);
+
+        assert_eq!(w.explain_pair(a, far).miss, Some(PairMiss::NotCandidates));
+        assert_eq!(w.explain_pair(b, shy).miss, Some(PairMiss::MaskRejected));
+        assert_eq!(
+            w.explain_pair(a, FrameId(99)).miss,
+            Some(PairMiss::UnknownId)
+        );
+    }
+
+    #[test]
+    fn test_overlap_tiles_at_rest() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        // Floor row y=0 solid, row y=1 empty
+        let solids = vec![1u8, 1, 1, 0, 0, 0];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 3,
+            height: 2,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        // Box sunk 0.1 into the floor, straddling two tiles
+        let hits = w.overlap_aabb_tiles(Vec2::new(1.0, 1.4), Vec2::splat(0.5), mask);
+        assert_eq!(hits.len(), 2);
+        for (_, ov) in &hits {
+            assert_eq!(ov.normal, Vec2::Y);
+            assert!((ov.depth - 0.1).abs() < 1e-5);
+            assert!(ov.hint.start_embedded);
+        }
+        assert!(
+            w.overlap_aabb_tiles(Vec2::new(1.0, 1.6), Vec2::splat(0.5), mask)
+                .is_empty()
+        );
+        // Circle near a tile corner: the box test passes, the exact test does not
+        let c = Vec2::new(3.0, 1.0) + Vec2::splat(0.45);
+        assert!(w.overlap_circle_tiles(c, 0.5, mask).is_empty());
+        let hits = w.overlap_circle_tiles(Vec2::new(1.5, 1.3), 0.5, mask);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.cell_xy, glam::UVec2::new(1, 0));
+        assert!((hits[0].1.depth - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_raycast_all_tie_preference() {
+        let run = |ray_tie_preference: TiePreference| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                ray_tie_preference,
+                ..cfg()
+            });
+            let mask = LayerMask::simple(1, 1);
+            let solids = vec![0u8, 0, 1];
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 3,
+                height: 1,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.begin_frame();
+            // Collider face flush with the tile face at x = 2
+            w.push_aabb(
+                Vec2::new(2.5, 0.5),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.end_frame();
+            let ray = w
+                .raycast_all(Vec2::new(0.0, 0.5), Vec2::X, mask, 10.0)
+                .unwrap();
+            let cast = w
+                .cast(
+                    ColliderKind::Circle { radius: 0.25 },
+                    Vec2::new(0.0, 0.5),
+                    Vec2::X,
+                    mask,
+                    10.0,
+                )
+                .unwrap();
+            assert_eq!(ray.1.toi, 2.0);
+            (ray.0, cast.0)
+        };
+        let (ray, cast) = run(TiePreference::Collider);
+        assert!(matches!(ray, BodyRef::Collider(_)));
+        assert!(matches!(cast, BodyRef::Collider(_)));
+        let (ray, cast) = run(TiePreference::Tile);
+        assert!(matches!(ray, BodyRef::Tile(_)));
+        assert!(matches!(cast, BodyRef::Tile(_)));
+    }
+
+    #[test]
+    fn test_events_borrow_survives_until_begin_frame() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        w.push_aabb(
+            Vec2::new(0.5, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        // Two readers see the same events
+        assert_eq!(w.events().len(), 1);
+        assert_eq!(w.events().len(), 1);
+        w.begin_frame();
+        assert!(w.events().is_empty());
+    }
+
+    #[test]
+    fn test_sweep_and_emit_probe() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![0u8, 0, 0, 0, 1];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 5,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(1, 1),
+            user_key: Some(3),
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        let target = w.push_aabb(
+            Vec2::new(2.5, 0.5),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(2, 1),
+            None,
+        );
+        w.end_frame();
+        let desc = |mask| ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(0.5, 0.5),
+            mask,
+            user_key: Some(77),
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        let motion = Motion {
+            vel: Vec2::new(4.0, 0.0),
+            ..Motion::default()
+        };
+        // Reaches the collider (gap 1) before the tile (gap 3)
+        let ev = w
+            .sweep_and_emit(desc(LayerMask::simple(1, 3)), motion)
+            .unwrap();
+        assert!(matches!(ev.kind, EventKind::Sweep));
+        assert_eq!(ev.a, BodyRef::Collider(FrameId::PROBE));
+        assert_eq!(ev.b, BodyRef::Collider(target));
+        assert_eq!(ev.a_key, Some(77));
+        assert!((ev.sweep.unwrap().toi - 0.25).abs() < 1e-4);
+        // Masked off the collider: the tile is next
+        let ev = w
+            .sweep_and_emit(desc(LayerMask::simple(1, 1)), motion)
+            .unwrap();
+        assert!(matches!(ev.b, BodyRef::Tile(_)));
+        assert_eq!(ev.b_key, Some(3));
+        assert!((ev.sweep.unwrap().toi - 0.75).abs() < 1e-3);
+        // Buffered like frame events; the probe never joins the frame
+        assert_eq!(w.events().len(), 2);
+        assert_eq!(w.debug_stats().entries, 1);
+    }
+
+    #[test]
+    fn test_zero_and_negative_radius_circles_act_as_points() {
+        let mask = LayerMask::simple(1, 1);
+        let run = |kind: ColliderKind| {
+            let mut w = PhysicsWorld::new(cfg());
+            w.begin_frame();
+            let desc = |center| ColliderDesc {
+                kind,
+                center,
+                mask,
+                user_key: None,
+                broadphase: BroadphaseMode::Grid,
+                tag: 0,
+                event_targets: EventTargets::Both,
+            };
+            let inside = w.push(desc(Vec2::new(0.2, 0.1)), Motion::default());
+            let mover = w.push(
+                desc(Vec2::new(-3.0, 0.0)),
+                Motion {
+                    vel: Vec2::new(4.0, 0.0),
+                    ..Motion::default()
+                },
+            );
+            let box_id = w.push_aabb(Vec2::ZERO, Vec2::splat(1.0), Vec2::ZERO, mask, None);
+            let far = w.push_circle(Vec2::new(0.2, 2.1), 0.5, Vec2::ZERO, mask, None);
+            w.end_frame();
+            let ov = w.overlap_pair(inside, box_id).unwrap();
+            let hit = w.sweep_pair(mover, box_id).unwrap();
+            assert!(w.overlap_pair(inside, far).is_none());
+            (
+                ov.normal,
+                ov.depth,
+                ov.contact,
+                hit.toi,
+                hit.normal,
+                hit.contact,
+            )
+        };
+        let point = run(ColliderKind::Point);
+        assert_eq!(run(ColliderKind::Circle { radius: 0.0 }), point);
+        // (-3 + 0.5)^2 would otherwise exceed the squared distance to the far circle
+        assert_eq!(run(ColliderKind::Circle { radius: -3.0 }), point);
+    }
+
+    #[test]
+    fn test_mutual_consent_rejections_counted() {
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        // `a` wants `b`, but `b` doesn't list `a`'s layer
+        w.push_aabb(
+            Vec2::ZERO,
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(1, 2),
+            None,
+        );
+        w.push_aabb(
+            Vec2::new(0.5, 0.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(2, 0),
+            None,
+        );
+        // Neither side consents: not a one-sided mistake
+        w.push_aabb(
+            Vec2::new(0.0, 0.5),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            LayerMask::simple(4, 0),
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        assert!(w.drain_events().is_empty());
+        assert_eq!(w.debug_stats().mutual_consent_rejections, 1);
+        w.begin_frame();
+        assert_eq!(w.debug_stats().mutual_consent_rejections, 0);
+    }
+
+    #[test]
+    fn test_raycast_skip_start_solid() {
+        let run = |raycast_skip_start_solid: bool| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                raycast_skip_start_solid,
+                ..cfg()
+            });
+            // Solid, empty, solid
+            let solids = vec![1u8, 0, 1];
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 3,
+                height: 1,
+                solids: &solids,
+                mask: LayerMask::simple(1, 1),
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.raycast_tiles(Vec2::new(0.5, 0.5), Vec2::X, 10.0, LayerMask::simple(1, 1))
+        };
+        let (t, hit, _) = run(false).unwrap();
+        assert_eq!((t.cell_xy.x, hit.toi, hit.normal), (0, 0.0, Vec2::ZERO));
+        let (t, hit, _) = run(true).unwrap();
+        assert_eq!(t.cell_xy.x, 2);
+        assert!((hit.toi - 1.5).abs() < 1e-5);
+        assert_eq!(hit.normal, -Vec2::X);
+    }
+
+    #[test]
+    fn test_contact_id_stable_per_feature() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let mut frame = |a_center: Vec2| {
+            w.begin_frame();
+            w.push_aabb(a_center, Vec2::splat(0.5), Vec2::ZERO, mask, Some(1));
+            w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, Some(2));
+            w.end_frame();
+            w.generate_events();
+            let ev = w.drain_events();
+            assert_eq!(ev.len(), 1);
+            (ev[0].pair_id, ev[0].contact_id)
+        };
+        let top = frame(Vec2::new(0.0, 0.9));
+        // Slid a little but still on the same face: same id
+        assert_eq!(frame(Vec2::new(0.2, 0.9)), top);
+        // Now against the side face: same pair, different contact
+        let side = frame(Vec2::new(0.9, 0.0));
+        assert_eq!(side.0, top.0);
+        assert_ne!(side.1, top.1);
+    }
+
+    #[test]
+    fn test_round_sweep_contact_id_ignores_approach_angle() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let mut solids = vec![0u8; 16];
+        solids[5] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(10.0, 0.0),
+            cell: 1.0,
+            width: 4,
+            height: 4,
+            solids: &solids,
+            mask,
+            user_key: Some(9),
+            solid_mask: 0xFF,
+        });
+        let mut sweep_ids = |offset: Vec2, vel: Vec2| {
+            w.begin_frame();
+            // A circle into a resting circle, and another into tile (1, 1) at (11, 1)..(12, 2)
+            w.push_circle(offset, 0.25, vel, mask, Some(1));
+            w.push_circle(Vec2::ZERO, 0.25, Vec2::ZERO, mask, Some(2));
+            w.push_circle(Vec2::new(11.0, 1.5) + offset, 0.25, vel, mask, Some(3));
+            w.end_frame();
+            w.generate_events();
+            let mut ids: Vec<_> = w
+                .drain_events()
+                .iter()
+                .filter(|e| matches!(e.kind, EventKind::Sweep))
+                .map(|e| (e.pair_id, e.contact_id, e.sweep.unwrap().normal))
+                .collect();
+            ids.sort_unstable_by_key(|&(p, ..)| p);
+            assert_eq!(ids.len(), 2);
+            ids
+        };
+        let head_on = sweep_ids(Vec2::new(-1.5, 0.0), Vec2::new(1.5, 0.0));
+        let diagonal = sweep_ids(Vec2::new(-1.5, -2.0), Vec2::new(1.5, 1.5));
+        for ((pa, ca, na), (pb, cb, nb)) in head_on.into_iter().zip(diagonal) {
+            // Different box features along the normals, but round shapes have one: same contact
+            assert!((na - nb).length() > 0.1);
+            assert_eq!((pa, ca), (pb, cb));
+        }
+    }
+
+    #[test]
+    fn test_query_aabb_filtered_by_key() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        w.begin_frame();
+        let a = w.push_circle(Vec2::new(0.0, 0.0), 0.25, Vec2::ZERO, mask, Some(1));
+        let b = w.push_circle(Vec2::new(1.0, 0.0), 0.25, Vec2::ZERO, mask, Some(2));
+        let c = w.push_circle(Vec2::new(2.0, 0.0), 0.25, Vec2::ZERO, mask, None);
+        w.end_frame();
+        let (center, he) = (Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+        let odd = |k: ColKey| k % 2 == 1;
+        let mut hits = w.query_aabb_filtered(center, he, mask, false, odd);
+        hits.sort_by_key(|h| h.0);
+        assert_eq!(hits, vec![(a, Some(1))]);
+        let mut hits = w.query_aabb_filtered(center, he, mask, true, odd);
+        hits.sort_by_key(|h| h.0);
+        assert_eq!(hits, vec![(a, Some(1)), (c, None)]);
+        let all = w.query_aabb_filtered(center, he, mask, true, |_| true);
+        assert_eq!(all.len(), w.query_aabb(center, he, mask).len());
+        assert!(all.contains(&(b, Some(2))));
+    }
+
+    #[test]
+    fn test_motion_dt_overrides_world_dt() {
+        let mask = LayerMask::simple(1, 1);
+        let circle = |x: f32, key| ColliderDesc {
+            kind: ColliderKind::Circle { radius: 0.5 },
+            center: Vec2::new(x, 0.0),
+            mask,
+            user_key: Some(key),
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        // Bullet-time: 4 units/s over a quarter step only covers 1 unit of the 2-unit gap
+        let a = w.push(circle(0.0, 1), Motion::with_dt(Vec2::new(4.0, 0.0), 0.25));
+        let b = w.push(circle(3.0, 2), Motion::default());
+        w.end_frame();
+        assert!(w.sweep_by_key(1, 2).is_none());
+        assert_eq!(w.explain_pair(a, b).rel_vel, Vec2::new(1.0, 0.0));
+        w.begin_frame();
+        w.push(circle(0.0, 1), Motion::with_dt(Vec2::new(4.0, 0.0), 1.0));
+        w.push(circle(3.0, 2), Motion::default());
+        w.end_frame();
+        let hit = w.sweep_by_key(1, 2).expect("full-rate sweep hits");
+        assert!((hit.toi - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_set_config_applies_at_begin_frame() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let st = w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(6.5, 0.5),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        w.begin_frame();
+        w.end_frame();
+        w.set_config(WorldConfig {
+            cell_size: 4.0,
+            ..cfg()
+        });
+        // Still the old grid until the next frame
+        assert_eq!(w.config().cell_size, 1.0);
+        w.begin_frame();
+        assert_eq!(w.config().cell_size, 4.0);
+        let id = w.push_circle(Vec2::new(5.5, 0.5), 0.25, Vec2::ZERO, mask, None);
+        w.end_frame();
+        let hits = w.query_aabb_all(Vec2::new(6.5, 0.5), Vec2::splat(0.1), mask);
+        assert_eq!(hits, vec![(BodyRef::Static(st), None)]);
+        let hits = w.query_aabb_all(Vec2::new(6.0, 0.5), Vec2::new(0.6, 0.1), mask);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.contains(&(BodyRef::Collider(id), None)));
+    }
+
+    #[test]
+    fn test_circle_on_floor_manifold_point_is_stable() {
+        let mask = LayerMask::simple(1, 1);
+        let contact_at = |center: Vec2| {
+            let mut w = PhysicsWorld::new(cfg());
+            w.begin_frame();
+            let ball = w.push_circle(center, 0.5, Vec2::ZERO, mask, None);
+            let floor = w.push_aabb(
+                Vec2::new(0.0, -1.0),
+                Vec2::new(4.0, 1.0),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.end_frame();
+            w.manifold_pair(ball, floor).expect("resting contact")
+        };
+        // Jittering into and out of the floor keeps the same point on its top face
+        let m0 = contact_at(Vec2::new(0.25, 0.49));
+        let m1 = contact_at(Vec2::new(0.25, 0.45));
+        assert_eq!(m0.point, Vec2::new(0.25, 0.0));
+        assert_eq!(m1.point, m0.point);
+        assert!(m1.depth > m0.depth);
+        assert_eq!(m0.normal, Vec2::Y);
+        // Sliding along the floor only moves the point tangentially
+        let m2 = contact_at(Vec2::new(0.27, 0.47));
+        assert_eq!(m2.point, Vec2::new(0.27, 0.0));
+        // Circle pairs use the point on B's surface too
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let a = w.push_circle(Vec2::new(0.0, 0.9), 0.5, Vec2::ZERO, mask, None);
+        let b = w.push_circle(Vec2::ZERO, 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        let m = w.manifold_pair(a, b).unwrap();
+        assert!((m.point - Vec2::new(0.0, 0.5)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_tile_sweep_last_free_pos_is_clear() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mut solids = vec![0u8; 16];
+        solids[5] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 16,
+            height: 1,
+            solids: &solids,
+            mask: LayerMask::simple(2, 1),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mask = LayerMask::simple(1, 2);
+        let he = Vec2::new(0.05, 0.2);
+        let (_, hit, _) = w
+            .sweep_aabb_tiles(Vec2::new(0.5, 0.5), he, Vec2::new(9.0, 0.0), mask)
+            .unwrap();
+        let free = hit.hint.last_free_pos.expect("start is free");
+        assert!(free.x < 5.0 - he.x && free.x > 4.0);
+        let hits = w.query_aabb_all(free, he, mask);
+        assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
+        // Starting inside the solid: nothing was ever free
+        let (_, hit, _) = w
+            .sweep_aabb_tiles(Vec2::new(5.5, 0.5), he, Vec2::new(1.0, 0.0), mask)
+            .unwrap();
+        assert!(hit.hint.last_free_pos.is_none());
+    }
+
+    #[test]
+    fn test_tile_surface_normal_nearest_face() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        assert!(w.tile_surface_normal(Vec2::ZERO, mask).is_none());
+        // 8x4 map: floor on row 0, a wall column at x = 6
+        let (width, height) = (8u32, 4u32);
+        let mut solids = vec![0u8; (width * height) as usize];
+        for x in 0..width {
+            solids[x as usize] = 1;
+        }
+        for y in 0..height {
+            solids[(y * width + 6) as usize] = 1;
+        }
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width,
+            height,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let at = |x, y| TileRef {
+            map,
+            cell_xy: glam::UVec2::new(x, y),
+        };
+        // Just above the floor, far from the wall
+        let (t, n) = w.tile_surface_normal(Vec2::new(1.5, 1.2), mask).unwrap();
+        assert_eq!((t, n), (at(1, 0), Vec2::Y));
+        // Closer to the wall's left face than to the floor
+        let (t, n) = w.tile_surface_normal(Vec2::new(5.8, 2.5), mask).unwrap();
+        assert_eq!((t, n), (at(6, 2), Vec2::NEG_X));
+        // Outside the map, past the wall
+        let (t, n) = w.tile_surface_normal(Vec2::new(8.5, 3.5), mask).unwrap();
+        assert_eq!((t, n), (at(6, 3), Vec2::X));
+        // Inside the floor near its top: minimum-penetration face
+        let (t, n) = w.tile_surface_normal(Vec2::new(2.5, 0.9), mask).unwrap();
+        assert_eq!((t, n), (at(2, 0), Vec2::Y));
+        // Masked-out maps are skipped
+        assert!(
+            w.tile_surface_normal(Vec2::new(1.5, 1.2), LayerMask::simple(2, 2))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_out_of_bounds_uses_swept_bounds() {
+        let mask = LayerMask::simple(1, 1);
+        let mut w = PhysicsWorld::new(WorldConfig {
+            world_bounds: Some((Vec2::ZERO, Vec2::splat(10.0))),
+            ..cfg()
+        });
+        w.begin_frame();
+        w.push_circle(Vec2::new(5.0, 5.0), 0.5, Vec2::ZERO, mask, None);
+        let gone = w.push_circle(Vec2::new(20.0, 5.0), 0.5, Vec2::ZERO, mask, None);
+        // Starts outside but sweeps into the play area this frame
+        w.push_circle(Vec2::new(-5.0, 5.0), 0.5, Vec2::new(10.0, 0.0), mask, None);
+        let bad = w.push_circle(Vec2::new(f32::NAN, 5.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        assert_eq!(w.out_of_bounds(), vec![gone, bad]);
+        w.set_config(cfg());
+        w.begin_frame();
+        w.push_circle(Vec2::new(20.0, 5.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        assert!(w.out_of_bounds().is_empty());
+    }
+
+    #[test]
+    fn test_raycast_desc_resolves_hit_body() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![0u8, 0, 0, 0, 0, 0, 0, 0, 3];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 9,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: Some(4),
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        let id = w.push_aabb(
+            Vec2::new(3.0, 0.5),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            Some(2),
+        );
+        w.end_frame();
+        let (body, hit, desc) = w
+            .raycast_desc(Vec2::new(0.0, 0.5), Vec2::X, mask, 20.0)
+            .unwrap();
+        assert_eq!(body, BodyRef::Collider(id));
+        assert!((hit.toi - 2.5).abs() < 1e-5);
+        let BodyDesc::Shape(d) = desc else {
+            panic!("expected a shape, got {desc:?}");
+        };
+        assert_eq!(d.center, Vec2::new(3.0, 0.5));
+        assert_eq!(d.user_key, Some(2));
+        assert!(
+            matches!(d.kind, ColliderKind::Aabb { half_extents } if half_extents == Vec2::splat(0.5))
+        );
+        // Past the box only the tile at x = 8 remains
+        let (body, _, desc) = w
+            .raycast_desc(Vec2::new(4.0, 0.5), Vec2::X, mask, 20.0)
+            .unwrap();
+        assert!(matches!(body, BodyRef::Tile(_)));
+        let BodyDesc::Tile {
+            user_key,
+            min,
+            cell,
+            bits,
+            ..
+        } = desc
+        else {
+            panic!("expected a tile, got {desc:?}");
+        };
+        assert_eq!(
+            (user_key, min, cell, bits),
+            (Some(4), Vec2::new(8.0, 0.0), 1.0, 3)
+        );
+    }
+
+    #[test]
+    fn test_event_display_summary() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let mut solids = vec![0u8; 8];
+        solids[3] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 8,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        w.push_aabb(
+            Vec2::new(0.5, 0.5),
+            Vec2::splat(0.5),
+            Vec2::new(4.0, 0.0),
+            mask,
+            Some(1),
+        );
+        w.push_circle(Vec2::new(6.5, 0.5), 0.5, Vec2::ZERO, mask, None);
+        w.push_circle(Vec2::new(6.75, 0.5), 0.5, Vec2::ZERO, mask, Some(7));
+        w.end_frame();
+        w.generate_events();
+        let lines: Vec<String> = w.events().iter().map(|e| e.to_string()).collect();
+        assert!(
+            lines.contains(
+                &"Sweep Collider(0,key=1) vs Tile(map=0,cell=3,0) toi=0.5 n=(-1,0)".into()
+            ),
+            "{lines:?}"
+        );
+        assert!(
+            lines.contains(&"Overlap Collider(1) vs Collider(2,key=7) depth=0.75 n=(-1,0)".into()),
+            "{lines:?}"
+        );
+        let ev = w
+            .events()
+            .iter()
+            .find(|e| matches!(e.kind, EventKind::Overlap))
+            .unwrap();
+        assert_eq!(
+            format!("{ev:.2}"),
+            "Overlap Collider(1) vs Collider(2,key=7) depth=0.75 n=(-1.00,0.00)"
+        );
+        assert_eq!(BodyRef::Static(StaticId(4)).to_string(), "Static(4)");
+    }
+
+    #[test]
+    fn test_tags_echoed_in_events() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![0u8, 0, 0, 1];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 4,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let tagged = |x: f32, tag| ColliderDesc {
+            kind: ColliderKind::Circle { radius: 0.5 },
+            center: Vec2::new(x, 0.5),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag,
+            event_targets: EventTargets::Both,
+        };
+        w.begin_frame();
+        // Same tag twice is fine: tags are never looked up
+        w.push(tagged(0.5, 7), Motion::default());
+        w.push(tagged(1.25, 7), Motion::default());
+        w.push(tagged(2.75, 9), Motion::default());
+        w.end_frame();
+        w.generate_events();
+        let ev = w.drain_events();
+        let pair = ev
+            .iter()
+            .find(|e| !matches!(e.b, BodyRef::Tile(_)))
+            .unwrap();
+        assert_eq!((pair.a_tag, pair.b_tag), (7, 7));
+        let tile = ev.iter().find(|e| matches!(e.b, BodyRef::Tile(_))).unwrap();
+        assert_eq!((tile.a_tag, tile.b_tag), (9, 0));
+    }
+
+    #[test]
+    fn test_event_targets_skip_phases() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![0u8, 0, 0, 0, 0, 1];
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 6,
+            height: 1,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let st = w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.25),
+            },
+            center: Vec2::new(1.0, 0.5),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        let bullet = |targets| ColliderDesc {
+            kind: ColliderKind::Circle { radius: 0.25 },
+            center: Vec2::new(0.0, 0.5),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: targets,
+        };
+        // The bullet's path crosses the static, a frame collider and then the solid tile
+        let run = |w: &mut PhysicsWorld, targets| {
+            w.begin_frame();
+            let id = w.push(bullet(targets), Motion::from_delta(Vec2::new(6.0, 0.0)));
+            let target = w.push_circle(Vec2::new(3.0, 0.5), 0.25, Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            let bodies: Vec<BodyRef> = w.drain_events().iter().map(|e| e.b).collect();
+            (id, target, bodies)
+        };
+        let (_, target, all) = run(&mut w, EventTargets::Both);
+        assert_eq!(all.len(), 3);
+        assert!(all.contains(&BodyRef::Static(st)) && all.contains(&BodyRef::Collider(target)));
+        let (id, target, tiles) = run(&mut w, EventTargets::TilesOnly);
+        assert_eq!(tiles.len(), 1);
+        assert!(matches!(tiles[0], BodyRef::Tile(_)));
+        assert_eq!(
+            w.explain_pair(id, target).miss,
+            Some(PairMiss::TargetsExcluded)
+        );
+        // Queries still see a tiles-only collider
+        assert!(!w.query_point(Vec2::new(0.0, 0.5), mask).is_empty());
+        let (_, _, colliders) = run(&mut w, EventTargets::CollidersOnly);
+        assert_eq!(colliders.len(), 2);
+        assert!(!colliders.iter().any(|b| matches!(b, BodyRef::Tile(_))));
+    }
+
+    #[test]
+    fn test_solid_tiles_in_circle_carves_one_map() {
+        let mut w = PhysicsWorld::new(cfg());
+        let solids = vec![1u8; 9];
+        let desc = |mask| TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 3,
+            height: 3,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        };
+        let map = w.attach_tilemap(desc(LayerMask::simple(1, 0)));
+        let other = w.attach_tilemap(desc(LayerMask::simple(2, 2)));
+        let center = Vec2::new(1.5, 1.5);
+        // Corners are ~0.71 from the center, so only the plus shape is hit
+        let cells = w.solid_tiles_in_circle(center, 0.6, map);
+        assert_eq!(
+            cells,
+            vec![
+                glam::UVec2::new(1, 0),
+                glam::UVec2::new(0, 1),
+                glam::UVec2::new(1, 1),
+                glam::UVec2::new(2, 1),
+                glam::UVec2::new(1, 2),
+            ]
+        );
+        for c in &cells {
+            w.update_tiles(map, (c.x, c.y, 1, 1), &[0]);
+        }
+        assert!(w.solid_tiles_in_circle(center, 0.6, map).is_empty());
+        assert_eq!(w.solid_tiles_in_circle(center, 0.6, other).len(), 5);
+        assert!(
+            w.solid_tiles_in_circle(center, 0.6, TileMapRef(9))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_query_aabb_into_reuses_buffers() {
+        let mask = LayerMask::simple(1, 1);
+        let mut seen = std::collections::HashSet::new();
+        let mut out = vec![(FrameId(99), None)];
+        for broadphase in [Broadphase::Grid, Broadphase::Bvh, Broadphase::Sap] {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                broadphase,
+                ..cfg()
+            });
+            w.begin_frame();
+            for i in 0..6 {
+                let c = Vec2::new(i as f32 * 1.5, 0.5);
+                w.push_aabb(c, Vec2::splat(0.5), Vec2::ZERO, mask, Some(i));
+            }
+            w.end_frame();
+            let (center, he) = (Vec2::new(3.0, 0.5), Vec2::new(1.5, 0.5));
+            w.query_aabb_into(center, he, mask, &mut seen, &mut out);
+            assert_eq!(out, w.query_aabb(center, he, mask));
+            assert_eq!(out.len(), 3);
+            let cap = out.capacity();
+            w.query_aabb_into(Vec2::new(50.0, 50.0), he, mask, &mut seen, &mut out);
+            assert!(out.is_empty());
+            assert_eq!(out.capacity(), cap);
+        }
+    }
+
+    #[test]
+    fn test_point_eps_merges_nearby_points() {
+        let mask = LayerMask::simple(1, 1);
+        let run = |point_eps: f32| {
+            let mut w = PhysicsWorld::new(WorldConfig { point_eps, ..cfg() });
+            w.begin_frame();
+            // Neighbouring grid cells, 0.04 apart
+            let a = w.push_point(Vec2::new(0.98, 0.5), Vec2::ZERO, mask, None);
+            w.push_point(Vec2::new(1.02, 0.5), Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            let events = w.drain_events().len();
+            let hits = w.query_point(Vec2::new(0.95, 0.5), mask);
+            let all = w.query_point_all(Vec2::new(0.95, 0.5), mask).len();
+            (events, hits.first().map(|h| h.0) == Some(a), all)
+        };
+        assert_eq!(run(0.0), (0, false, 0));
+        assert_eq!(run(0.05), (1, true, 1));
+    }
+
+    #[test]
+    fn test_tile_sweep_reports_toi_error() {
+        let mask = LayerMask::simple(1, 1);
+        let mut solids = vec![0u8; 8];
+        solids[5] = 1;
+        let run = |dt: f32, toi_in_seconds: bool| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                dt,
+                toi_in_seconds,
+                ..cfg()
+            });
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 8,
+                height: 1,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            let vel = Vec2::new(10.0, 0.0) / dt;
+            let (_, hit, _) = w
+                .sweep_aabb_tiles(Vec2::new(0.5, 0.5), Vec2::splat(0.4), vel, mask)
+                .unwrap();
+            hit
+        };
+        let hit = run(1.0, false);
+        // The box touches x = 5 at t = 0.41; the bisection brackets it
+        assert!(hit.hint.toi_error > 0.0 && hit.hint.toi_error < 1e-3);
+        assert!(hit.toi - hit.hint.toi_error <= 0.41 + 1e-6 && 0.41 <= hit.toi + 1e-6);
+        let secs = run(2.0, true);
+        assert!((secs.toi - hit.toi * 2.0).abs() < 1e-5);
+        assert!((secs.hint.toi_error - hit.hint.toi_error * 2.0).abs() < 1e-6);
+        // Exact sweeps carry no bracket
+        let mut w = PhysicsWorld::new(cfg());
+        w.begin_frame();
+        let a = w.push_circle(Vec2::ZERO, 0.5, Vec2::new(4.0, 0.0), mask, None);
+        let b = w.push_circle(Vec2::new(3.0, 0.0), 0.5, Vec2::ZERO, mask, None);
+        w.end_frame();
+        assert_eq!(w.sweep_pair(a, b).unwrap().hint.toi_error, 0.0);
+    }
+
+    #[test]
+    fn test_path_clear_matches_sweeps() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let mut solids = vec![0u8; 64];
+        solids[3 * 8 + 6] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 8,
+            height: 8,
+            solids: &solids,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(3.5, 6.5),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        });
+        w.begin_frame();
+        w.end_frame();
+        let he = Vec2::splat(0.25);
+        // Into the tile, into the static, through open space
+        assert!(!w.path_clear_aabb(Vec2::new(1.5, 3.5), he, Vec2::new(6.0, 0.0), mask));
+        assert!(!w.path_clear_aabb(Vec2::new(1.5, 6.5), he, Vec2::new(4.0, 0.0), mask));
+        assert!(w.path_clear_aabb(Vec2::new(1.5, 1.5), he, Vec2::new(6.0, 0.0), mask));
+        assert!(!w.path_clear_point(Vec2::new(6.5, 0.5), Vec2::new(0.0, 6.0), mask));
+        assert!(w.path_clear_point(Vec2::new(5.5, 0.5), Vec2::new(0.0, 6.0), mask));
+        // Past the tile's corner: the box's path clips it, the circle's rounded path doesn't
+        let (c, vel) = (Vec2::new(6.2, 1.6), Vec2::new(1.1, 1.1));
+        assert!(!w.path_clear_aabb(c, Vec2::splat(0.4), vel, mask));
+        assert!(w.sweep_circle_tiles(c, 0.4, vel, mask).is_none());
+        assert!(w.path_clear_circle(c, 0.4, vel, mask));
+        assert!(w.path_clear_aabb(c, he, vel, LayerMask::simple(2, 2)));
+    }
+
+    #[test]
+    fn test_bit_packed_tilemap_matches_bytes() {
+        let mask = LayerMask::simple(1, 1);
+        let (width, height) = (70u32, 3u32);
+        let mut bytes = vec![0u8; (width * height) as usize];
+        for &i in &[5usize, 63, 64, 69, 70 + 40, 140 + 66] {
+            bytes[i] = 1;
+        }
+        let mut bits = vec![0u64; bytes.len().div_ceil(64)];
+        for (i, &b) in bytes.iter().enumerate() {
+            bits[i / 64] |= (b as u64) << (i % 64);
+        }
+        let mut wb = PhysicsWorld::new(cfg());
+        let byte_map = wb.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width,
+            height,
+            solids: &bytes,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mut wp = PhysicsWorld::new(cfg());
+        let desc = TileMapBitsDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width,
+            height,
+            bits: &bits,
+            mask,
+            user_key: None,
+        };
+        let bit_map = wp.attach_tilemap_bits(desc);
+        let probe = |w: &PhysicsWorld| {
+            let ray = w.raycast_tiles(Vec2::new(0.5, 0.5), Vec2::X, 100.0, mask);
+            let sweep =
+                w.sweep_aabb_tiles(Vec2::new(60.5, 2.5), Vec2::splat(0.4), Vec2::X * 9.0, mask);
+            let q = w
+                .query_aabb_all(Vec2::new(35.0, 1.5), Vec2::new(35.0, 1.5), mask)
+                .len();
+            (ray.map(|h| h.0), sweep.map(|h| h.0), q)
+        };
+        assert_eq!(probe(&wb), probe(&wp));
+        assert!(probe(&wp).1.is_some());
+        assert_eq!(probe(&wp).2, 6);
+        assert_eq!(wp.tile_value(bit_map, glam::UVec2::new(63, 0)), Some(1));
+        // Clearing and setting bits across a word boundary
+        wb.update_tiles(byte_map, (62, 0, 4, 1), &[7, 0, 0, 0]);
+        wp.update_tiles(bit_map, (62, 0, 4, 1), &[7, 0, 0, 0]);
+        assert_eq!(probe(&wb), probe(&wp));
+        assert_eq!(wp.tile_value(bit_map, glam::UVec2::new(62, 0)), Some(1));
+        assert_eq!(wp.tile_value(bit_map, glam::UVec2::new(64, 0)), Some(0));
+        assert_eq!(
+            wp.try_attach_tilemap_bits(TileMapBitsDesc {
+                bits: &bits[..3],
+                ..desc
+            }),
+            Err(TileMapError::SizeMismatch {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_bit_packed_tilemap_skips_runs_without_tables() {
+        let mask = LayerMask::simple(1, 1);
+        let (width, height) = (150u32, 90u32);
+        let mut seed = 7u32;
+        let mut lcg = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            seed as f32 / u32::MAX as f32
+        };
+        let bytes: Vec<u8> = (0..width * height).map(|_| (lcg() < 0.01) as u8).collect();
+        let mut bits = vec![0u64; bytes.len().div_ceil(64)];
+        for (i, &b) in bytes.iter().enumerate() {
+            bits[i / 64] |= (b as u64) << (i % 64);
+        }
+        let mut wb = PhysicsWorld::new(cfg());
+        wb.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(-2.0, 1.0),
+            cell: 0.75,
+            width,
+            height,
+            solids: &bytes,
+            mask,
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let mut wp = PhysicsWorld::new(cfg());
+        let bit_map = wp.attach_tilemap_bits(TileMapBitsDesc {
+            origin: Vec2::new(-2.0, 1.0),
+            cell: 0.75,
+            width,
+            height,
+            bits: &bits,
+            mask,
+            user_key: None,
+        });
+        // Bits are the only per-cell storage
+        let runs = &wp.tilemaps[0].runs;
+        assert!(runs.pos_x.is_empty() && runs.neg_x.is_empty());
+        assert!(runs.pos_y.is_empty() && runs.neg_y.is_empty());
+        wp.update_tiles(bit_map, (3, 4, 2, 2), &[0, 1, 1, 0]);
+        wb.update_tiles(TileMapRef(0), (3, 4, 2, 2), &[0, 1, 1, 0]);
+        assert!(wp.tilemaps[0].runs.pos_x.is_empty());
+        for _ in 0..500 {
+            let origin = Vec2::new(lcg() * 120.0 - 6.0, lcg() * 75.0 - 2.0);
+            let dir = Vec2::new(lcg() * 2.0 - 1.0, lcg() * 2.0 - 1.0);
+            let a = wb.raycast_tiles(origin, dir, 300.0, mask);
+            let b = wp.raycast_tiles(origin, dir, 300.0, mask);
+            assert_eq!(a.map(|h| h.0), b.map(|h| h.0));
+            if let (Some((_, ha, _)), Some((_, hb, _))) = (a, b) {
+                assert!((ha.toi - hb.toi).abs() < 1e-3);
+                assert_eq!(ha.normal, hb.normal);
+            }
+        }
+        // Axis-aligned rays take the longest skips
+        for (origin, dir) in [
+            (Vec2::new(-1.5, 30.2), Vec2::X),
+            (Vec2::new(110.0, 30.2), Vec2::NEG_X),
+            (Vec2::new(40.1, 1.5), Vec2::Y),
+            (Vec2::new(40.1, 67.0), Vec2::NEG_Y),
+        ] {
+            assert_eq!(
+                wb.raycast_tiles(origin, dir, 300.0, mask).map(|h| h.0),
+                wp.raycast_tiles(origin, dir, 300.0, mask).map(|h| h.0)
+            );
+        }
     }
 
     #[test]
-    fn test_generate_sweep_event_and_drain() {
+    fn test_tile_sweep_contact_axis() {
         let mut w = PhysicsWorld::new(cfg());
-        w.begin_frame();
         let mask = LayerMask::simple(1, 1);
-        let a = w.push_circle(
-            Vec2::new(-2.0, 0.0),
-            0.5,
-            Vec2::new(4.0, 0.0),
+        let mut solids = vec![0u8; 32];
+        solids[..8].fill(1);
+        solids[2 * 8 + 5] = 1;
+        solids[8 + 1] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 8,
+            height: 4,
+            solids: &solids,
             mask,
-            Some(11),
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        let he = Vec2::splat(0.25);
+        let axis = |c: Vec2, d: Vec2| {
+            let (_, hit, _) = w.sweep_aabb_tiles(c, he, d, mask).unwrap();
+            hit.hint.contact_axis
+        };
+        assert_eq!(
+            axis(Vec2::new(2.5, 2.0), Vec2::new(1.0, -2.0)),
+            Some(Axis::Y)
+        );
+        assert_eq!(
+            axis(Vec2::new(3.5, 2.5), Vec2::new(3.0, -0.5)),
+            Some(Axis::X)
+        );
+        // Box corner meets the floating tile's corner head-on
+        assert_eq!(
+            axis(Vec2::new(4.25, 1.25), Vec2::new(1.0, 1.0)),
+            Some(Axis::Both)
+        );
+        assert_eq!(axis(Vec2::new(5.5, 2.5), Vec2::new(1.0, 0.0)), None);
+        // Sliding along the floor into a step's side is still a wall
+        assert_eq!(
+            axis(Vec2::new(3.5, 1.25), Vec2::new(-2.0, 0.0)),
+            Some(Axis::X)
         );
-        let b = w.push_circle(Vec2::new(0.0, 0.0), 0.5, Vec2::ZERO, mask, Some(22));
-        w.end_frame();
-        w.generate_events();
-        let evs = w.drain_events();
-        assert_eq!(evs.len(), 1);
-        let ev = evs[0];
-        assert!(matches!(ev.kind, crate::types::EventKind::Sweep));
-        match ev.a {
-            BodyRef::Collider(id) => assert_eq!(id, a),
-            _ => panic!("expected collider A"),
-        }
-        match ev.b {
-            BodyRef::Collider(id) => assert_eq!(id, b),
-            _ => panic!("expected collider B"),
-        }
-        assert!(ev.sweep.is_some());
-        // Drained; buffer should be empty now
-        assert!(w.drain_events().is_empty());
     }
 
     #[test]
-    fn test_queries_and_pairwise() {
+    fn test_sweep_aabb_tiles_all_reports_sliding_floor() {
         let mut w = PhysicsWorld::new(cfg());
-        w.begin_frame();
         let mask = LayerMask::simple(1, 1);
-        let id_a = w.push_aabb(
-            Vec2::new(0.0, 0.0),
-            Vec2::splat(1.0),
-            Vec2::ZERO,
+        let mut solids = vec![0u8; 24];
+        solids[..8].fill(1);
+        solids[8 + 5] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 8,
+            height: 3,
+            solids: &solids,
             mask,
-            Some(100),
-        );
-        let id_b = w.push_circle(Vec2::new(3.0, 0.0), 1.0, Vec2::ZERO, mask, Some(200));
-        w.end_frame();
-        // point inside AABB
-        let q1 = w.query_point(Vec2::new(0.5, 0.5), mask);
-        assert!(q1.iter().any(|(id, _)| *id == id_a));
-        // aabb overlaps a
-        let q2 = w.query_aabb(Vec2::new(0.0, 0.0), Vec2::splat(0.5), mask);
-        assert!(q2.iter().any(|(id, _)| *id == id_a));
-        // circle query hits circle b
-        let q3 = w.query_circle(Vec2::new(3.0, 0.0), 1.0, mask);
-        assert!(q3.iter().any(|(id, _)| *id == id_b));
-        // pairwise overlap between aabb and circle should be false
-        assert!(w.overlap_pair(id_a, id_b).is_none());
-        // by key lookup
-        assert!(w.overlap_by_key(100, 200).is_none());
+            user_key: None,
+            solid_mask: 0xFF,
+        });
+        // Resting on the floor, sliding right into the wall cell
+        let (c, he, vel) = (Vec2::new(3.5, 1.25), Vec2::splat(0.25), Vec2::new(2.0, 0.0));
+        let (toi, all) = w.sweep_aabb_tiles_all(c, he, vel, mask).unwrap();
+        assert!((toi - 0.625).abs() < 1e-3);
+        let normal_of = |cells: &[(TileRef, Vec2)], x: u32, y: u32| {
+            cells
+                .iter()
+                .find(|(t, _)| t.cell_xy == glam::UVec2::new(x, y))
+                .map(|&(_, n)| n)
+        };
+        assert_eq!(all.len(), 3);
+        assert_eq!(normal_of(&all, 4, 0), Some(Vec2::Y));
+        assert_eq!(normal_of(&all, 5, 1), Some(Vec2::NEG_X));
+        // The contacts variant keeps only faces opposing the motion
+        let (_, opposing) = w.sweep_aabb_tiles_contacts(c, he, vel, mask).unwrap();
+        let opposing: Vec<_> = opposing.into_iter().flatten().collect();
+        assert_eq!(normal_of(&opposing, 4, 0), None);
+        assert_eq!(normal_of(&opposing, 5, 1), Some(Vec2::NEG_X));
     }
 
     #[test]
-    fn test_raycast_hits_closest() {
+    fn test_moving_tilemap_sweeps_resting_collider() {
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8; 4];
+        let run = |vel: Vec2| {
+            let mut w = PhysicsWorld::new(cfg());
+            let map = w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 4,
+                height: 1,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.set_tilemap_velocity(map, vel);
+            w.begin_frame();
+            w.push_aabb(
+                Vec2::new(2.5, 2.0),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.end_frame();
+            w.generate_events();
+            let events = w.drain_events();
+            let query = w.sweep_aabb_tiles(Vec2::new(2.5, 2.0), Vec2::splat(0.5), Vec2::ZERO, mask);
+            (events, query)
+        };
+        let (events, query) = run(Vec2::ZERO);
+        assert!(events.is_empty() && query.is_none());
+        // The platform's top rises from 1 to 4 and meets the box's bottom (1.5) at t = 1/6
+        let (events, query) = run(Vec2::new(0.0, 3.0));
+        assert_eq!(events.len(), 1);
+        let hit = events[0].sweep.unwrap();
+        assert!((hit.toi - 1.0 / 6.0).abs() < 1e-3);
+        assert_eq!(hit.normal, Vec2::Y);
+        assert!((hit.impact_speed - 3.0).abs() < 1e-4);
+        // Resolution positions stay in world space: the box itself never moved
+        assert!((hit.hint.last_free_pos.unwrap() - Vec2::new(2.5, 2.0)).length() < 1e-3);
+        assert!((hit.hint.safe_pos.unwrap() - Vec2::new(2.5, 2.0)).length() < 1e-3);
+        assert_eq!(hit.hint.contact_axis, Some(Axis::Y));
+        let (_, qhit, _) = query.unwrap();
+        assert!((qhit.toi - hit.toi).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_raycast_one_ignores_other_colliders() {
         let mut w = PhysicsWorld::new(cfg());
         w.begin_frame();
         let mask = LayerMask::simple(1, 1);
-        let id_a = w.push_aabb(
+        let _front = w.push_aabb(
             Vec2::new(2.0, 0.0),
             Vec2::splat(0.5),
             Vec2::ZERO,
             mask,
-            Some(1),
+            None,
         );
-        let _id_b = w.push_aabb(
-            Vec2::new(4.0, 0.0),
-            Vec2::splat(0.5),
+        let boss = w.push_circle(
+            Vec2::new(5.0, 0.0),
+            1.0,
             Vec2::ZERO,
-            mask,
-            Some(2),
+            LayerMask::simple(2, 2),
+            None,
         );
         w.end_frame();
-        let hit = w
-            .raycast(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), mask, 10.0)
-            .unwrap();
-        assert_eq!(hit.0, id_a);
-        let hit2 = w.raycast(Vec2::new(0.0, 0.0), Vec2::new(-1.0, 0.0), mask, 10.0);
-        assert!(hit2.is_none());
-    }
-
-    // --- Tile tests ---------------------------------------------------------
-
-    fn simple_map_bits() -> Vec<u8> {
-        // 3x1 with middle solid
-        vec![0, 1, 0]
+        let hit = w.raycast_one(boss, Vec2::ZERO, Vec2::X, 10.0).unwrap();
+        assert!((hit.toi - 4.0).abs() < 1e-5);
+        assert_eq!(hit.normal, -Vec2::X);
+        assert!(w.raycast_one(boss, Vec2::ZERO, Vec2::X, 3.0).is_none());
+        assert!(w.raycast_one(boss, Vec2::ZERO, -Vec2::X, 10.0).is_none());
+        assert!(
+            w.raycast_one(FrameId(7), Vec2::ZERO, Vec2::X, 10.0)
+                .is_none()
+        );
     }
 
     #[test]
-    fn test_tile_raycast_basic() {
-        let mut w = PhysicsWorld::new(cfg());
-        let map = TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width: 3,
-            height: 1,
-            solids: &simple_map_bits(),
-            mask: LayerMask::simple(2, 1),
-            user_key: Some(77),
-        };
-        w.attach_tilemap(map);
-        // ray from left hits middle cell at x=1 boundary
-        let origin = Vec2::new(-0.5, 0.5);
-        let dir = Vec2::new(1.0, 0.0);
-        let mask = LayerMask::simple(1, 2);
-        let hit = w.raycast_all(origin, dir, mask, 10.0).unwrap();
-        match hit.0 {
-            BodyRef::Tile(t) => {
-                assert_eq!(t.cell_xy.x, 1);
-            }
-            _ => panic!("expected tile hit"),
+    fn test_tile_events_per_cell() {
+        let mask = LayerMask::simple(1, 1);
+        // 2x2 hazard patch in a 4x4 map; the box covers all of it plus empty cells
+        let mut solids = vec![0u8; 16];
+        for idx in [5, 6, 9, 10] {
+            solids[idx] = 1;
         }
-    }
-
-    #[test]
-    fn test_query_aabb_all_tiles() {
-        let mut w = PhysicsWorld::new(cfg());
-        let map = TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width: 3,
-            height: 1,
-            solids: &simple_map_bits(),
-            mask: LayerMask::simple(2, 1),
-            user_key: None,
+        let run = |tile_events_per_cell: bool, max_events: usize| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                tile_events_per_cell,
+                max_events,
+                ..cfg()
+            });
+            w.attach_tilemap(TileMapDesc {
+                origin: Vec2::ZERO,
+                cell: 1.0,
+                width: 4,
+                height: 4,
+                solids: &solids,
+                mask,
+                user_key: None,
+                solid_mask: 0xFF,
+            });
+            w.begin_frame();
+            w.push_aabb(Vec2::splat(2.0), Vec2::splat(1.4), Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            w.drain_events()
         };
-        w.attach_tilemap(map);
-        let res = w.query_aabb_all(
-            Vec2::new(1.0, 0.5),
-            Vec2::splat(0.6),
-            LayerMask::simple(1, 2),
-        );
+        assert_eq!(run(false, 16).len(), 1);
+        let events = run(true, 16);
+        assert_eq!(events.len(), 4);
+        let mut cells: Vec<(u32, u32)> = events
+            .iter()
+            .map(|ev| match ev.b {
+                BodyRef::Tile(t) => (t.cell_xy.x, t.cell_xy.y),
+                _ => panic!("expected a tile"),
+            })
+            .collect();
+        cells.sort();
+        assert_eq!(cells, vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
         assert!(
-            res.iter().any(
-                |(b, _)| matches!(b, BodyRef::Tile(TileRef { cell_xy, .. }) if cell_xy.x == 1)
-            )
+            events
+                .iter()
+                .all(|ev| matches!(ev.kind, EventKind::Overlap))
         );
+        assert_eq!(run(true, 3).len(), 3);
     }
 
     #[test]
-    fn test_sweep_aabb_tiles_basic() {
+    fn test_query_obb_all_rotated_selection() {
+        let mask = LayerMask::simple(1, 1);
         let mut w = PhysicsWorld::new(cfg());
-        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0]; // 3x3 column in middle
-        let map = TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
+        // Solid corner tiles (0, 0) and (5, 5), both under the ends of the diagonal strip
+        let mut solids = vec![0u8; 36];
+        solids[5 * 6 + 5] = 1;
+        solids[0] = 1;
+        w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
             cell: 1.0,
-            width: 3,
-            height: 3,
+            width: 6,
+            height: 6,
             solids: &solids,
-            mask: LayerMask::simple(2, 1),
-            user_key: None,
-        };
-        w.attach_tilemap(map);
-        let start = Vec2::new(0.2, 1.5);
-        let he = Vec2::splat(0.3);
-        let vel = Vec2::new(2.0, 0.0);
-        let res = w
-            .sweep_aabb_tiles(start, he, vel, LayerMask::simple(1, 2))
-            .unwrap();
-        assert!(res.1.toi > 0.0 && res.1.toi <= 1.0);
-        // normal should be -X (hitting vertical face)
-        assert!(res.1.normal.x < -0.5);
-        assert!(res.1.hint.safe_pos.is_some());
+            mask,
+            user_key: Some(9),
+            solid_mask: 0xFF,
+        });
+        w.begin_frame();
+        // A thin strip along the diagonal y = x
+        let on_strip = w.push_circle(Vec2::splat(3.0), 0.2, Vec2::ZERO, mask, Some(1));
+        // Inside the strip's bounding box but off the diagonal
+        let _off = w.push_aabb(
+            Vec2::new(4.5, 1.5),
+            Vec2::splat(0.3),
+            Vec2::ZERO,
+            mask,
+            Some(2),
+        );
+        let _off_point = w.push_point(Vec2::new(1.5, 4.5), Vec2::ZERO, mask, Some(3));
+        let box_on = w.push_aabb(
+            Vec2::new(1.5, 1.5),
+            Vec2::splat(0.2),
+            Vec2::ZERO,
+            mask,
+            Some(4),
+        );
+        w.end_frame();
+        let angle = std::f32::consts::FRAC_PI_4;
+        let hits = w.query_obb_all(Vec2::splat(3.0), Vec2::new(3.5, 0.3), angle, mask);
+        let mut colliders: Vec<FrameId> = hits
+            .iter()
+            .filter_map(|(b, _)| match b {
+                BodyRef::Collider(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        colliders.sort_by_key(|id| id.0);
+        assert_eq!(colliders, vec![on_strip, box_on]);
+        let tiles: Vec<(u32, u32)> = hits
+            .iter()
+            .filter_map(|(b, key)| match b {
+                BodyRef::Tile(t) => {
+                    assert_eq!(*key, Some(9));
+                    Some((t.cell_xy.x, t.cell_xy.y))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tiles.len(), 2);
+        // The axis-aligned query of the same extents misses the diagonal ends
+        let aabb = w.query_aabb_all(Vec2::splat(3.0), Vec2::new(3.5, 0.3), mask);
+        assert!(aabb.iter().all(|(b, _)| !matches!(b, BodyRef::Tile(_))));
     }
 
     #[test]
-    fn test_tile_raycast_monotonicity() {
-        let mut w = PhysicsWorld::new(cfg());
-        let solids = vec![0, 1, 0]; // 3x1, solid at x=1
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width: 3,
-            height: 1,
-            solids: &solids,
-            mask: LayerMask::simple(2, 1),
-            user_key: None,
+    fn test_timing_stamped_with_frame() {
+        let mask = LayerMask::simple(1, 1);
+        let mut w = PhysicsWorld::new(WorldConfig {
+            enable_timing: true,
+            ..cfg()
         });
-        let origin = Vec2::new(0.1, 0.5);
-        let dir = Vec2::new(1.0, 0.0);
-        let mask = LayerMask::simple(1, 2);
-        let h1 = w.raycast_tiles(origin, dir, 0.8, mask);
-        assert!(h1.is_none());
-        let h2 = w.raycast_tiles(origin, dir, 10.0, mask).unwrap();
-        let t2 = h2.1.toi;
-        assert!(t2 > 0.8);
-        let h3 = w.raycast_tiles(origin, dir, t2, mask).unwrap();
-        assert!((h3.1.toi - t2).abs() < 1e-5);
+        let frame = |w: &mut PhysicsWorld, generate: bool| {
+            w.begin_frame();
+            w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+            w.push_aabb(
+                Vec2::new(0.5, 0.0),
+                Vec2::splat(0.5),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.end_frame();
+            if generate {
+                w.generate_events();
+            }
+        };
+        frame(&mut w, false);
+        assert!(w.timing().is_none());
+        frame(&mut w, true);
+        let t = w.timing().unwrap();
+        assert_eq!(t.frame_counter, w.frame_counter);
+        assert_eq!(t.events_emitted, 1);
+        // Untimed frames and a timed end_frame alone keep the last completed reading
+        let stamp = w.frame_counter;
+        frame(&mut w, false);
+        assert_eq!(w.timing().unwrap().frame_counter, stamp);
+        w.set_config(WorldConfig {
+            enable_timing: false,
+            ..cfg()
+        });
+        frame(&mut w, true);
+        assert_eq!(w.timing().unwrap().frame_counter, stamp);
+        assert_ne!(w.frame_counter, stamp);
     }
 
     #[test]
-    fn test_safe_pos_no_overlap_after_sweep() {
+    fn test_tilemaps_lists_attached_maps() {
         let mut w = PhysicsWorld::new(cfg());
-        let solids = vec![0, 1, 0, 0, 1, 0, 0, 1, 0];
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width: 3,
-            height: 3,
+        let solids = vec![0u8, 3, 0, 1];
+        let a = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(-2.0, 1.0),
+            cell: 0.5,
+            width: 2,
+            height: 2,
             solids: &solids,
-            mask: LayerMask::simple(2, 1),
+            mask: LayerMask::simple(1, 1),
+            user_key: Some(7),
+            solid_mask: 0x01,
+        });
+        let b = w.attach_tilemap_bits(TileMapBitsDesc {
+            origin: Vec2::ZERO,
+            cell: 1.0,
+            width: 8,
+            height: 8,
+            bits: &[u64::MAX],
+            mask: LayerMask::simple(2, 2),
             user_key: None,
         });
-        let start = Vec2::new(0.2, 1.5);
-        let he = Vec2::splat(0.4);
-        let vel = Vec2::new(3.0, 0.0);
-        let (_tref, hit, _key) = w
-            .sweep_aabb_tiles(start, he, vel, LayerMask::simple(1, 2))
-            .unwrap();
-        let p = hit.hint.safe_pos.expect("safe_pos should exist");
-        let hits = w.query_aabb_all(p, he, LayerMask::simple(1, 2));
-        assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
+        let maps: Vec<(TileMapRef, TileMapInfo)> = w.tilemaps().collect();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[0].0, a);
+        let info = maps[0].1;
+        assert_eq!((info.origin, info.cell), (Vec2::new(-2.0, 1.0), 0.5));
+        assert_eq!((info.width, info.height), (2, 2));
+        assert_eq!((info.mask.layer, info.user_key), (1, Some(7)));
+        assert_eq!((info.solid_mask, info.vel), (0x01, Vec2::ZERO));
+        assert_eq!(
+            (maps[1].0, maps[1].1.width, maps[1].1.solid_mask),
+            (b, 8, 1)
+        );
+        assert_eq!(w.tile_solids(a), Some(&solids[..]));
+        assert_eq!(w.tile_solids(b), None);
+        assert_eq!(w.tile_solids(TileMapRef(5)), None);
     }
 
     #[test]
-    fn test_start_embedded_emits_overlap_event() {
-        let mut w = PhysicsWorld::new(cfg());
-        let solids = vec![1]; // 1x1 solid at origin cell [0,0]
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width: 1,
-            height: 1,
-            solids: &solids,
-            mask: LayerMask::simple(2, 1),
-            user_key: Some(42),
-        });
+    fn test_large_collider_skips_grid() {
+        let mask = LayerMask::simple(1, 1);
+        let run = |large_collider_cell_threshold: u32| {
+            let mut w = PhysicsWorld::new(WorldConfig {
+                large_collider_cell_threshold,
+                ..cfg()
+            });
+            w.begin_frame();
+            let wall = w.push_aabb(Vec2::ZERO, Vec2::splat(50.0), Vec2::ZERO, mask, Some(1));
+            let small = w.push_aabb(
+                Vec2::new(10.5, 10.5),
+                Vec2::splat(0.4),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            w.push_point(Vec2::new(70.0, 0.0), Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            (w, wall, small)
+        };
+        let (grid, ..) = run(0);
+        let (mut w, wall, small) = run(64);
+        assert!(w.grid.values().all(|l| !l.contains(&(wall.0 as usize))));
+        assert!(w.grid.len() < grid.grid.len());
+        // Small bodies stay in the grid
+        assert_eq!(w.collider_broadphase_load(small).0, 1);
+        assert_eq!(w.collider_broadphase_load(wall), (0, 2));
+        // Same events and queries as with the wall in the grid
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), grid.events().len());
+        assert_eq!(evs.len(), 1);
+        assert!(
+            w.query_point(Vec2::new(-40.0, 40.0), mask)
+                .contains(&(wall, Some(1)))
+        );
+    }
+
+    #[test]
+    fn test_sweep_pair_interval_enter_and_exit() {
+        let mask = LayerMask::simple(1, 1);
+        let mut w = PhysicsWorld::new(cfg());
         w.begin_frame();
-        // AABB entirely inside the tile, no motion
-        let mask = LayerMask::simple(1, 2);
-        w.push_aabb(
-            Vec2::new(0.5, 0.5),
-            Vec2::splat(0.1),
-            Vec2::ZERO,
+        // A 1-wide box crossing a still 1-wide box: Minkowski width 2, path length 10
+        let a = w.push_aabb(
+            Vec2::new(-3.0, 0.0),
+            Vec2::splat(0.5),
+            Vec2::new(10.0, 0.0),
             mask,
-            Some(7),
+            None,
         );
+        let b = w.push_aabb(Vec2::ZERO, Vec2::splat(0.5), Vec2::ZERO, mask, None);
+        // Circle already overlapping `b` and still there at the end
+        let c = w.push_circle(Vec2::new(0.8, 0.0), 0.5, Vec2::new(0.1, 0.0), mask, None);
+        let d = w.push_circle(Vec2::new(0.0, 5.0), 0.5, Vec2::ZERO, mask, None);
+        let e = w.push_circle(Vec2::new(-2.0, 5.0), 0.5, Vec2::new(4.0, 0.0), mask, None);
         w.end_frame();
-        w.generate_events();
-        let evs = w.drain_events();
-        assert!(evs.iter().any(|e| matches!(e.kind, EventKind::Overlap)
-            && matches!(e.b, BodyRef::Tile(_))
-            && e.overlap.unwrap().hint.start_embedded));
+        let (enter, exit) = w.sweep_pair_interval(a, b).unwrap();
+        assert!((enter - 0.2).abs() < 1e-5 && (exit - 0.4).abs() < 1e-5);
+        assert!((enter - w.sweep_pair(a, b).unwrap().toi).abs() < 1e-5);
+        assert_eq!(w.sweep_pair_interval(c, b), Some((0.0, 1.0)));
+        // Circles: centers 1 apart at t = 0.25 and 0.75
+        let (enter, exit) = w.sweep_pair_interval(e, d).unwrap();
+        assert!((enter - 0.25).abs() < 1e-5 && (exit - 0.75).abs() < 1e-5);
+        assert_eq!(w.sweep_pair_interval(a, d), None);
     }
 
     #[test]
-    fn test_tile_raycast_monotonicity_random() {
+    fn test_tilemap_solid_bounds_tracks_updates() {
+        let mask = LayerMask::simple(1, 1);
         let mut w = PhysicsWorld::new(cfg());
-        // map with a single solid column at x=10
-        let width = 32u32;
-        let height = 16u32;
-        let mut solids = vec![0u8; (width * height) as usize];
-        for y in 0..height {
-            solids[(y * width + 10) as usize] = 1;
-        }
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
+        let mut solids = vec![0u8; 100 * 100];
+        solids[60 * 100 + 40] = 1;
+        solids[61 * 100 + 42] = 1;
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::new(-10.0, 0.0),
             cell: 1.0,
-            width,
-            height,
+            width: 100,
+            height: 100,
             solids: &solids,
-            mask: LayerMask::simple(2, 1),
+            mask,
             user_key: None,
+            solid_mask: 0xFF,
         });
-        let mask = LayerMask::simple(1, 2);
-        let mut seed = 1234567u32;
-        let lcg = |s: &mut u32| {
-            *s = s.wrapping_mul(1664525).wrapping_add(1013904223);
-            *s
-        };
-        for _ in 0..50 {
-            let ry = (lcg(&mut seed) as f32 / u32::MAX as f32) * (height as f32 - 1.0) + 0.5;
-            let ox = (lcg(&mut seed) as f32 / u32::MAX as f32) * 5.0; // start in [0,5)
-            let origin = Vec2::new(ox, ry);
-            let dir = Vec2::new(1.0, 0.0);
-            let small = 1.0; // < distance to column at x=10
-            let big = 100.0;
-            let h_small = w.raycast_tiles(origin, dir, small, mask);
-            let h_big = w.raycast_tiles(origin, dir, big, mask);
-            if let Some((_tref_s, hs, _)) = h_small {
-                let (_tref_b, hb, _) = h_big.expect("big max_t should retain hit");
-                assert!((hs.toi - hb.toi).abs() < 1e-5);
-            }
-        }
+        let bounds = |w: &PhysicsWorld| w.tilemap_solid_bounds(map);
+        assert_eq!(
+            bounds(&w),
+            Some((Vec2::new(30.0, 60.0), Vec2::new(33.0, 62.0)))
+        );
+        // A new solid outside grows the bounds; clearing it shrinks them back
+        w.update_tiles(map, (2, 3, 1, 1), &[1]);
+        assert_eq!(
+            bounds(&w),
+            Some((Vec2::new(-8.0, 3.0), Vec2::new(33.0, 62.0)))
+        );
+        let ray = w.raycast_tiles(Vec2::new(-7.5, -5.0), Vec2::Y, 100.0, mask);
+        assert_eq!(ray.unwrap().0.cell_xy, glam::UVec2::new(2, 3));
+        w.update_tiles(map, (2, 3, 1, 1), &[0]);
+        assert_eq!(
+            bounds(&w),
+            Some((Vec2::new(30.0, 60.0), Vec2::new(33.0, 62.0)))
+        );
+        assert!(
+            w.raycast_tiles(Vec2::new(-7.5, -5.0), Vec2::Y, 100.0, mask)
+                .is_none()
+        );
+        // Rays and queries still find the remaining tiles
+        let (t, hit, _) = w
+            .raycast_tiles(Vec2::new(30.5, 0.0), Vec2::Y, 100.0, mask)
+            .unwrap();
+        assert_eq!((t.cell_xy, hit.toi), (glam::UVec2::new(40, 60), 60.0));
+        assert_eq!(
+            w.query_aabb_all(Vec2::new(32.5, 61.5), Vec2::splat(0.4), mask)
+                .len(),
+            1
+        );
+        w.update_tiles(map, (40, 60, 3, 2), &[0; 6]);
+        assert_eq!(bounds(&w), None);
+        assert!(
+            w.raycast_tiles(Vec2::new(30.5, 0.0), Vec2::Y, 100.0, mask)
+                .is_none()
+        );
     }
 
     #[test]
-    fn test_safe_pos_invariant_random() {
+    fn test_tilemap_solid_bounds_shrink_only_on_edge_clears() {
         let mut w = PhysicsWorld::new(cfg());
-        // vertical wall at x=5 across all rows
-        let width = 16u32;
-        let height = 16u32;
-        let mut solids = vec![0u8; (width * height) as usize];
-        for y in 0..height {
-            solids[(y * width + 5) as usize] = 1;
+        let mut solids = vec![0u8; 100 * 100];
+        for y in 20..23 {
+            solids[y * 100 + 10..y * 100 + 13].fill(1);
         }
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
+        let map = w.attach_tilemap(TileMapDesc {
+            origin: Vec2::ZERO,
             cell: 1.0,
-            width,
-            height,
+            width: 100,
+            height: 100,
             solids: &solids,
-            mask: LayerMask::simple(2, 1),
+            mask: LayerMask::simple(1, 1),
             user_key: None,
+            solid_mask: 0xFF,
         });
-        let mask = LayerMask::simple(1, 2);
-        let mut seed = 42u32;
-        let lcg = |s: &mut u32| {
-            *s = s.wrapping_mul(1664525).wrapping_add(1013904223);
-            *s
-        };
-        for _ in 0..40 {
-            let y = (lcg(&mut seed) as f32 / u32::MAX as f32) * 10.0 + 2.0;
-            let start_x = (lcg(&mut seed) as f32 / u32::MAX as f32) * 3.0;
-            let start = Vec2::new(start_x, y);
-            let he = Vec2::new(0.2, 0.3);
-            let vel = Vec2::new(4.0 + (lcg(&mut seed) as f32 / u32::MAX as f32) * 2.0, 0.0);
-            if let Some((_tref, hit, _)) = w.sweep_aabb_tiles(start, he, vel, mask)
-                && let Some(p) = hit.hint.safe_pos
-            {
-                let hits = w.query_aabb_all(p, he, mask);
-                assert!(!hits.iter().any(|(b, _)| matches!(b, BodyRef::Tile(_))));
-            }
-        }
+        let cells = |w: &PhysicsWorld| w.tilemaps[map.0 as usize].solid_bounds;
+        let full = Some((glam::UVec2::new(10, 20), glam::UVec2::new(12, 22)));
+        assert_eq!(cells(&w), full);
+        // Interior clears and sets inside the bounds keep them
+        w.update_tiles(map, (11, 21, 1, 1), &[0]);
+        assert_eq!(cells(&w), full);
+        w.update_tiles(map, (11, 21, 1, 1), &[1]);
+        assert_eq!(cells(&w), full);
+        // An edge cell whose column still has solids leaves the bounds as they were
+        w.update_tiles(map, (12, 21, 1, 1), &[0]);
+        assert_eq!(cells(&w), full);
+        // Emptying the edge column and the top row shrinks them
+        w.update_tiles(map, (12, 20, 1, 3), &[0; 3]);
+        assert_eq!(
+            cells(&w),
+            Some((glam::UVec2::new(10, 20), glam::UVec2::new(11, 22)))
+        );
+        w.update_tiles(map, (10, 22, 2, 1), &[0, 0]);
+        assert_eq!(
+            cells(&w),
+            Some((glam::UVec2::new(10, 20), glam::UVec2::new(11, 21)))
+        );
     }
 
     #[test]
-    fn test_tile_raycast_diagonal_hits_correct_cell() {
+    fn test_overlaps_between_groups() {
+        let mask = LayerMask::simple(1, 1);
         let mut w = PhysicsWorld::new(cfg());
-        // 16x16 map with a single solid at (5,5)
-        let width = 16u32;
-        let height = 16u32;
-        let mut solids = vec![0u8; (width * height) as usize];
-        solids[(5 * width + 5) as usize] = 1;
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width,
-            height,
-            solids: &solids,
-            mask: LayerMask::simple(2, 1),
+        w.begin_frame();
+        let red: Vec<FrameId> = [0.0, 0.6, 10.0]
+            .into_iter()
+            .map(|x| w.push_aabb(Vec2::new(x, 0.0), Vec2::splat(0.5), Vec2::ZERO, mask, None))
+            .collect();
+        let mut blue: Vec<FrameId> = [0.9, 20.0]
+            .into_iter()
+            .map(|x| w.push_circle(Vec2::new(x, 0.0), 0.5, Vec2::ZERO, mask, None))
+            .collect();
+        // Invalid entries never overlap anything
+        blue.push(w.push_circle(Vec2::new(f32::NAN, 0.0), 0.5, Vec2::ZERO, mask, None));
+        w.end_frame();
+        assert_eq!(w.invalid_colliders(), vec![blue[2]]);
+        // red[0] and red[1] overlap each other but are on the same team
+        let mut hits = w.overlaps_between(&red, &blue);
+        hits.sort_by_key(|(a, _, _)| a.0);
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].0, hits[0].1), (red[0], blue[0]));
+        assert_eq!((hits[1].0, hits[1].1), (red[1], blue[0]));
+        // Normal points from the blue circle into the red box
+        assert!(hits[0].2.normal.x < 0.0);
+        assert_eq!(w.overlaps_between(&red, &red).len(), 1);
+        assert!(w.overlaps_between(&red, &[FrameId(99)]).is_empty());
+    }
+
+    #[test]
+    fn test_contact_skin_reports_near_touch() {
+        let mask = LayerMask::simple(1, 1);
+        let mut w = PhysicsWorld::new(cfg());
+        // Static box 0.15 above `a` (and well clear of `b`)
+        w.add_static(ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.45),
+            },
+            center: Vec2::new(0.3, 1.55),
+            mask,
             user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
         });
-        let mask = LayerMask::simple(1, 2);
-        let origin = Vec2::new(0.25, 0.25);
-        let dir = Vec2::new(1.0, 1.0).normalize();
-        let (_tref, hit, _key) = w
-            .raycast_tiles(origin, dir, 100.0, mask)
-            .expect("expected tile hit");
-        // The cell index should be (5,5)
-        if let Some((TileRef { cell_xy, .. }, _, _)) = w.raycast_tiles(origin, dir, 100.0, mask) {
-            assert_eq!(cell_xy.x, 5);
-            assert_eq!(cell_xy.y, 5);
-            // Contact must lie on one of the tile boundaries [1.0 tolerance]
-            let cx = hit.contact.x;
-            let cy = hit.contact.y;
-            let on_vert = (cx - 5.0).abs() < 1e-3 || (cx - 6.0).abs() < 1e-3;
-            let on_horz = (cy - 5.0).abs() < 1e-3 || (cy - 6.0).abs() < 1e-3;
-            assert!(on_vert || on_horz);
-        } else {
-            panic!("no tile hit");
-        }
+        let frame = |w: &mut PhysicsWorld| {
+            w.begin_frame();
+            // Boxes in neighboring cells with a 0.15 gap; circles 0.1 apart
+            let a = w.push_aabb(
+                Vec2::new(0.5, 0.5),
+                Vec2::splat(0.45),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            let b = w.push_aabb(
+                Vec2::new(1.55, 0.5),
+                Vec2::splat(0.45),
+                Vec2::ZERO,
+                mask,
+                None,
+            );
+            let c = w.push_circle(Vec2::new(5.0, 5.0), 0.5, Vec2::ZERO, mask, None);
+            let d = w.push_circle(Vec2::new(6.1, 5.0), 0.5, Vec2::ZERO, mask, None);
+            w.end_frame();
+            w.generate_events();
+            (a, b, c, d)
+        };
+        let (a, b, c, d) = frame(&mut w);
+        assert!(w.overlap_pair(a, b).is_none() && w.overlap_pair(c, d).is_none());
+        assert!(w.drain_events().is_empty());
+        w.set_config(WorldConfig {
+            contact_skin: 0.2,
+            ..cfg()
+        });
+        let (a, b, c, d) = frame(&mut w);
+        let ov = w.overlap_pair(a, b).unwrap();
+        assert!((ov.depth + 0.15).abs() < 1e-5);
+        assert_eq!(ov.normal, -Vec2::X);
+        let ov = w.overlap_pair(c, d).unwrap();
+        assert!((ov.depth + 0.1).abs() < 1e-5);
+        let events = w.drain_events();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().any(|e| matches!(e.b, BodyRef::Static(_))));
+        assert!(events.iter().all(|e| e.overlap.unwrap().depth < 0.0));
     }
 
     #[test]
-    fn test_circle_sweep_minkowski_equivalence() {
+    fn test_sweep_hits_at_toi_zero_flag_start_embedded() {
         let mut w = PhysicsWorld::new(cfg());
-        // vertical wall at x=5 across all rows
-        let width = 16u32;
-        let height = 16u32;
-        let mut solids = vec![0u8; (width * height) as usize];
-        for y in 0..height {
-            solids[(y * width + 5) as usize] = 1;
-        }
+        let mask = LayerMask::simple(1, 1);
+        let desc = |x: f32| ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(x, 0.0),
+            mask,
+            user_key: None,
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        w.begin_frame();
+        // Touching at the start vs. reaching contact mid-frame
+        let touching = w.push(desc(0.0), Motion::from_delta(Vec2::new(1.0, 0.0)));
+        let wall = w.push(desc(1.0), Motion::default());
+        let far = w.push(desc(10.0), Motion::from_delta(Vec2::new(2.0, 0.0)));
+        let far_wall = w.push(desc(12.0), Motion::default());
+        w.end_frame();
+        let hit = w.sweep_pair(touching, wall).unwrap();
+        assert_eq!(hit.toi, 0.0);
+        assert!(hit.hint.start_embedded);
+        let hit = w.sweep_pair(far, far_wall).unwrap();
+        assert!(hit.toi > 0.0);
+        assert!(!hit.hint.start_embedded);
+
+        // Tile sweeps: exact circle touching a tile, and a sampled box starting inside one
+        let solids = vec![1];
         w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
+            origin: Vec2::new(20.0, 0.0),
             cell: 1.0,
-            width,
-            height,
+            width: 1,
+            height: 1,
             solids: &solids,
-            mask: LayerMask::simple(2, 1),
+            mask,
             user_key: None,
+            solid_mask: 0xFF,
         });
-        let mask = LayerMask::simple(1, 2);
-        let c = Vec2::new(1.5, 3.5);
-        let r = 0.4;
-        let vel = Vec2::new(6.0, 0.0);
-        let (_t_aabb, hit_aabb, _) = w.sweep_aabb_tiles(c, Vec2::splat(r), vel, mask).unwrap();
-        let (_t_circ, hit_circ, _) = w.sweep_circle_tiles(c, r, vel, mask).unwrap();
-        assert!((hit_aabb.toi - hit_circ.toi).abs() < 5e-3);
-        // Normals should closely match
-        let dn = (hit_aabb.normal - hit_circ.normal).length();
-        assert!(dn < 1e-3);
+        let (_, hit, _) = w
+            .sweep_circle_tiles(Vec2::new(19.5, 0.5), 0.5, Vec2::new(1.0, 0.0), mask)
+            .unwrap();
+        assert_eq!(hit.toi, 0.0);
+        assert!(hit.hint.start_embedded);
+        let (_, hit, _) = w
+            .sweep_aabb_tiles(
+                Vec2::new(20.5, 0.5),
+                Vec2::splat(0.1),
+                Vec2::new(1.0, 0.0),
+                mask,
+            )
+            .unwrap();
+        assert!(hit.hint.start_embedded);
+        let (_, hit, _) = w
+            .sweep_aabb_tiles(
+                Vec2::new(18.0, 0.5),
+                Vec2::splat(0.5),
+                Vec2::new(2.0, 0.0),
+                mask,
+            )
+            .unwrap();
+        assert!(!hit.hint.start_embedded);
     }
 
-    // Note: diagonal raycast octants are covered by test_tile_raycast_diagonal_hits_correct_cell.
+    #[test]
+    fn test_query_aabb_swept_region_reports_entry_times() {
+        let mut w = PhysicsWorld::new(cfg());
+        let mask = LayerMask::simple(1, 1);
+        let desc = |x: f32, y: f32| ColliderDesc {
+            kind: ColliderKind::Aabb {
+                half_extents: Vec2::splat(0.5),
+            },
+            center: Vec2::new(x, y),
+            mask,
+            user_key: Some((x * 10.0 + y) as u64),
+            broadphase: BroadphaseMode::Grid,
+            tag: 0,
+            event_targets: EventTargets::Both,
+        };
+        w.begin_frame();
+        // Region spans x in [9, 11]; the mover's right face reaches x = 9 halfway through
+        let mover = w.push(desc(0.0, 0.0), Motion::from_delta(Vec2::new(17.0, 0.0)));
+        let inside = w.push(desc(10.0, 0.0), Motion::default());
+        let leaving = w.push(desc(10.0, 3.0), Motion::from_delta(Vec2::new(0.0, 5.0)));
+        let _miss = w.push(desc(0.0, 3.0), Motion::from_delta(Vec2::new(20.0, 0.0)));
+        let _far = w.push(desc(30.0, 0.0), Motion::from_delta(Vec2::new(-5.0, 0.0)));
+        let _masked = w.push(
+            ColliderDesc {
+                mask: LayerMask::simple(2, 2),
+                ..desc(12.0, 0.0)
+            },
+            Motion::from_delta(Vec2::new(-2.0, 0.0)),
+        );
+        w.end_frame();
+        let hits = w.query_aabb_swept_region(Vec2::new(10.0, 0.0), Vec2::splat(1.0), mask);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].0, inside);
+        assert_eq!(hits[0].1, 0.0);
+        assert_eq!(hits[0].2, Some(100));
+        assert_eq!(hits[1].0, mover);
+        assert!((hits[1].1 - 0.5).abs() < 1e-5);
+        // Moving away from a region it already touches still counts, at 0
+        let hits = w.query_aabb_swept_region(Vec2::new(10.0, 2.0), Vec2::splat(0.5), mask);
+        assert!(hits.iter().any(|h| h.0 == leaving && h.1 == 0.0));
+    }
 
     #[test]
-    fn test_circle_sweep_diagonal_vel_and_radii() {
+    fn test_tilemap_desc_new_treats_any_nonzero_as_solid() {
+        let mask = LayerMask::simple(1, 1);
+        let desc = TileMapDesc::new(Vec2::ZERO, 1.0, 3, 1, &[0, 4, 0], mask);
+        assert_eq!((desc.solid_mask, desc.user_key), (0xFF, None));
         let mut w = PhysicsWorld::new(cfg());
-        // 32x32 map with vertical wall at x=16
-        let width = 32u32;
-        let height = 32u32;
-        let mut solids = vec![0u8; (width * height) as usize];
-        for y in 0..height {
-            solids[(y * width + 16) as usize] = 1;
-        }
-        w.attach_tilemap(TileMapDesc {
-            origin: Vec2::new(0.0, 0.0),
-            cell: 1.0,
-            width,
-            height,
-            solids: &solids,
-            mask: LayerMask::simple(2, 1),
-            user_key: None,
+        let map = w.attach_tilemap(TileMapDesc {
+            user_key: Some(9),
+            ..desc
         });
-        let mask = LayerMask::simple(1, 2);
-        let center = Vec2::new(12.5, 10.5);
-        let radii = [0.1f32, 0.25, 0.5, 0.9];
-        let vels = [
-            Vec2::new(6.0, 3.0),
-            Vec2::new(12.0, -6.0),
-            Vec2::new(8.0, 4.0),
-        ];
-        for &r in &radii {
-            for &v in &vels {
-                let (_tr1, hit_c, _k1) = w
-                    .sweep_circle_tiles(center, r, v, mask)
-                    .expect("circle sweep should hit");
-                let (_tr2, hit_a, _k2) = w
-                    .sweep_aabb_tiles(center, Vec2::splat(r), v, mask)
-                    .expect("aabb(r) sweep should hit");
-                assert!(
-                    (hit_c.toi - hit_a.toi).abs() < 5e-3,
-                    "toi mismatch r={} v=({},{})",
-                    r,
-                    v.x,
-                    v.y
-                );
-                let dn = (hit_c.normal - hit_a.normal).length();
-                assert!(dn < 1e-2, "normal mismatch r={} v=({},{})", r, v.x, v.y);
-                assert!(hit_c.hint.safe_pos.is_some());
-            }
-        }
+        let (t, _, key) = w
+            .raycast_tiles(Vec2::new(-0.5, 0.5), Vec2::X, 10.0, mask)
+            .unwrap();
+        assert_eq!(
+            (t.map, t.cell_xy, key),
+            (map, glam::UVec2::new(1, 0), Some(9))
+        );
+    }
+
+    #[test]
+    fn test_moving_tilemap_leaves_static_map_overlaps_alone() {
+        let mask = LayerMask::simple(1, 1);
+        let solids = vec![1u8; 4];
+        let mut w = PhysicsWorld::new(cfg());
+        let floor = w.attach_tilemap(TileMapDesc::new(Vec2::ZERO, 1.0, 4, 1, &solids, mask));
+        let lift = w.attach_tilemap(TileMapDesc::new(
+            Vec2::new(100.0, 0.0),
+            1.0,
+            4,
+            1,
+            &solids,
+            mask,
+        ));
+        w.set_tilemap_velocity(lift, Vec2::new(0.0, 3.0));
+        w.begin_frame();
+        // Resting 0.1 inside the static floor
+        w.push_aabb(
+            Vec2::new(2.5, 1.4),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        // Resting just above the lift, which rises into it
+        w.push_aabb(
+            Vec2::new(102.5, 2.0),
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+            mask,
+            None,
+        );
+        w.end_frame();
+        w.generate_events();
+        let evs = w.drain_events();
+        assert_eq!(evs.len(), 2);
+        let on = |map: TileMapRef| {
+            evs.iter()
+                .find(|e| matches!(e.b, BodyRef::Tile(t) if t.map == map))
+                .unwrap()
+        };
+        let ev = on(floor);
+        assert!(matches!(ev.kind, EventKind::Overlap));
+        assert!((ev.overlap.unwrap().depth - 0.1).abs() < 1e-4);
+        assert!(matches!(on(lift).kind, EventKind::Sweep));
+        // Zero-motion sweeps only see maps that move
+        let q = |c: Vec2| w.sweep_aabb_tiles(c, Vec2::splat(0.5), Vec2::ZERO, mask);
+        assert!(q(Vec2::new(2.5, 1.4)).is_none());
+        assert_eq!(q(Vec2::new(102.5, 2.0)).unwrap().0.map, lift);
     }
 }